@@ -0,0 +1,524 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use std::time::{Duration, Instant};
+use crate::state::*;
+use crate::engine::*;
+use crate::view::{ActionView, InPlayView, PlayerView, PrizeView};
+use crate::replay::Label;
+
+// Makes every decision uniformly at random among the legal ones. Coins and
+// shuffles come from the same seeded generator, so a game it plays on its
+// own is reproducible.
+pub struct RandomDM {
+    rng: StdRng,
+}
+
+impl RandomDM {
+    pub fn new(seed: u64) -> Self {
+        Self { rng: StdRng::seed_from_u64(seed) }
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        items.choose(&mut self.rng).unwrap()
+    }
+
+    fn pick_many<'a, T>(&mut self, how_many: usize, items: &'a [T]) -> Vec<&'a T> {
+        items.choose_multiple(&mut self.rng, how_many.min(items.len())).collect()
+    }
+}
+
+impl Shuffler for RandomDM {
+    fn random_card(&mut self, n: usize) -> usize {
+        self.rng.gen_range(0..n)
+    }
+}
+
+impl DecisionMaker for RandomDM {
+    fn shuffler(&mut self) -> &mut dyn Shuffler { self }
+    fn flip(&mut self, number_of_coins: usize) -> Flips {
+        Flips::from_results((0..number_of_coins).map(|_| self.rng.gen()).collect())
+    }
+
+    fn confirm_setup_mulligan(&mut self, _p: Player) {}
+    fn confirm_setup_active_or_mulligan(&mut self, _p: Player, maybe: &Vec<Card>) -> SetupActiveSelection {
+        match maybe.get(self.rng.gen_range(0..=maybe.len())) {
+            Some(card) => SetupActiveSelection::Place(card.clone()),
+            None => SetupActiveSelection::Mulligan,
+        }
+    }
+    fn confirm_setup_active(&mut self, _p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card {
+        let candidates = yes.iter().chain(maybe.iter()).collect::<Vec<_>>();
+        (*self.pick(&candidates)).clone()
+    }
+    fn confirm_mulligan_draw(&mut self, _p: Player, upto: usize) -> usize { self.rng.gen_range(0..=upto) }
+    fn confirm_setup_bench_selection(&mut self, _p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> {
+        let how_many = self.rng.gen_range(0..=cards.len().min(upto));
+        self.pick_many(how_many, cards).into_iter().cloned().collect()
+    }
+    fn confirm_going_first(&mut self, _p: Player) -> bool { self.rng.gen() }
+    fn pick_type<'a>(&mut self, _p: Player, types: &'a Vec<Type>) -> &'a Type { self.pick(types) }
+    fn pick_move_damage_counters<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) { self.pick(possibilities) }
+    fn pick_attach_from_hand<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) { self.pick(possibilities) }
+    fn pick_attack<'a>(&mut self, _p: Player, attacks: &'a Vec<Attack>) -> &'a Attack { self.pick(attacks) }
+    fn pick_action<'a>(&mut self, _p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView { self.pick(actions) }
+    fn pick_stage<'a>(&mut self, _p: Player, items: &'a Vec<Stage>) -> &'a Stage { self.pick(items) }
+    fn pick_from_hand<'a>(&mut self, _p: Player, _whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> { self.pick_many(how_many, hand) }
+    fn pick_from_discard<'a>(&mut self, _p: Player, _whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.pick_many(how_many, searchable) }
+    fn pick_in_play<'a>(&mut self, _p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> { self.pick_many(how_many, searchable) }
+    fn pick_attached<'a>(&mut self, _p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        let how_many = self.rng.gen_range(*how_many.start()..=*how_many.end().max(how_many.start()));
+        self.pick_many(how_many, searchable)
+    }
+    fn pick_energy_discard<'a>(&mut self, _p: Player, _cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> { self.pick(options) }
+    fn pick_from_prizes<'a>(&mut self, _who: Player, _whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> { self.pick_many(how_many, searchable) }
+    fn search_deck<'a>(&mut self, _p: Player, _whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> { self.pick_many(how_many, deck) }
+    fn rearrange<'a>(&mut self, _p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> { self.pick_many(cards.len(), cards) }
+    fn accept_challenge(&mut self, _p: Player) -> bool { self.rng.gen() }
+    fn pick_player<'a>(&mut self, _p: Player, players: &'a [Player]) -> &'a Player { self.pick(players) }
+}
+
+// Plays one move ahead. On its turn it knocks out the Defending Pokémon if it
+// can, retreats a Pokémon that's about to be knocked out, evolves, attaches
+// energy where it gets an attack closer to being paid for, benches, plays
+// trainers, and otherwise attacks for as much damage as possible. Everything
+// else is left to a RandomDM. It tries moves out on a game rebuilt from its
+// view, with the cards it can't see made up, so it never peeks.
+pub struct GreedyDM {
+    format: Box<dyn Format>,
+    random: RandomDM,
+    views: [Option<PlayerView>; 2],
+    target: Option<InPlayID>,
+    powers_used: Vec<(usize, InPlayID, String)>,
+}
+
+impl GreedyDM {
+    pub fn new(format: Box<dyn Format>, seed: u64) -> Self {
+        Self {
+            format,
+            random: RandomDM::new(seed),
+            views: [None, None],
+            target: None,
+            powers_used: vec![],
+        }
+    }
+
+    fn engine(&self, player: Player) -> Option<GameEngine> {
+        let view = self.views[seat(player)].as_ref()?;
+        let guess = placeholder(self.format.as_ref(), view)?;
+
+        Some(GameEngine::from_state(view.determinize(&mut |_| guess.clone()), self.format.clone()))
+    }
+
+    // How much damage `attack` would do, and whether it would knock `defending` out.
+    fn try_attack(&mut self, engine: &GameEngine, attacking: &InPlayCard, defending: &InPlayCard, attack: &Attack, ignore_energy_cost: bool) -> (usize, bool) {
+        let mut engine = engine.clone();
+        engine.state = engine.state.with_stage(GameStage::Turn(attacking.owner));
+        let engine = engine
+            .push_action(Action::Attack(attacking.owner, attacking.clone(), attack.clone()))
+            .push_target(attacking, defending);
+
+        let builder = if ignore_energy_cost { attack.build().ignore_energy_cost() } else { attack.build() };
+        let after = builder.apply(engine, &mut self.random).engine();
+
+        match after.state.in_play(&defending.id) {
+            Some(now) => (now.damage_counters.saturating_sub(defending.damage_counters) * 10, after.remaining_hp(now) == 0),
+            None => (0, false),
+        }
+    }
+
+    // The most damage the opponent's Active Pokémon could do to `defending`
+    // next turn, with at most one more energy attached.
+    fn threat(&mut self, engine: &GameEngine, defending: &InPlayCard) -> usize {
+        let mut threat = 0;
+
+        for attacking in engine.state.side(defending.owner.opponent()).active.clone().iter() {
+            for attack in engine.attacks(attacking) {
+                if engine.missing_energy(&engine.attack_cost(attacking, &attack), engine.attached_energy(attacking)) <= 1 {
+                    threat = threat.max(self.try_attack(engine, attacking, defending, &attack, true).0);
+                }
+            }
+        }
+
+        threat
+    }
+
+    // How much closer attaching `card` gets `target` to paying for its attacks.
+    fn energy_progress(&self, engine: &GameEngine, card: &Card, target: &InPlayCard) -> usize {
+        let provided = engine.attached_energy(target);
+        let mut with_card = provided.clone();
+        with_card.extend(engine.provides(card));
+
+        engine.attacks(target).iter().map(|attack| {
+            let cost = engine.attack_cost(target, attack);
+            let (before, after) = (engine.missing_energy(&cost, provided.clone()), engine.missing_energy(&cost, with_card.clone()));
+
+            (before - after) * 10 + if before > 0 && after == 0 { 50 } else { 0 }
+        }).max().unwrap_or(0)
+    }
+
+    // Higher is better, with where to put the card or which Pokémon to
+    // retreat to for actions that ask.
+    fn score(&mut self, engine: &GameEngine, player: Player, action: &ActionView) -> (isize, Option<InPlayID>) {
+        match action {
+            ActionView::Attack(_, attacking, attack) => {
+                let attacking = engine.state.in_play(&attacking.id).unwrap().clone();
+                let defending = engine.state.side(player.opponent()).active[0].clone();
+                match self.try_attack(engine, &attacking, &defending, attack, false) {
+                    (_, true) => (1000, None),
+                    (damage, false) => (100 + damage as isize, None),
+                }
+            },
+            ActionView::Retreat(_, active) => {
+                let active = engine.state.in_play(&active.id).unwrap();
+                let remaining = engine.remaining_hp(active);
+                if remaining == 0 || self.threat(engine, active) < remaining {
+                    return (-1, None);
+                }
+
+                let mut safest = None;
+                for benched in engine.state.side(player).bench.clone().iter() {
+                    let margin = engine.remaining_hp(benched) as isize - self.threat(engine, benched) as isize;
+                    if margin > 0 && safest.is_none_or(|(best, _)| margin > best) {
+                        safest = Some((margin, benched.id));
+                    }
+                }
+
+                match safest {
+                    Some((_, id)) => (900, Some(id)),
+                    None => (-1, None),
+                }
+            },
+            ActionView::EvolveFromHand(_, card) => {
+                let targets = engine.evolution_targets(card);
+                let active = engine.state.side(player).active.iter().find(|active| targets.iter().any(|target| target.id == active.id));
+                (500, active.or(targets.first()).map(|target| target.id))
+            },
+            ActionView::AttachFromHand(_, card) => {
+                let mut best = (250, None);
+                for target in engine.attachment_from_hand_targets(player, card) {
+                    let progress = self.energy_progress(engine, card, &target);
+                    let active = engine.state.side(player).active.iter().any(|active| active.id == target.id);
+                    let score = if progress > 0 { 400 + progress as isize + if active { 5 } else { 0 } } else { 250 };
+
+                    if score > best.0 || best.1.is_none() {
+                        best = (score, Some(target.id));
+                    }
+                }
+                best
+            },
+            ActionView::BenchFromHand(_, _) => (300, None),
+            ActionView::TrainerFromHand(_, _) => (200, None),
+            ActionView::PokePower(_, in_play, power) => {
+                let used = self.powers_used.contains(&(engine.state.turn, in_play.id, power.name().clone()));
+                (if used { -1 } else { 150 }, None)
+            },
+            ActionView::Pass => (0, None),
+        }
+    }
+}
+
+// Something to stand in for cards nobody can see when all they need to be is
+// a card the format knows about: energy the player has already shown, or
+// failing that their Active Pokémon.
+fn placeholder(format: &dyn Format, view: &PlayerView) -> Option<String> {
+    let side = view.side(view.player);
+    let energy = side.cards().into_iter().find(|card| {
+        format.behavior_from_id(&card.archetype).map(|archetype| !archetype.provides().is_empty()).unwrap_or(false)
+    });
+
+    Some(energy.or_else(|| side.active.first()?.stack[0].as_ref())?.archetype.clone())
+}
+
+fn seat(player: Player) -> usize {
+    match player {
+        Player::One => 0,
+        Player::Two => 1,
+    }
+}
+
+impl DecisionMaker for GreedyDM {
+    fn observe(&mut self, view: &PlayerView) {
+        self.views[seat(view.player)] = Some(view.clone());
+    }
+
+    fn shuffler(&mut self) -> &mut dyn Shuffler { self.random.shuffler() }
+    fn flip(&mut self, number_of_coins: usize) -> Flips { self.random.flip(number_of_coins) }
+
+    fn confirm_setup_mulligan(&mut self, p: Player) { self.random.confirm_setup_mulligan(p) }
+    fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection { self.random.confirm_setup_active_or_mulligan(p, maybe) }
+    fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card { self.random.confirm_setup_active(p, yes, maybe) }
+    fn confirm_mulligan_draw(&mut self, _p: Player, upto: usize) -> usize { upto }
+    fn confirm_setup_bench_selection(&mut self, _p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> { cards.iter().take(upto).cloned().collect() }
+    fn confirm_going_first(&mut self, _p: Player) -> bool { true }
+    fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type { self.random.pick_type(p, types) }
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) { self.random.pick_move_damage_counters(p, possibilities) }
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) { self.random.pick_attach_from_hand(p, possibilities) }
+    fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack { self.random.pick_attack(p, attacks) }
+
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView {
+        let engine = match self.engine(p) {
+            Some(engine) => engine,
+            None => return self.random.pick_action(p, actions),
+        };
+
+        let mut best: Option<(isize, &ActionView, Option<InPlayID>)> = None;
+        for action in actions.iter() {
+            let (score, target) = self.score(&engine, p, action);
+            if best.is_none_or(|(best, _, _)| score > best) {
+                best = Some((score, action, target));
+            }
+        }
+
+        let (_, action, target) = best.unwrap();
+        self.target = target;
+        if let ActionView::PokePower(_, in_play, power) = action {
+            self.powers_used.push((engine.state.turn, in_play.id, power.name().clone()));
+        }
+
+        action
+    }
+
+    fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage { self.random.pick_stage(p, items) }
+    fn pick_from_hand<'a>(&mut self, p: Player, whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> { self.random.pick_from_hand(p, whose, how_many, hand) }
+    fn pick_from_discard<'a>(&mut self, p: Player, whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.random.pick_from_discard(p, whose, how_many, searchable) }
+
+    // Where the last action said to go if it's on offer, otherwise the
+    // opponent's weakest Pokémon or the player's own sturdiest.
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> {
+        if let Some(target) = self.target.take() {
+            if how_many == 1 {
+                if let Some(in_play) = searchable.iter().find(|in_play| in_play.id == target) {
+                    return vec![in_play];
+                }
+            }
+        }
+
+        let engine = match self.engine(p) {
+            Some(engine) => engine,
+            None => return self.random.pick_in_play(p, how_many, searchable),
+        };
+
+        let mut sorted = searchable.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|in_play| {
+            let hp = engine.state.in_play(&in_play.id).map_or(0, |in_play| engine.remaining_hp(in_play)) as isize;
+            if in_play.owner == p { -hp } else { hp }
+        });
+        sorted.truncate(how_many);
+        sorted
+    }
+
+    fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.random.pick_attached(p, how_many, searchable) }
+    fn pick_energy_discard<'a>(&mut self, _p: Player, _cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> {
+        // Keep as many cards attached as possible.
+        options.iter().min_by_key(|option| option.len()).unwrap()
+    }
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> { self.random.pick_from_prizes(who, whose, how_many, searchable) }
+    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> { self.random.search_deck(p, whose, how_many, deck) }
+    fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> { self.random.rearrange(p, cards) }
+    fn accept_challenge(&mut self, p: Player) -> bool { self.random.accept_challenge(p) }
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player { self.random.pick_player(p, players) }
+}
+
+// Picks actions by playing them out. Every iteration deals the cards the
+// player can't see (the opponent's hand, both decks, face-down prizes) anew,
+// drawing from the deck lists it was given minus the cards already in sight,
+// tries one of the available actions on that deal and plays the game on with
+// random moves for a few turns. Actions are tried by UCB1 on how those
+// rollouts went, and the one tried most wins. Any other decision is left to
+// a GreedyDM.
+pub struct MctsDM {
+    format: Box<dyn Format>,
+    greedy: GreedyDM,
+    rng: StdRng,
+    views: [Option<PlayerView>; 2],
+    decks: [Vec<String>; 2],
+    iterations: usize,
+    time_budget: Option<Duration>,
+    rollout_turns: usize,
+}
+
+impl MctsDM {
+    pub fn new(format: Box<dyn Format>, seed: u64) -> Self {
+        Self {
+            greedy: GreedyDM::new(format.clone(), seed),
+            format,
+            rng: StdRng::seed_from_u64(seed),
+            views: [None, None],
+            decks: [vec![], vec![]],
+            iterations: 200,
+            time_budget: None,
+            rollout_turns: 8,
+        }
+    }
+
+    // What each player is known or expected to play. Without a list, hidden
+    // cards are made up like GreedyDM does.
+    pub fn deck(mut self, player: Player, deck: &[String]) -> Self {
+        self.decks[seat(player)] = deck.to_vec();
+        self
+    }
+
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    // Stops searching after this long, even if there are iterations left.
+    pub fn time_budget(mut self, budget: Duration) -> Self {
+        self.time_budget = Some(budget);
+        self
+    }
+
+    pub fn rollout_turns(mut self, turns: usize) -> Self {
+        self.rollout_turns = turns;
+        self
+    }
+
+    fn determinize(&mut self, player: Player) -> Option<GameEngine> {
+        let view = self.views[seat(player)].as_ref()?;
+        let placeholder = placeholder(self.format.as_ref(), view)?;
+
+        let mut unseen = [Player::One, Player::Two].map(|owner| {
+            let mut pool = self.decks[seat(owner)].clone();
+            for card in view.side(owner).cards() {
+                if let Some(position) = pool.iter().position(|archetype| *archetype == card.archetype) {
+                    pool.remove(position);
+                }
+            }
+            pool.shuffle(&mut self.rng);
+            pool
+        });
+
+        let state = view.determinize(&mut |owner| unseen[seat(owner)].pop().unwrap_or_else(|| placeholder.clone()));
+        Some(GameEngine::from_state(state, self.format.clone()))
+    }
+
+    // Plays `action` and then random moves until the game is over or the
+    // rollout runs out of turns, scoring the result for `player` between 0
+    // and 1. Unfinished games are scored by the prizes left.
+    fn rollout(&mut self, engine: &GameEngine, player: Player, action: &str) -> f64 {
+        let mut dm = Rollout { action: Some(action.to_string()), random: RandomDM::new(self.rng.gen()) };
+        let mut engine = engine.step(&mut dm);
+        let limit = engine.state.turns.len() + self.rollout_turns;
+
+        while !engine.is_finished() && engine.state.turns.len() < limit {
+            engine = engine.step(&mut dm);
+        }
+
+        match engine.state.stage {
+            GameStage::Winner(winner) => if winner == player { 1.0 } else { 0.0 },
+            GameStage::Tie => 0.5,
+            _ => {
+                let left = |player: Player| engine.state.side(player).prizes.len() as f64;
+                0.5 + (left(player.opponent()) - left(player)) / 12.0
+            },
+        }
+    }
+}
+
+impl DecisionMaker for MctsDM {
+    fn observe(&mut self, view: &PlayerView) {
+        self.views[seat(view.player)] = Some(view.clone());
+        self.greedy.observe(view);
+    }
+
+    fn shuffler(&mut self) -> &mut dyn Shuffler { self.greedy.shuffler() }
+    fn flip(&mut self, number_of_coins: usize) -> Flips { self.greedy.flip(number_of_coins) }
+
+    fn confirm_setup_mulligan(&mut self, p: Player) { self.greedy.confirm_setup_mulligan(p) }
+    fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection { self.greedy.confirm_setup_active_or_mulligan(p, maybe) }
+    fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card { self.greedy.confirm_setup_active(p, yes, maybe) }
+    fn confirm_mulligan_draw(&mut self, p: Player, upto: usize) -> usize { self.greedy.confirm_mulligan_draw(p, upto) }
+    fn confirm_setup_bench_selection(&mut self, p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> { self.greedy.confirm_setup_bench_selection(p, upto, cards) }
+    fn confirm_going_first(&mut self, p: Player) -> bool { self.greedy.confirm_going_first(p) }
+    fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type { self.greedy.pick_type(p, types) }
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) { self.greedy.pick_move_damage_counters(p, possibilities) }
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) { self.greedy.pick_attach_from_hand(p, possibilities) }
+    fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack { self.greedy.pick_attack(p, attacks) }
+
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView {
+        if actions.len() == 1 {
+            return &actions[0];
+        }
+
+        let labels = actions.iter().map(Label::label).collect::<Vec<_>>();
+        let mut visits = vec![0usize; actions.len()];
+        let mut wins = vec![0f64; actions.len()];
+        let deadline = self.time_budget.map(|budget| Instant::now() + budget);
+
+        for iteration in 0..self.iterations {
+            if deadline.is_some_and(|deadline| Instant::now() > deadline) {
+                break;
+            }
+
+            let engine = match self.determinize(p) {
+                Some(engine) => engine,
+                None => return self.greedy.pick_action(p, actions),
+            };
+
+            let ucb = |i: usize| match visits[i] {
+                0 => f64::INFINITY,
+                n => wins[i] / n as f64 + (2.0 * (iteration as f64).ln() / n as f64).sqrt(),
+            };
+            let i = (0..actions.len()).max_by(|&a, &b| ucb(a).total_cmp(&ucb(b))).unwrap();
+
+            wins[i] += self.rollout(&engine, p, &labels[i]);
+            visits[i] += 1;
+        }
+
+        let best = (0..actions.len()).max_by_key(|&i| visits[i]).unwrap();
+        &actions[best]
+    }
+
+    fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage { self.greedy.pick_stage(p, items) }
+    fn pick_from_hand<'a>(&mut self, p: Player, whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> { self.greedy.pick_from_hand(p, whose, how_many, hand) }
+    fn pick_from_discard<'a>(&mut self, p: Player, whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.greedy.pick_from_discard(p, whose, how_many, searchable) }
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> { self.greedy.pick_in_play(p, how_many, searchable) }
+    fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.greedy.pick_attached(p, how_many, searchable) }
+    fn pick_energy_discard<'a>(&mut self, p: Player, cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> { self.greedy.pick_energy_discard(p, cost, options) }
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> { self.greedy.pick_from_prizes(who, whose, how_many, searchable) }
+    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> { self.greedy.search_deck(p, whose, how_many, deck) }
+    fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> { self.greedy.rearrange(p, cards) }
+    fn accept_challenge(&mut self, p: Player) -> bool { self.greedy.accept_challenge(p) }
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player { self.greedy.pick_player(p, players) }
+}
+
+// Random play for both players, except for the first action.
+struct Rollout {
+    action: Option<String>,
+    random: RandomDM,
+}
+
+impl DecisionMaker for Rollout {
+    fn shuffler(&mut self) -> &mut dyn Shuffler { self.random.shuffler() }
+    fn flip(&mut self, number_of_coins: usize) -> Flips { self.random.flip(number_of_coins) }
+
+    fn confirm_setup_mulligan(&mut self, p: Player) { self.random.confirm_setup_mulligan(p) }
+    fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection { self.random.confirm_setup_active_or_mulligan(p, maybe) }
+    fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card { self.random.confirm_setup_active(p, yes, maybe) }
+    fn confirm_mulligan_draw(&mut self, p: Player, upto: usize) -> usize { self.random.confirm_mulligan_draw(p, upto) }
+    fn confirm_setup_bench_selection(&mut self, p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> { self.random.confirm_setup_bench_selection(p, upto, cards) }
+    fn confirm_going_first(&mut self, p: Player) -> bool { self.random.confirm_going_first(p) }
+    fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type { self.random.pick_type(p, types) }
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) { self.random.pick_move_damage_counters(p, possibilities) }
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) { self.random.pick_attach_from_hand(p, possibilities) }
+    fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack { self.random.pick_attack(p, attacks) }
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView {
+        match self.action.take().and_then(|label| actions.iter().find(|action| action.label() == label)) {
+            Some(action) => action,
+            None => self.random.pick_action(p, actions),
+        }
+    }
+    fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage { self.random.pick_stage(p, items) }
+    fn pick_from_hand<'a>(&mut self, p: Player, whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> { self.random.pick_from_hand(p, whose, how_many, hand) }
+    fn pick_from_discard<'a>(&mut self, p: Player, whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.random.pick_from_discard(p, whose, how_many, searchable) }
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> { self.random.pick_in_play(p, how_many, searchable) }
+    fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.random.pick_attached(p, how_many, searchable) }
+    fn pick_energy_discard<'a>(&mut self, p: Player, cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> { self.random.pick_energy_discard(p, cost, options) }
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> { self.random.pick_from_prizes(who, whose, how_many, searchable) }
+    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> { self.random.search_deck(p, whose, how_many, deck) }
+    fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> { self.random.rearrange(p, cards) }
+    fn accept_challenge(&mut self, p: Player) -> bool { self.random.accept_challenge(p) }
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player { self.random.pick_player(p, players) }
+}
@@ -1,6 +1,7 @@
 use crate::engine::*;
 use crate::state::*;
 use crate::effect;
+use crate::effect::CustomEffect;
 use crate::custom_effects;
 
 #[derive(PartialEq, Eq)]
@@ -81,6 +82,10 @@ impl<'a> AttackBuilderContext<'a> {
         self.engine.defending()
     }
 
+    pub fn attack_cost(&self) -> &[Type] {
+        &self.attack_cost
+    }
+
     pub fn engine(&self) -> GameEngine {
         if self.failed {
             self.original.clone()
@@ -120,6 +125,10 @@ impl AttackBuilder {
 
     pub fn chain<'a>(&self, mut ctx: AttackBuilderContext<'a>) -> AttackBuilderContext<'a> {
         for operation in self.operations.iter() {
+            if ctx.failed {
+                break;
+            }
+
             let run_this_operation = match self.costs {
                 Costs::All => true,
                 Costs::IgnoreAllCosts => operation.optype == OperationType::Normal,
@@ -193,28 +202,57 @@ impl AttackBuilder {
 
     pub fn flip_a_coin(self) -> Self {
         self.add_operation(|mut builder| {
-            builder.flips.push(builder.dm.flip(1));
+            let flips;
+            (builder.engine, flips) = builder.engine.flip(builder.player(), 1, builder.dm);
+            builder.flips.push(flips);
             builder
         })
     }
 
     pub fn flip_coins(self, how_many: usize) -> Self {
         self.add_operation(move |mut builder| {
-            builder.flips.push(builder.dm.flip(how_many));
+            let flips;
+            (builder.engine, flips) = builder.engine.flip(builder.player(), how_many, builder.dm);
+            builder.flips.push(flips);
             builder
         })
     }
 
     pub fn damage(self, damage: usize) -> Self {
         self.add_operation(move |mut builder| {
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
+            builder
+        })
+    }
+
+    pub fn damage_ignoring_weakness_and_resistance(self, damage: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            (builder.engine, builder.damage_done) = builder.engine.damage_ignoring_weakness_and_resistance(damage, builder.dm);
+            builder
+        })
+    }
+
+    pub fn counterattack(self, damage: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let (attacking, defending) = (builder.attacking().clone(), builder.defending().clone());
+            builder.engine = builder.engine.push_target(&defending, &attacking);
+            (builder.engine, _) = builder.engine.damage(damage, builder.dm);
+            builder.engine = builder.engine.pop_target();
             builder
         })
     }
 
     pub fn damage_self(self, damage: usize) -> Self {
         self.add_operation(move |mut builder| {
-            (builder.engine, _) = builder.engine.damage_self(damage);
+            (builder.engine, _) = builder.engine.damage_self(damage, builder.dm);
+            builder
+        })
+    }
+
+    pub fn damage_self_per_tails(self, damage_per_tails: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let damage = damage_per_tails * builder.flips.last().unwrap().tails();
+            (builder.engine, _) = builder.engine.damage_self(damage, builder.dm);
             builder
         })
     }
@@ -243,6 +281,16 @@ impl AttackBuilder {
         })
     }
 
+    pub fn if_at_least_heads<F>(self, heads: usize, f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        self.add_operation(move |builder| {
+            if builder.heads() >= heads {
+                Self::wrap(builder, &f)
+            } else {
+                builder
+            }
+        })
+    }
+
     pub fn if_did_damage<F>(self, f: F) -> Self where F: Fn(Self) -> Self + 'static {
         self.add_operation(move |builder| {
             if builder.damage_done > 0 {
@@ -281,6 +329,52 @@ impl AttackBuilder {
         })
     }
 
+    pub fn opponent_must_have_benched_pokemon(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            if builder.engine.bench(builder.opponent()).is_empty() {
+                builder.failed = true;
+            }
+            builder
+        })
+    }
+
+    pub fn must_have_benched_pokemon(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            if builder.engine.bench(builder.player()).is_empty() {
+                builder.failed = true;
+            }
+            builder
+        })
+    }
+
+    pub fn must_have_bench_space(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            if !builder.engine.has_bench_space(builder.player()) {
+                builder.failed = true;
+            }
+            builder
+        })
+    }
+
+    pub fn attacking_must_have_damage_counters(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            if !builder.engine.has_damage_counters(builder.attacking()) {
+                builder.failed = true;
+            }
+            builder
+        })
+    }
+
+    pub fn discard_must_contain_energy_card(self, energy_type: Type) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            let engine = builder.engine.ensure_discard_contains(builder.player(), 1, |e, c| e.is_energy(c) && e.provides(c).contains(&energy_type));
+            if !engine.is_good() {
+                builder.failed = true;
+            }
+            builder
+        })
+    }
+
     pub fn defending_must_be_asleep(self) -> Self {
         self.add_operation(move |mut builder| {
             if !builder.defending().is_asleep() {
@@ -312,6 +406,106 @@ impl AttackBuilder {
         })
     }
 
+    pub fn each_pokemon_in_play_named<F>(self, names: &[&str], f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        let names = names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+        self.add_operation(move |mut builder| {
+            let names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+            let mut targets = builder.engine.in_play(builder.player());
+            targets.extend(builder.engine.in_play(builder.opponent()));
+            let targets = targets.into_iter().filter(|t| builder.engine.is_named(t, &names)).cloned().collect::<Vec<_>>();
+
+            for target in targets {
+                builder.engine = builder.engine.push_target(builder.attacking(), &target);
+                builder = Self::wrap(builder, &f);
+                builder.engine = builder.engine.pop_target();
+            }
+            builder
+        })
+    }
+
+    // Every benched Pokémon, on both sides, that shares a type with the Defending Pokémon. Colorless doesn't count.
+    pub fn each_bench_of_defending_type<F>(self, f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        self.add_operation(move |mut builder| {
+            let types = builder.engine.pokemon_types(builder.defending()).into_iter()
+                .filter(|t| *t != Type::Colorless)
+                .collect::<Vec<_>>();
+            let mut targets = builder.engine.bench(builder.player());
+            targets.extend(builder.engine.bench(builder.opponent()));
+            targets.retain(|t| builder.engine.pokemon_types(t).iter().any(|t| types.contains(t)));
+
+            for target in targets {
+                builder.engine = builder.engine.push_target(builder.attacking(), &target);
+                builder = Self::wrap(builder, &f);
+                builder.engine = builder.engine.pop_target();
+            }
+            builder
+        })
+    }
+
+    // Pairs the opponent's benched Pokémon with the last coin flips, one coin each.
+    pub fn each_opponents_bench_on_heads<F>(self, f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        self.add_operation(move |mut builder| {
+            let results = builder.flips.last().unwrap().results().to_vec();
+            for (target, heads) in builder.engine.bench(builder.opponent()).into_iter().zip(results) {
+                if heads {
+                    builder.engine = builder.engine.push_target(builder.attacking(), &target);
+                    builder = Self::wrap(builder, &f);
+                    builder.engine = builder.engine.pop_target();
+                }
+            }
+            builder
+        })
+    }
+
+    pub fn choose_opponents_pokemon<F>(self, f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        self.add_operation(move |mut builder| {
+            let possibilities = builder.engine.in_play(builder.opponent()).into_iter().cloned().collect::<Vec<_>>();
+            if possibilities.is_empty() {
+                return builder;
+            }
+
+            let target = builder.dm.pick_in_play_cards(builder.player(), 1, &possibilities)[0].clone();
+            builder.engine = builder.engine.push_target(builder.this_pokemon(), &target);
+            builder = Self::wrap(builder, &f);
+            builder.engine = builder.engine.pop_target();
+            builder
+        })
+    }
+
+    pub fn choose_opponents_benched_pokemon<F>(self, f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        self.add_operation(move |mut builder| {
+            let possibilities = builder.engine.bench(builder.opponent());
+            if possibilities.is_empty() {
+                return builder;
+            }
+
+            let target = builder.dm.pick_in_play_cards(builder.player(), 1, &possibilities)[0].clone();
+            builder.engine = builder.engine.push_target(builder.attacking(), &target);
+            builder = Self::wrap(builder, &f);
+            builder.engine = builder.engine.pop_target();
+            builder
+        })
+    }
+
+    pub fn choose_opponents_benched_pokemon_up_to<F>(self, how_many: usize, f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        self.add_operation(move |mut builder| {
+            let possibilities = builder.engine.bench(builder.opponent());
+            if possibilities.is_empty() {
+                return builder;
+            }
+
+            let how_many = how_many.min(possibilities.len());
+            let targets = builder.dm.pick_in_play_cards(builder.player(), how_many, &possibilities).into_iter().cloned().collect::<Vec<_>>();
+            for target in targets {
+                builder.engine = builder.engine.push_target(builder.attacking(), &target);
+                builder = Self::wrap(builder, &f);
+                builder.engine = builder.engine.pop_target();
+            }
+            builder
+        })
+    }
+
     pub fn with_effect(self, effect: Effect) -> Self {
         self.add_operation(move |mut builder| {
             builder.engine = builder.engine.with_effect(effect.clone());
@@ -340,6 +534,30 @@ impl AttackBuilder {
         })
     }
 
+    pub fn confuse_attacking(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.confuse(builder.engine.attacking());
+            builder
+        })
+    }
+
+    // For Pokémon Powers, where there's no attacking and defending Pokémon.
+    pub fn confuse_opponents_active(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let active = builder.engine.state.side(builder.opponent()).active[0].clone();
+            builder.engine = builder.engine.confuse(&active);
+            builder
+        })
+    }
+
+    pub fn confuse_own_active(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let active = builder.engine.state.side(builder.player()).active[0].clone();
+            builder.engine = builder.engine.confuse(&active);
+            builder
+        })
+    }
+
     pub fn poison(self) -> Self {
         self.add_operation(move |mut builder| {
             builder.engine = builder.engine.poison(builder.engine.defending(), 1);
@@ -347,6 +565,13 @@ impl AttackBuilder {
         })
     }
 
+    pub fn burn(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.burn(builder.engine.defending());
+            builder
+        })
+    }
+
     pub fn severe_poison(self, counters: usize) -> Self {
         self.add_operation(move |mut builder| {
             builder.engine = builder.engine.poison(builder.engine.defending(), counters);
@@ -385,6 +610,97 @@ impl AttackBuilder {
         })
     }
 
+    pub fn discard_attacking_energy_cards_per_heads(self, energy_type: Type) -> Self {
+        self.add_operation(move |mut builder| {
+            let energy_requirements = vec![energy_type.clone(); builder.heads()];
+            if energy_requirements.is_empty() {
+                return builder;
+            }
+
+            let (engine, result) = builder.engine.discard_attached_energy_cards(builder.player(), builder.engine.attacking(), &energy_requirements, builder.dm);
+
+            builder.engine = engine;
+            builder.results.push(result);
+            builder
+        })
+    }
+
+    pub fn if_discards_attacking_energy_card<F>(self, energy_type: Type, f: F) -> Self where F: Fn(Self) -> Self + 'static {
+        self.add_operation(move |mut builder| {
+            let energy_cards = builder.attacking().attached.iter()
+                .map(|a| a.card().clone())
+                .filter(|c| builder.engine.is_energy(c) && builder.engine.provides(c).contains(&energy_type))
+                .collect::<Vec<_>>();
+            if energy_cards.is_empty() {
+                return builder;
+            }
+
+            let chosen = builder.dm.pick_attached(builder.player(), 0..=1, &energy_cards).into_iter().cloned().collect::<Vec<_>>();
+            if chosen.is_empty() {
+                return builder;
+            }
+
+            builder.engine = builder.engine.remove_attached_cards(&chosen.iter().collect());
+            Self::wrap(builder, &f)
+        })
+    }
+
+    pub fn move_defending_basic_energy_to_opponents_bench(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let energy_cards = builder.defending().attached.iter()
+                .map(|a| a.card().clone())
+                .filter(|c| builder.engine.is_basic_energy(c))
+                .collect::<Vec<_>>();
+            let bench = builder.engine.bench(builder.opponent());
+            if energy_cards.is_empty() || bench.is_empty() {
+                return builder;
+            }
+
+            let card = builder.dm.pick_attached(builder.player(), 1..=1, &energy_cards)[0].clone();
+            let target = builder.dm.pick_in_play_cards(builder.player(), 1, &bench)[0].clone();
+            builder.engine = builder.engine.move_attached_card(&card, &target);
+            builder
+        })
+    }
+
+    pub fn move_attacking_energy_cards_to_own_bench(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let energy_cards = builder.attacking().attached.iter()
+                .map(|a| a.card().clone())
+                .filter(|c| builder.engine.is_energy(c))
+                .collect::<Vec<_>>();
+            let bench = builder.engine.bench(builder.player());
+            if bench.is_empty() {
+                return builder;
+            }
+
+            // TODO: let the player split the energy cards between benched Pokémon
+            for card in energy_cards {
+                let target = builder.dm.pick_in_play_cards(builder.player(), 1, &bench)[0].clone();
+                builder.engine = builder.engine.move_attached_card(&card, &target);
+            }
+            builder
+        })
+    }
+
+    pub fn discard_opponents_deck_per_discarded_attacking_energy_card(self, energy_type: Type) -> Self {
+        self.add_operation(move |mut builder| {
+            let energy_cards = builder.attacking().attached.iter()
+                .map(|a| a.card().clone())
+                .filter(|c| builder.engine.is_energy(c) && builder.engine.provides(c).contains(&energy_type))
+                .collect::<Vec<_>>();
+            if energy_cards.is_empty() {
+                return builder;
+            }
+
+            let chosen = builder.dm.pick_attached(builder.player(), 0..=energy_cards.len(), &energy_cards).into_iter().cloned().collect::<Vec<_>>();
+            builder.engine = builder.engine
+                .remove_attached_cards(&chosen.iter().collect())
+                .discard_from_top_of_deck(builder.opponent(), chosen.len(), builder.dm);
+            builder
+        })
+    }
+
     pub fn heal_all_attacking(self) -> Self {
         self.add_operation(move |mut builder| {
             builder.engine = builder.engine.heal_all(builder.attacking());
@@ -399,109 +715,456 @@ impl AttackBuilder {
         })
     }
 
-    pub fn damage_per_heads(self, damage_per_heads: usize) -> Self {
+    pub fn heal_attacking_damage_done(self) -> Self {
         self.add_operation(move |mut builder| {
-            let damage = damage_per_heads * builder.heads();
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+            builder.engine = builder.engine.heal(builder.attacking(), builder.damage_done);
             builder
         })
     }
 
-    pub fn damage_plus_per_energy_card_on_defending(self, base_damage: usize, damage_per_energy_card: usize) -> Self {
+    // rounded up to the nearest 10
+    pub fn heal_attacking_half_damage_done(self) -> Self {
         self.add_operation(move |mut builder| {
-            let energy_cards = builder.defending().attached.iter().filter(|c| builder.engine.is_energy(c.card())).count();
-            let damage = base_damage + damage_per_energy_card * energy_cards;
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+            let damage = (builder.damage_done / 2).div_ceil(10) * 10;
+            builder.engine = builder.engine.heal(builder.attacking(), damage);
             builder
         })
     }
 
-    pub fn damage_plus_per_damage_counter_on_defending(self, base_damage: usize, damage_per_counter: usize) -> Self {
+    pub fn damage_per_heads(self, damage_per_heads: usize) -> Self {
         self.add_operation(move |mut builder| {
-            let damage_counters = builder.engine.damage_counters_on(builder.defending());
-            let damage = base_damage + damage_per_counter * damage_counters;
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+            let damage = damage_per_heads * builder.heads();
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
             builder
         })
     }
 
-    pub fn damage_per_damage_counter_on_itself(self, damage_per_counter: usize) -> Self {
+    pub fn damage_per_tails(self, damage_per_tails: usize) -> Self {
         self.add_operation(move |mut builder| {
-            let damage_counters = builder.engine.damage_counters_on(builder.attacking());
-            let damage = damage_per_counter * damage_counters;
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+            let damage = damage_per_tails * builder.flips.last().unwrap().tails();
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
             builder
         })
     }
 
-    pub fn damage_minus_per_damage_counter_on_itself(self, base_damage: usize, damage_per_counter: usize) -> Self {
+    pub fn flip_coins_per_opponents_benched_pokemon(self) -> Self {
         self.add_operation(move |mut builder| {
-            let damage_counters = builder.engine.damage_counters_on(builder.attacking());
-            let damage = base_damage.saturating_sub(damage_counters * damage_per_counter);
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+            let how_many = builder.engine.bench(builder.opponent()).len();
+            let flips;
+            (builder.engine, flips) = builder.engine.flip(builder.player(), how_many, builder.dm);
+            builder.flips.push(flips);
             builder
         })
     }
 
-    pub fn damage_half_defending_remaining_hp(self) -> Self {
+    pub fn flip_coins_per_energy_card_on_attacking(self, energy_type: Type) -> Self {
         self.add_operation(move |mut builder| {
-            let remaining_hp = builder.engine.remaining_hp(builder.defending());
-            let damage = remaining_hp.div_ceil(2);
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+            let how_many = builder.attacking().attached.iter()
+                .filter(|a| builder.engine.is_energy(a.card()) && builder.engine.provides(a.card()).contains(&energy_type))
+                .count();
+            let flips;
+            (builder.engine, flips) = builder.engine.flip(builder.player(), how_many, builder.dm);
+            builder.flips.push(flips);
             builder
         })
     }
 
-    pub fn damage_plus_per_extra_energy_on_attacking(self, base_damage: usize, per_energy: usize, energy_type: Type, energy_limit: usize) -> Self {
+    pub fn flip_coins_per_energy_attached_to_attacking(self) -> Self {
         self.add_operation(move |mut builder| {
-            let mut additional = 0;
-            let mut requirements = builder.attack_cost.clone();
-            while additional < energy_limit {
-                requirements.push(energy_type.clone());
-                if !builder.engine.is_attack_energy_cost_met(builder.attacking(), &requirements) {
+            let how_many = builder.attacking().attached.iter()
+                .filter(|a| builder.engine.is_energy(a.card()))
+                .map(|a| builder.engine.provides(a.card()).len())
+                .sum();
+            let flips;
+            (builder.engine, flips) = builder.engine.flip(builder.player(), how_many, builder.dm);
+            builder.flips.push(flips);
+            builder
+        })
+    }
+
+    pub fn flip_until_tails(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let mut results = vec![];
+            loop {
+                let flips;
+                (builder.engine, flips) = builder.engine.flip(builder.player(), 1, builder.dm);
+                let Some(&heads) = flips.results().first() else { break };
+                results.push(heads);
+                if !heads {
                     break;
                 }
-                additional += 1;
             }
+            builder.flips.push(Flips::from_results(results));
+            builder
+        })
+    }
 
-            let damage = base_damage + additional * per_energy;
-            (builder.engine, builder.damage_done) = builder.engine.damage(damage);
+    pub fn damage_per_pokemon_in_play_named(self, damage_per_pokemon: usize, names: &[&str]) -> Self {
+        let names = names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+        self.add_operation(move |mut builder| {
+            let names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+            let pokemon = builder.engine.in_play(builder.player()).into_iter()
+                .chain(builder.engine.in_play(builder.opponent()))
+                .filter(|p| builder.engine.is_named(p, &names))
+                .count();
+            let damage = damage_per_pokemon * pokemon;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
             builder
         })
     }
 
-    pub fn switch_defending(self) -> Self {
+    pub fn damage_plus_per_own_benched_pokemon_named(self, base_damage: usize, damage_per_pokemon: usize, names: &[&str]) -> Self {
+        let names = names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
         self.add_operation(move |mut builder| {
-            builder.engine = builder.engine.switch(builder.opponent(), builder.dm);
+            let names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+            let pokemon = builder.engine.bench(builder.player()).iter()
+                .filter(|p| builder.engine.is_named(p, &names))
+                .count();
+            let damage = base_damage + damage_per_pokemon * pokemon;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
             builder
         })
     }
 
-    pub fn gust_defending(self) -> Self {
+    pub fn damage_plus_per_own_pokemon_in_play_named(self, base_damage: usize, damage_per_pokemon: usize, names: &[&str]) -> Self {
+        let names = names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
         self.add_operation(move |mut builder| {
-            builder.engine = builder.engine.gust(builder.player(), builder.dm);
+            let names = names.iter().map(|name| name.as_str()).collect::<Vec<_>>();
+            let pokemon = builder.engine.in_play(builder.player()).into_iter()
+                .filter(|p| builder.engine.is_named(p, &names))
+                .count();
+            let damage = base_damage + damage_per_pokemon * pokemon;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
             builder
         })
     }
 
-    pub fn knock_out_attacking(self) -> Self {
+    pub fn damage_plus_per_own_benched_pokemon(self, base_damage: usize, damage_per_pokemon: usize) -> Self {
         self.add_operation(move |mut builder| {
-            builder.engine = builder.engine.knock_out(&builder.attacking().clone(), builder.dm);
+            let pokemon = builder.engine.bench(builder.player()).len();
+            let damage = base_damage + damage_per_pokemon * pokemon;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
             builder
         })
     }
 
-    pub fn draw(self, how_many: usize) -> Self {
+    pub fn damage_plus_per_energy_card_on_defending(self, base_damage: usize, damage_per_energy_card: usize) -> Self {
         self.add_operation(move |mut builder| {
-            builder.engine = builder.engine.draw(builder.engine.player(), how_many, builder.dm);
+            let energy_cards = builder.defending().attached.iter().filter(|c| builder.engine.is_energy(c.card())).count();
+            let damage = base_damage + damage_per_energy_card * energy_cards;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
             builder
         })
     }
 
-    pub fn change_attacking_resistance_except(self, exceptions: &[Type]) -> Self {
-        let except_types = exceptions.iter().cloned().collect::<Vec<_>>();
+    pub fn damage_plus_per_damage_counter_on_defending(self, base_damage: usize, damage_per_counter: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let damage_counters = builder.engine.damage_counters_on(builder.defending());
+            let damage = base_damage + damage_per_counter * damage_counters;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
+            builder
+        })
+    }
 
-        self.add_operation(move |builder| {
+    pub fn damage_per_damage_counter_on_itself(self, damage_per_counter: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let damage_counters = builder.engine.damage_counters_on(builder.attacking());
+            let damage = damage_per_counter * damage_counters;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
+            builder
+        })
+    }
+
+    pub fn damage_plus_per_damage_counter_on_itself(self, base_damage: usize, damage_per_counter: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let damage_counters = builder.engine.damage_counters_on(builder.attacking());
+            let damage = base_damage + damage_per_counter * damage_counters;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
+            builder
+        })
+    }
+
+    pub fn damage_minus_per_damage_counter_on_itself(self, base_damage: usize, damage_per_counter: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let damage_counters = builder.engine.damage_counters_on(builder.attacking());
+            let damage = base_damage.saturating_sub(damage_counters * damage_per_counter);
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
+            builder
+        })
+    }
+
+    pub fn damage_half_defending_remaining_hp(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let remaining_hp = builder.engine.remaining_hp(builder.defending());
+            let damage = remaining_hp.div_ceil(2);
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
+            builder
+        })
+    }
+
+    pub fn damage_plus_per_extra_energy_on_attacking(self, base_damage: usize, per_energy: usize, energy_type: Type, energy_limit: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let mut additional = 0;
+            let mut requirements = builder.attack_cost.clone();
+            while additional < energy_limit {
+                requirements.push(energy_type.clone());
+                if !builder.engine.is_attack_energy_cost_met(builder.attacking(), &requirements) {
+                    break;
+                }
+                additional += 1;
+            }
+
+            let damage = base_damage + additional * per_energy;
+            (builder.engine, builder.damage_done) = builder.engine.damage(damage, builder.dm);
+            builder
+        })
+    }
+
+    // TODO: only the damage is mirrored, not the rest of the attack's effects
+    pub fn damage_taken_last_turn(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let attacking = builder.attacking().clone();
+            let damage = builder.engine.state.effects.iter()
+                .filter(|e| e.consequence == custom_effects::DamagedByAttack::identifier() && e.target.is_in_play(&attacking))
+                .filter(|e| matches!(e.source, EffectSource::Attack(p, _) if p != attacking.owner))
+                .filter_map(|e| e.get_parameter_usize(0))
+                .next_back()
+                .unwrap_or(0);
+            if damage > 0 {
+                (builder.engine, builder.damage_done) = builder.engine.damage_ignoring_weakness_and_resistance(damage, builder.dm);
+            }
+            builder
+        })
+    }
+
+    pub fn switch_defending(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.switch(builder.opponent(), builder.dm);
+            builder
+        })
+    }
+
+    pub fn gust_defending(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.gust(builder.player(), builder.dm);
+            builder
+        })
+    }
+
+    pub fn switch_attacking(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.switch(builder.player(), builder.dm);
+            builder
+        })
+    }
+
+    pub fn optionally_switch_attacking(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let bench = builder.engine.bench(builder.player());
+            if bench.is_empty() {
+                return builder;
+            }
+
+            // picking the active Pokémon itself means staying in place
+            let mut possibilities = vec![builder.attacking().clone()];
+            possibilities.extend(bench);
+            let chosen = builder.dm.pick_in_play_cards(builder.player(), 1, &possibilities)[0].clone();
+            if chosen.id != builder.attacking().id {
+                let attacking = builder.attacking().clone();
+                builder.engine = builder.engine.just_switch(builder.player(), &attacking, &chosen);
+            }
+            builder
+        })
+    }
+
+    pub fn shuffle_defending_into_deck(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.shuffle_into_deck(&builder.defending().clone(), |_, _| true);
+            builder
+        })
+    }
+
+    pub fn return_defending_to_hand_unless_knocked_out(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let defending = builder.defending().clone();
+            if builder.engine.remaining_hp(&defending) > 0 {
+                builder.engine = builder.engine.scoop_up(&defending, |_, _| true);
+            }
+            builder
+        })
+    }
+
+    pub fn shuffle_attacking_into_deck(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let attacking = builder.attacking().clone();
+            let stack = attacking.stack.iter().map(|c| c.card().clone()).collect::<Vec<_>>();
+            builder.engine = builder.engine.shuffle_into_deck(&attacking, |_, card| stack.contains(card));
+            builder
+        })
+    }
+
+    pub fn shuffle_opponents_deck(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.shuffle_deck(builder.opponent());
+            builder
+        })
+    }
+
+    pub fn evolve_attacking_from_deck(self, names: &[&str]) -> Self {
+        let names = names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+        self.add_operation(move |mut builder| {
+            let player = builder.player();
+            let deck = builder.engine.state.side(player).deck.cards().into_iter()
+                .filter(|c| builder.engine.is_pokemon(c) && names.contains(&builder.engine.archetype(c).name()))
+                .collect::<Vec<_>>();
+            if deck.is_empty() {
+                builder.engine = builder.engine.shuffle_deck(player);
+                return builder;
+            }
+
+            let chosen = builder.dm.search_deck_cards(player, player, 1, &deck)[0].clone();
+            builder.engine = builder.engine.evolve_from_deck(&builder.attacking().clone(), &chosen);
+            builder
+        })
+    }
+
+    pub fn attach_energy_from_deck_to_attacking(self, energy_type: Type) -> Self {
+        self.add_operation(move |mut builder| {
+            let player = builder.player();
+            let deck = builder.engine.state.side(player).deck.cards().into_iter()
+                .filter(|c| builder.engine.is_basic_energy(c) && builder.engine.provides(c).contains(&energy_type))
+                .collect::<Vec<_>>();
+            if deck.is_empty() {
+                builder.engine = builder.engine.shuffle_deck(player);
+                return builder;
+            }
+
+            let chosen = builder.dm.search_deck_cards(player, player, 1, &deck)[0].clone();
+            builder.engine = builder.engine.attach_from_deck(&chosen, &builder.attacking().clone());
+            builder
+        })
+    }
+
+    pub fn search_deck_to_bench<F>(self, how_many: usize, filter: F) -> Self where F: Fn(&GameEngine, &Card) -> bool + 'static {
+        self.add_operation(move |mut builder| {
+            let player = builder.player();
+            let deck = builder.engine.state.side(player).deck.cards().into_iter()
+                .filter(|c| filter(&builder.engine, c))
+                .collect::<Vec<_>>();
+            let bench_space = builder.engine.bench_size(player).saturating_sub(builder.engine.bench(player).len());
+            let how_many = how_many.min(deck.len()).min(bench_space);
+
+            if how_many > 0 {
+                let chosen = builder.dm.search_deck_cards(player, player, how_many, &deck).into_iter().cloned().collect::<Vec<_>>();
+                for card in chosen {
+                    builder.engine = builder.engine.bench_from_deck(player, &card);
+                }
+            }
+            builder.engine = builder.engine.shuffle_deck(player);
+            builder
+        })
+    }
+
+    pub fn energy_cards_from_discard_to_hand(self, how_many: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let player = builder.player();
+            let engine = builder.engine.clone();
+            let available = engine.state.side(player).discard.iter().filter(|c| engine.is_energy(c)).count();
+            let how_many = how_many.min(available);
+            if how_many > 0 {
+                builder.engine = builder.engine.search_discard_to_hand(player, how_many, |c| engine.is_energy(c), builder.dm);
+            }
+            builder
+        })
+    }
+
+    pub fn pokemon_cards_from_discard_to_hand(self, how_many: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let player = builder.player();
+            let engine = builder.engine.clone();
+            let available = engine.state.side(player).discard.iter().filter(|c| engine.is_pokemon(c)).count();
+            let how_many = how_many.min(available);
+            if how_many > 0 {
+                builder.engine = builder.engine.search_discard_to_hand(player, how_many, |c| engine.is_pokemon(c), builder.dm);
+            }
+            builder
+        })
+    }
+
+    pub fn search_deck_to_hand<F>(self, how_many: usize, filter: F) -> Self where F: Fn(&GameEngine, &Card) -> bool + 'static {
+        self.add_operation(move |mut builder| {
+            if builder.engine.state.side(builder.player()).deck.is_empty() {
+                builder.failed = true;
+                return builder;
+            }
+
+            builder.engine = builder.engine.search_deck_to_hand(builder.player(), how_many, &filter, builder.dm);
+            builder
+        })
+    }
+
+    pub fn discard_from_hand_to_draw(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            let side = builder.engine.state.side(builder.player());
+            if side.hand.is_empty() || side.deck.is_empty() {
+                builder.failed = true;
+            }
+            builder
+        })
+        .add_operation(move |mut builder| {
+            let player = builder.player();
+            let hand = builder.engine.state.side(player).hand.clone();
+            let discarded = builder.dm.pick_from_hand(player, player, 1, &hand)[0].clone();
+            builder.engine = builder.engine
+                .discard_from_hand(player, &discarded, builder.dm)
+                .draw(player, 1, builder.dm);
+            builder
+        })
+    }
+
+    pub fn trainer_card_from_discard_to_hand(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let player = builder.player();
+            let engine = builder.engine.clone();
+            if engine.state.side(player).discard.iter().any(|c| engine.is_trainer(c)) {
+                builder.engine = builder.engine.search_discard_to_hand(player, 1, |c| engine.is_trainer(c), builder.dm);
+            }
+            builder
+        })
+    }
+
+    pub fn rearrange_top_of_either_deck(self, how_many: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let players = vec![builder.player(), builder.opponent()];
+            let whose = *builder.dm.pick_player(builder.player(), &players);
+            let how_many = how_many.min(builder.engine.state.side(whose).deck.len());
+            builder.engine = builder.engine.rearrange_topdeck(builder.player(), whose, how_many, builder.dm);
+            builder
+        })
+    }
+
+    pub fn knock_out_attacking(self) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.knock_out(&builder.attacking().clone(), builder.dm);
+            builder
+        })
+    }
+
+    pub fn draw(self, how_many: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            builder.engine = builder.engine.draw(builder.engine.player(), how_many, builder.dm);
+            builder
+        })
+    }
+
+    pub fn change_attacking_resistance_except(self, exceptions: &[Type]) -> Self {
+        let except_types = exceptions.iter().cloned().collect::<Vec<_>>();
+
+        self.add_operation(move |builder| {
             let mut types = builder.engine.format.available_types();
             types.retain(|t| !except_types.contains(t));
             let chosen_type = builder.dm.pick_type(builder.player(), &types);
@@ -560,6 +1223,29 @@ impl AttackBuilder {
         })
     }
 
+    // For Pokémon Powers that are always on rather than used (eg: Kabuto
+    // Armor). They're never offered as actions, the engine looks them up
+    // with GameEngine::has_pokemon_power wherever they make a difference.
+    pub fn passive(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            builder.failed = true;
+            builder
+        })
+    }
+
+    // Pokémon Powers that can be used once during each turn.
+    pub fn once_per_turn(self) -> Self {
+        self.add_operation(move |builder| {
+            let effect = effect::from_poke_power()
+                .on_this_pokemon()
+                .until_end_of_turn()
+                .string_parameter(builder.engine.current_attack_name().unwrap())
+                .custom_effect::<custom_effects::DisablePokePower>();
+
+            effect.apply(builder)
+        })
+    }
+
     pub fn disabled_under_special_conditions(self) -> Self {
         self.add_operation(move |mut builder| {
             if !builder.engine.poke_power_affected_by_special_condition(builder.this_pokemon()) {
@@ -586,12 +1272,153 @@ impl AttackBuilder {
                 return builder;
             }
 
-            let choice = builder.dm.pick_move_damage_counters(builder.player(), &possibilities);
+            let choice = builder.dm.pick_move_damage_counters_between(builder.player(), &possibilities);
+            builder.engine = builder.engine.move_damage_counters(choice.0, choice.1, choice.2);
+            builder
+        })
+    }
+
+    pub fn move_own_damage_counter_to_this_pokemon_without_ko(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let possibilities = builder.engine
+                .move_damage_counter_possibilities()
+                .into_iter()
+                .filter(|(from, to, counters)| {
+                    from.owner == builder.player() &&
+                    to.id == builder.this_pokemon().id &&
+                    *counters == 1 &&
+                    builder.engine.remaining_hp(to) > 10
+                })
+                .collect::<Vec<_>>();
+
+            if possibilities.is_empty() {
+                builder.failed = true;
+                return builder;
+            }
+
+            let choice = builder.dm.pick_move_damage_counters_between(builder.player(), &possibilities);
+            builder.engine = builder.engine.move_damage_counters(choice.0, choice.1, choice.2);
+            builder
+        })
+    }
+
+    // even if it would knock out the other Pokémon
+    pub fn move_opponents_damage_counter(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let possibilities = builder.engine
+                .move_damage_counter_possibilities()
+                .into_iter()
+                .filter(|(from, to, counters)| {
+                    from.owner == builder.opponent() &&
+                    to.owner == builder.opponent() &&
+                    *counters == 1
+                })
+                .collect::<Vec<_>>();
+
+            if possibilities.is_empty() {
+                builder.failed = true;
+                return builder;
+            }
+
+            let choice = builder.dm.pick_move_damage_counters_between(builder.player(), &possibilities);
             builder.engine = builder.engine.move_damage_counters(choice.0, choice.1, choice.2);
             builder
         })
     }
 
+    pub fn must_have_damaged_pokemon(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            if builder.engine.healable_targets(builder.player()).is_empty() {
+                builder.failed = true;
+            }
+            builder
+        })
+    }
+
+    pub fn heal_own_pokemon(self, damage: usize) -> Self {
+        self.add_operation(move |mut builder| {
+            let targets = builder.engine.healable_targets(builder.player());
+            if targets.is_empty() {
+                builder.failed = true;
+                return builder;
+            }
+
+            let chosen = builder.dm.pick_in_play_cards(builder.player(), 1, &targets)[0].clone();
+            builder.engine = builder.engine.heal(&chosen, damage);
+            builder
+        })
+    }
+
+    pub fn switch_this_pokemon_from_bench(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let this_pokemon = builder.this_pokemon().clone();
+            if !builder.engine.bench(builder.player()).iter().any(|benched| benched.id == this_pokemon.id) {
+                builder.failed = true;
+                return builder;
+            }
+
+            let active = builder.engine.state.side(builder.player()).active[0].clone();
+            builder.engine = builder.engine.just_switch(builder.player(), &active, &this_pokemon);
+            builder
+        })
+    }
+
+    // the cards attached to it are discarded. If it's the Active Pokémon, it
+    // needs someone on the bench to take its place.
+    pub fn return_this_pokemon_to_hand(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let this_pokemon = builder.this_pokemon().clone();
+            let side = builder.engine.state.side(builder.player());
+            if side.bench.is_empty() && side.active.iter().any(|active| active.id == this_pokemon.id) {
+                builder.failed = true;
+                return builder;
+            }
+
+            let stack = this_pokemon.stack.iter().map(|c| c.card().clone()).collect::<Vec<_>>();
+            builder.engine = builder.engine.scoop_up(&this_pokemon, |_, card| stack.contains(card));
+            builder
+        })
+    }
+
+    pub fn not_the_turn_this_pokemon_was_put_in_play(self) -> Self {
+        self.add_requirement_operation(move |mut builder| {
+            if builder.this_pokemon().put_in_play_turn == builder.engine.state.turn {
+                builder.failed = true;
+            }
+            builder
+        })
+    }
+
+    // to the type of another Pokémon in play, other than Colorless, until the end of the turn.
+    pub fn change_this_pokemon_type(self) -> Self {
+        self.add_operation(move |mut builder| {
+            let this_pokemon = builder.this_pokemon().clone();
+            let current = builder.engine.pokemon_types(&this_pokemon);
+            let new_type = |engine: &GameEngine, in_play: &InPlayCard| {
+                engine.pokemon_types(in_play).into_iter().find(|t| *t != Type::Colorless && !current.contains(t))
+            };
+            let possibilities = builder.engine.state.all_in_play()
+                .into_iter()
+                .filter(|other| other.id != this_pokemon.id && new_type(&builder.engine, other).is_some())
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if possibilities.is_empty() {
+                builder.failed = true;
+                return builder;
+            }
+
+            let chosen = builder.dm.pick_in_play_cards(builder.player(), 1, &possibilities)[0].clone();
+            let new_type = new_type(&builder.engine, &chosen).unwrap();
+            effect::from_poke_power()
+                .on_this_pokemon()
+                .until_end_of_turn()
+                .type_parameter(new_type)
+                .custom_effect::<custom_effects::ChangeType>()
+                .apply(builder)
+        })
+    }
+
     pub fn attach_energy_from_hand(self, energy_type: Type, target_type: Type) -> Self {
         self.add_operation(move |mut builder| {
             let possibilities = builder.engine
@@ -611,7 +1438,7 @@ impl AttackBuilder {
                 return builder;
             }
 
-            let choice = builder.dm.pick_attach_from_hand(builder.player(), &possibilities);
+            let choice = builder.dm.pick_attach_from_hand_to(builder.player(), &possibilities);
             builder.engine = builder.engine.attach_from_hand(choice.0, choice.1);
             builder
         })
@@ -651,6 +1478,26 @@ impl AttackBuilder {
         self.add_operation(move |builder| effect.apply(builder))
     }
 
+    pub fn reduce_damage_during_opponents_next_turn(self, reduction: usize) -> Self {
+        let effect = effect::from_attack()
+            .on_attacking()
+            .until_opponents_end_of_turn()
+            .usize_parameter(reduction)
+            .custom_effect::<custom_effects::ReduceDamageDuringOpponentsTurn>();
+
+        self.add_operation(move |builder| effect.apply(builder))
+    }
+
+    pub fn reduce_defending_damage_during_opponents_next_turn(self, reduction: usize) -> Self {
+        let effect = effect::from_attack()
+            .on_defending()
+            .until_opponents_end_of_turn()
+            .usize_parameter(reduction)
+            .custom_effect::<custom_effects::ReduceDamageFromTargetDuringOpponentsTurn>();
+
+        self.add_operation(move |builder| effect.apply(builder))
+    }
+
     pub fn prevent_damage_and_effects_during_opponents_next_turn(self) -> Self {
         let effect = effect::from_attack()
             .on_attacking()
@@ -669,6 +1516,42 @@ impl AttackBuilder {
         self.add_operation(move |builder| effect.apply(builder))
     }
 
+    pub fn prevent_retreat_during_opponents_next_turn(self) -> Self {
+        let effect = effect::from_attack()
+            .on_defending()
+            .until_opponents_end_of_turn()
+            .custom_effect::<custom_effects::PreventRetreat>();
+
+        self.add_operation(move |builder| effect.apply(builder))
+    }
+
+    pub fn prevent_attacks_against_attacking_during_opponents_next_turn(self) -> Self {
+        let effect = effect::from_attack()
+            .on_defending()
+            .until_opponents_end_of_turn()
+            .custom_effect::<custom_effects::BlockAttacksAgainstSource>();
+
+        self.add_operation(move |builder| effect.apply(builder))
+    }
+
+    pub fn swords_dance(self) -> Self {
+        let effect = effect::from_attack()
+            .on_attacking()
+            .until_end_of_next_turn()
+            .custom_effect::<custom_effects::SwordsDance>();
+
+        self.add_operation(move |builder| effect.apply(builder))
+    }
+
+    pub fn reflect_damage_during_opponents_next_turn(self) -> Self {
+        let effect = effect::from_attack()
+            .on_attacking()
+            .until_opponents_end_of_turn()
+            .custom_effect::<custom_effects::ReflectDamageDuringOpponentsTurn>();
+
+        self.add_operation(move |builder| effect.apply(builder))
+    }
+
     pub fn knock_out_attacker_if_attacking_is_knocked_out_next_turn(self) -> Self {
         let effect = effect::from_attack()
             .on_attacking()
@@ -1,5 +1,6 @@
-use crate::state::*;
-use crate::engine::*;
+use ptcg_core::state::*;
+use ptcg_core::engine::*;
+use ptcg_core::view::PrizeView;
 
 #[derive(Default)]
 pub struct CLIDrawTarget {
@@ -38,7 +39,7 @@ pub trait CLIDrawable {
     fn draw(&self, x: usize, y: usize, target: &mut CLIDrawTarget);
 }
 
-impl CLIDrawable for PrizeCard {
+impl CLIDrawable for PrizeView {
     fn draw(&self, x: usize, y: usize, target: &mut CLIDrawTarget) {
         self.card.draw(x, y, target)
     }
@@ -47,13 +48,23 @@ impl CLIDrawable for PrizeCard {
 impl CLIDrawable for FaceCard {
     fn draw(&self, x: usize, y: usize, target: &mut CLIDrawTarget) {
         match &self {
-            FaceCard::Down(_) => {
+            FaceCard::Down(_) => None::<Card>.draw(x, y, target),
+            FaceCard::Up(c) => Some(c.clone()).draw(x, y, target),
+        }
+    }
+}
+
+// A card the viewer can't see is drawn face down.
+impl CLIDrawable for Option<Card> {
+    fn draw(&self, x: usize, y: usize, target: &mut CLIDrawTarget) {
+        match &self {
+            None => {
                 target.draw_line("|‾‾‾‾‾|", x, y);
                 target.draw_line("|  ?  |", x, y + 1);
                 target.draw_line("|  ?  |", x, y + 2);
                 target.draw_line("|_____|", x, y + 3);
             },
-            FaceCard::Up(c) => {
+            Some(c) => {
                 target.draw_line("|‾‾‾‾‾|", x, y);
                 target.draw_line(&format!("| {:3} |", &c.archetype[0..3]), x, y + 1);
                 target.draw_line(&format!("| {:3} |", &c.archetype[3..6]), x, y + 2);
@@ -80,34 +91,39 @@ pub fn energy_symbol(energy: &Type) -> String {
     }.into()
 }
 
-impl InPlayCard {
-    fn draw(&self, x: usize, y: usize, target: &mut CLIDrawTarget, engine: &GameEngine) {
-        self.stack[0].draw(x, y, target);
+fn draw_in_play(in_play: &InPlayCard, x: usize, y: usize, target: &mut CLIDrawTarget, engine: &GameEngine) {
+    in_play.stack[0].draw(x, y, target);
 
-        let energies = self.attached
-            .iter()
-            .filter(|card| card.is_up())
-            .flat_map(|card| engine.provides(card.card()))
-            .map(|energy_type| energy_symbol(&energy_type))
-            .collect::<Vec<_>>()
-            .join("");
+    let energies = in_play.attached
+        .iter()
+        .filter(|card| card.is_up())
+        .flat_map(|card| engine.provides(card.card()))
+        .map(|energy_type| energy_symbol(&energy_type))
+        .collect::<Vec<_>>()
+        .join("");
 
-        // "Defender (BS 80)" => "{Defender}",
-        target.draw_line(&energies, x, y - 1);
+    // "Defender (BS 80)" => "{Defender}",
+    target.draw_line(&energies, x, y - 1);
 
-        target.draw_line(&format!("{} HP", engine.remaining_hp(self)), x, y - 2);
+    target.draw_line(&format!("{} HP", engine.remaining_hp(in_play)), x, y - 2);
 
-        match self.rotational_status {
-            RotationalStatus::Paralyzed => { target.draw_line("* Paralyzed", x, y - 3); },
-            RotationalStatus::Asleep    => { target.draw_line("* Asleep", x, y - 3); },
-            RotationalStatus::Confused  => { target.draw_line("* Confused", x, y - 3); },
-            _ => {},
-        }
+    match in_play.rotational_status {
+        RotationalStatus::Paralyzed => { target.draw_line("* Paralyzed", x, y - 3); },
+        RotationalStatus::Asleep    => { target.draw_line("* Asleep", x, y - 3); },
+        RotationalStatus::Confused  => { target.draw_line("* Confused", x, y - 3); },
+        _ => {},
     }
 }
 
 impl CLIDrawable for GameEngine {
     fn draw(&self, x: usize, y: usize, target: &mut CLIDrawTarget) {
+        // hot seat: show the board to whoever is playing right now
+        let viewer = match self.state.stage {
+            GameStage::StartOfTurn(player) | GameStage::Turn(player) | GameStage::EndOfTurn(player) | GameStage::PokemonCheckup(player) => player,
+            _ => Player::One,
+        };
+        let view = self.view(viewer);
+
         target.draw_line("{     } {     }    {     } {     } {     } {     } {     }    |‾‾‾‾‾|", x, y +  8);
         target.draw_line("{  P  } {  P  }    {  B  } {  B  } {  B  } {  B  } {  B  }    |  U  |", x, y +  9);
         target.draw_line("{     } {     }    {     } {     } {     } {     } {     }    |     |", x, y + 10);
@@ -169,36 +185,39 @@ impl CLIDrawable for GameEngine {
             GameStage::Tie => {
                 target.draw_line("It's a tie!", 3, 24);
             },
+            GameStage::SuddenDeath => {
+                target.draw_line("Sudden Death!", 3, 24);
+            },
         }
 
         target.draw_line(&format!("{:3}", self.state.p1.deck.len()), x + 64, 35);
         target.draw_line(&format!("{:3}", self.state.p1.discard.len()), x + 64, 40);
         if !self.state.p1.active.is_empty() {
-            self.state.p1.active[0].draw(x + 35, y + 28, target, self);
+            draw_in_play(&self.state.p1.active[0], x + 35, y + 28, target, self);
         }
         for (i, benched) in self.state.p1.bench.iter().enumerate() {
-            benched.draw(x + 19 + i * 8, 38, target, self);
+            draw_in_play(benched, x + 19 + i * 8, 38, target, self);
         }
-        for (i, prize) in self.state.p1.prizes.iter().rev().enumerate() {
+        for (i, prize) in view.p1.prizes.iter().rev().enumerate() {
             prize.draw(x + 0 + (i%2) * 8, y + 28 + (i/2) * 5, target);
         }
-        for (i, card) in self.state.p1.hand.iter().enumerate() {
-            FaceCard::Up(card.clone()).draw(i*8, 43, target);
+        for (i, card) in view.p1.hand.iter().enumerate() {
+            card.draw(i*8, 43, target);
         }
 
         target.draw_line(&format!("{:3}", self.state.p2.deck.len()), x + 64, 15);
         target.draw_line(&format!("{:3}", self.state.p2.discard.len()), x + 64, 10);
         if !self.state.p2.active.is_empty() {
-            self.state.p2.active[0].draw(x + 35, y + 18, target, self);
+            draw_in_play(&self.state.p2.active[0], x + 35, y + 18, target, self);
         }
         for (i, benched) in self.state.p2.bench.iter().enumerate() {
-            benched.draw(x + 19 + i * 8, 8, target, self);
+            draw_in_play(benched, x + 19 + i * 8, 8, target, self);
         }
-        for (i, prize) in self.state.p2.prizes.iter().enumerate() {
+        for (i, prize) in view.p2.prizes.iter().enumerate() {
             prize.draw(x + 0 + (i%2) * 8, y + 8 + (i/2) * 5, target);
         }
-        for (i, card) in self.state.p2.hand.iter().enumerate() {
-            FaceCard::Up(card.clone()).draw(i*8, 1, target);
+        for (i, card) in view.p2.hand.iter().enumerate() {
+            card.draw(i*8, 1, target);
         }
 
         target.draw_line(&format!("Player One manual attachments: {}", self.state.p1.manual_attachments_this_turn), x + 80, 8);
@@ -1,22 +1,18 @@
-#![feature(int_roundings)]
-
 extern crate rand;
 use crate::rand::Rng;
 
-mod state;
-mod cli;
-mod engine;
-mod carddb;
-mod card_macros;
-mod attack_builder;
-mod effect;
-mod custom_effects;
-
-mod sets;
-mod formats;
+mod draw;
 
-use state::*;
-use engine::*;
+use ptcg_core::state::*;
+use ptcg_core::engine::*;
+use ptcg_core::formats;
+use ptcg_core::deck::DeckList;
+use ptcg_core::replay::Replay;
+use ptcg_core::simulation::{Contender, Simulation};
+use ptcg_core::ai::GreedyDM;
+use ptcg_core::observer::Observer;
+use ptcg_core::view::{ActionView, InPlayView, PrizeView};
+use draw::CLIDrawTarget;
 
 struct CLI {}
 
@@ -58,16 +54,30 @@ impl DecisionMaker for CLI {
         upto
     }
 
-    fn confirm_setup_bench_selection(&mut self, _p: Player, cards: &Vec<Card>) -> Vec<Card> {
-        cards.clone()
+    fn confirm_setup_bench_selection(&mut self, _p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> {
+        cards.iter().take(upto).cloned().collect()
     }
 
-    fn pick_attach_from_hand<'a>(&mut self, player: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayCard)>) -> &'a (&'a Card, &'a InPlayCard) {
+    fn confirm_going_first(&mut self, p: Player) -> bool {
+        println!("{:?} won the coin flip, do you want to go first? (y/n)", p);
+
+        loop {
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input).expect("Failed to read input");
+            match input.trim() {
+                "y" => return true,
+                "n" => return false,
+                _ => println!("Invalid answer."),
+            }
+        }
+    }
+
+    fn pick_attach_from_hand<'a>(&mut self, player: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) {
         Self::print_possibilities(player, &(1..=1), "attach from hand action", possibilities);
         &possibilities[Self::pick_number(possibilities)]
     }
 
-    fn pick_move_damage_counters<'a>(&mut self, player: Player, possibilities: &'a Vec<(&'a InPlayCard, &'a InPlayCard, usize)>) -> &'a (&'a InPlayCard, &'a InPlayCard, usize) {
+    fn pick_move_damage_counters<'a>(&mut self, player: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) {
         Self::print_possibilities(player, &(1..=1), "move damage counter action", possibilities);
         &possibilities[Self::pick_number(possibilities)]
     }
@@ -82,7 +92,7 @@ impl DecisionMaker for CLI {
         &possibilities[Self::pick_number(possibilities)]
     }
 
-    fn pick_action<'a>(&mut self, player: Player, possibilities: &'a Vec<Action>) -> &'a Action {
+    fn pick_action<'a>(&mut self, player: Player, possibilities: &'a Vec<ActionView>) -> &'a ActionView {
         Self::print_possibilities(player, &(1..=1), "action", possibilities);
         &possibilities[Self::pick_number(possibilities)]
     }
@@ -132,7 +142,7 @@ impl DecisionMaker for CLI {
         choice.unwrap()
     }
 
-    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a Vec<PrizeCard>) -> Vec<&'a PrizeCard> {
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> {
         let mut choice = None;
 
         Self::print_possibilities(who, &(how_many..=how_many), &format!("of Player {:?}'s prize card(s)", whose), searchable);
@@ -148,7 +158,7 @@ impl DecisionMaker for CLI {
         choice.unwrap()
     }
 
-    fn pick_in_play<'a>(&mut self, player: Player, how_many: usize, searchable: &'a Vec<InPlayCard>) -> Vec<&'a InPlayCard> {
+    fn pick_in_play<'a>(&mut self, player: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> {
         let mut choice = None;
 
         Self::print_possibilities(player, &(how_many..=how_many), "in play pokemon", searchable);
@@ -180,12 +190,17 @@ impl DecisionMaker for CLI {
         choice.unwrap()
     }
 
-    fn search_deck<'a>(&mut self, _player: Player, whose: Player, how_many: usize, deck: &'a Vec<Card>) -> Vec<&'a Card> {
+    fn pick_energy_discard<'a>(&mut self, player: Player, cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> {
+        Self::print_possibilities(player, &(1..=1), &format!("energy to discard for {:?}", cost), options);
+        &options[Self::pick_number(options)]
+    }
+
+    fn search_deck<'a>(&mut self, _player: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> {
         let mut choice = None;
 
         println!("Pick {} cards from {:?}'s deck:", how_many, whose);
         for (i, card) in deck.iter().enumerate() {
-            println!("{}. {}", i + 1, card.archetype);
+            println!("{}. {}", i + 1, card.as_ref().map_or("Face down card", |card| card.archetype.as_str()));
         }
 
         while choice.is_none() {
@@ -223,17 +238,36 @@ impl DecisionMaker for CLI {
 
         choice.iter().map(|x| &cards[*x]).collect()
     }
+
+    fn accept_challenge(&mut self, p: Player) -> bool {
+        println!("{:?}, do you accept the challenge? (y/n)", p);
+
+        loop {
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input).expect("Failed to read input");
+            match input.trim() {
+                "y" => return true,
+                "n" => return false,
+                _ => println!("Invalid answer."),
+            }
+        }
+    }
+
+    fn pick_player<'a>(&mut self, player: Player, possibilities: &'a [Player]) -> &'a Player {
+        Self::print_possibilities(player, &(1..=1), "player", possibilities);
+        &possibilities[Self::pick_number(possibilities)]
+    }
 }
 
 impl CLI {
-    fn print_possibilities<T: std::fmt::Debug>(player: Player, how_many: &std::ops::RangeInclusive<usize>, what: &str, possibilities: &Vec<T>) {
+    fn print_possibilities<T: std::fmt::Debug>(player: Player, how_many: &std::ops::RangeInclusive<usize>, what: &str, possibilities: &[T]) {
         println!("<Player {:?}>: pick {:?} {}:", player, how_many, what);
         for (i, card) in possibilities.iter().enumerate() {
             println!("{}. {:?}", i + 1, card);
         }
     }
 
-    fn pick_number<T>(possibilities: &Vec<T>) -> usize {
+    fn pick_number<T>(possibilities: &[T]) -> usize {
         let range = 1 ..= possibilities.len();
         let mut choice = None;
         while choice.is_none() {
@@ -249,45 +283,67 @@ impl CLI {
     }
 }
 
+// Draws the board after every step.
+struct CLIObserver {}
 
-use std::io::BufRead;
-fn load_deck(filename: &str) -> Result<Vec<String>, std::io::Error> {
-    let file = std::fs::File::open(filename)?;
-
-    let mut lines = vec![];
-    for line in std::io::BufReader::new(file).lines() {
-        match line {
-            Ok(line) => {
-                match line.split_once(" ") {
-                    None => { return Err(std::io::Error::new(std::io::ErrorKind::Other, "")); },
-                    Some((how_many, who)) => {
-                        match how_many.parse::<usize>() {
-                            Err(_) => { return Err(std::io::Error::new(std::io::ErrorKind::Other, "")); },
-                            Ok(how_many) => {
-                                for _ in 0..how_many {
-                                    lines.push(who.into());
-                                }
-                            }
-                        }
-                    }
+impl Observer for CLIObserver {
+    fn on_start(&mut self, engine: &GameEngine) {
+        CLIDrawTarget::print(engine);
+    }
+
+    fn on_step(&mut self, before: &GameEngine, after: &GameEngine) {
+        if let GameStage::PokemonCheckup(player) = before.state.stage {
+            for in_play in before.state.side(player).active.iter() {
+                let still_asleep = after.state.side(player).in_play(&in_play.id)
+                    .is_some_and(|now| now.rotational_status == RotationalStatus::Asleep);
+                if in_play.rotational_status == RotationalStatus::Asleep && !still_asleep {
+                    println!("{:?} woke up!", in_play);
                 }
-            },
-            Err(err) => {
-                return Err(err);
-            },
+            }
         }
-    }
 
-    Ok(lines)
+        CLIDrawTarget::print(after);
+    }
 }
 
 fn main() {
-    let _raindance = load_deck("decks/base-fossil-rain-dance.deck").unwrap();
-    let _arcanine_electrode = load_deck("decks/base-fossil-arcanine-electrode.deck").unwrap();
-    let random_cards = load_deck("decks/base-fossil-random-cards.deck").unwrap();
-
-    let state = GameState::initial(&random_cards, &random_cards);
+    let format = formats::BaseFossil::new();
+    let load_deck = |path: &str| match DeckList::load(std::path::Path::new(path), format.registry()) {
+        Ok(deck) => deck.cards(),
+        Err(err) => panic!("{}: {}", path, err),
+    };
+    let raindance = load_deck("decks/base-fossil-rain-dance.deck");
+    let arcanine_electrode = load_deck("decks/base-fossil-arcanine-electrode.deck");
+    let random_cards = load_deck("decks/base-fossil-random-cards.deck");
+
+    let args = std::env::args().collect::<Vec<_>>();
+    match (args.get(1).map(String::as_str), args.get(2)) {
+        (Some("--replay"), Some(path)) => {
+            let replay = Replay::load(std::path::Path::new(path)).unwrap();
+            replay.run().unwrap();
+        },
+        (Some("--simulate"), Some(games)) => {
+            let ai = || -> Contender { Box::new(|_, seed| Box::new(GreedyDM::new(Box::new(formats::BaseFossil::new()), seed))) };
+            let simulation = Simulation::new(|| Box::new(formats::BaseFossil::new()), (&raindance, ai()), (&arcanine_electrode, ai()))
+                .games(games.parse().unwrap());
+
+            println!("{}", simulation.run().unwrap());
+        },
+        (Some("--record"), Some(path)) => {
+            let state = GameState::initial(&random_cards, &random_cards, &format, true).unwrap();
+            let engine = GameEngine::from_state(state, Box::new(format.clone()));
+
+            let (result, replay) = Replay::record(&engine, &mut CLI { }, &mut CLIObserver { });
+            replay.save(std::path::Path::new(path)).unwrap();
+
+            if let Err(panic) = result {
+                std::panic::resume_unwind(panic);
+            }
+        },
+        _ => {
+            let state = GameState::initial(&random_cards, &random_cards, &format, true).unwrap();
 
-    GameEngine::from_state(state, Box::new(formats::BaseFossil::new()))
-        .play(&mut CLI { });
+            GameEngine::from_state(state, Box::new(format.clone())).play(&mut CLI { }, &mut CLIObserver { });
+        },
+    }
 }
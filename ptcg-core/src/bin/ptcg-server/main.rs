@@ -0,0 +1,33 @@
+use ptcg_core::state::*;
+use ptcg_core::engine::*;
+use ptcg_core::formats;
+use ptcg_core::deck::DeckList;
+use ptcg_core::observer::Headless;
+use ptcg_core::server::RemoteDM;
+
+// ptcg-server [address] [player one's deck] [player two's deck]
+fn main() {
+    let args = std::env::args().collect::<Vec<_>>();
+    let address = args.get(1).map(String::as_str).unwrap_or("127.0.0.1:7878");
+    let deck_one = args.get(2).map(String::as_str).unwrap_or("decks/base-fossil-random-cards.deck");
+    let deck_two = args.get(3).map(String::as_str).unwrap_or(deck_one);
+
+    let format = formats::BaseFossil::new();
+    let load_deck = |path: &str| match DeckList::load(std::path::Path::new(path), format.registry()) {
+        Ok(deck) => deck.cards(),
+        Err(err) => panic!("{}: {}", path, err),
+    };
+
+    let state = GameState::initial(&load_deck(deck_one), &load_deck(deck_two), &format, true).unwrap();
+    let engine = GameEngine::from_state(state, Box::new(format.clone()));
+
+    let mut dm = RemoteDM::bind(address).unwrap();
+    println!("waiting for players on {}", dm.local_addr().unwrap());
+    if let Err(err) = dm.wait_for_players() {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+
+    let engine = dm.play(&engine, &mut Headless::default());
+    println!("{:?}", engine.state.stage);
+}
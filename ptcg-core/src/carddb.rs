@@ -19,6 +19,9 @@ pub trait TrainerCardArchetype {
     fn execute(&self, player: Player, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine;
     fn identifier(&self) -> String;
     fn name(&self) -> String;
+    fn is_supporter(&self) -> bool {
+        false
+    }
     fn cost(&self, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
         engine.clone()
     }
@@ -35,6 +38,9 @@ pub trait TrainerCardArchetype {
     fn on_turn_end(&self, _card: &Card, _engine: &GameEngine) -> Option<GameEngine> {
         None
     }
+    fn on_pokemon_checkup(&self, _card: &Card, _engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        None
+    }
 }
 
 pub struct Trainer {
@@ -77,6 +83,9 @@ impl CardArchetype for Trainer {
     fn is_trainer(&self, _card: &Card, _engine: &GameEngine) -> bool {
         true
     }
+    fn is_supporter(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        self.archetype.is_supporter()
+    }
     fn evolves_from(&self) -> Option<String> {
         None
     }
@@ -101,4 +110,96 @@ impl CardArchetype for Trainer {
     fn on_turn_end(&self, card: &Card, engine: &GameEngine) -> Option<GameEngine> {
         self.archetype.on_turn_end(card, engine)
     }
+    fn on_pokemon_checkup(&self, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        self.archetype.on_pokemon_checkup(card, engine, dm)
+    }
+}
+
+// A card reprinted under a new identifier (eg: Base Set 2), behaving exactly
+// like the original printing.
+pub struct Reprint {
+    identifier: String,
+    original: Box<dyn CardArchetype>,
+}
+impl Reprint {
+    pub fn create(identifier: &str, original: Box<dyn CardArchetype>) -> Box<dyn CardArchetype> {
+        Box::new(Self { identifier: identifier.into(), original })
+    }
+}
+impl CardArchetype for Reprint {
+    fn identifier(&self) -> String {
+        self.identifier.clone()
+    }
+    fn card_actions(&self, player: Player, card: &Card, engine: &GameEngine) -> Vec<Action> {
+        self.original.card_actions(player, card, engine)
+    }
+    fn execute(&self, player: Player, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        self.original.execute(player, card, engine, dm)
+    }
+    fn stage(&self) -> Option<Stage> {
+        self.original.stage()
+    }
+    fn evolves_from(&self) -> Option<String> {
+        self.original.evolves_from()
+    }
+    fn attacks(&self) -> Vec<Attack> {
+        self.original.attacks()
+    }
+    fn poke_powers(&self) -> Vec<Attack> {
+        self.original.poke_powers()
+    }
+    fn provides(&self) -> Vec<Type> {
+        self.original.provides()
+    }
+    fn hp(&self, card: &Card, engine: &GameEngine) -> Option<usize> {
+        self.original.hp(card, engine)
+    }
+    fn weakness(&self) -> Weakness {
+        self.original.weakness()
+    }
+    fn resistance(&self) -> Resistance {
+        self.original.resistance()
+    }
+    fn pokemon_type(&self) -> Vec<Type> {
+        self.original.pokemon_type()
+    }
+    fn name(&self) -> String {
+        self.original.name()
+    }
+    fn retreat(&self) -> usize {
+        self.original.retreat()
+    }
+    fn is_pokemon(&self, card: &Card, engine: &GameEngine) -> bool {
+        self.original.is_pokemon(card, engine)
+    }
+    fn is_trainer(&self, card: &Card, engine: &GameEngine) -> bool {
+        self.original.is_trainer(card, engine)
+    }
+    fn is_supporter(&self, card: &Card, engine: &GameEngine) -> bool {
+        self.original.is_supporter(card, engine)
+    }
+    fn is_energy(&self, card: &Card, engine: &GameEngine) -> bool {
+        self.original.is_energy(card, engine)
+    }
+    fn attachable_as_energy_for_turn(&self, card: &Card, engine: &GameEngine) -> bool {
+        self.original.attachable_as_energy_for_turn(card, engine)
+    }
+    fn on_attach_from_hand(&self, card: &Card, target: &InPlayCard, engine: &GameEngine) -> Option<GameEngine> {
+        self.original.on_attach_from_hand(card, target, engine)
+    }
+    fn defending_damage_effect(&self, card: &Card, engine: &GameEngine, damage: usize) -> Option<usize> {
+        self.original.defending_damage_effect(card, engine, damage)
+    }
+    fn attacking_damage_effect(&self, card: &Card, engine: &GameEngine, damage: usize) -> Option<usize> {
+        self.original.attacking_damage_effect(card, engine, damage)
+    }
+    fn on_turn_end(&self, card: &Card, engine: &GameEngine) -> Option<GameEngine> {
+        self.original.on_turn_end(card, engine)
+    }
+    fn on_pokemon_checkup(&self, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        self.original.on_pokemon_checkup(card, engine, dm)
+    }
+    fn on_play_from_hand(&self, in_play: &InPlayCard, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        self.original.on_play_from_hand(in_play, engine, dm)
+    }
 }
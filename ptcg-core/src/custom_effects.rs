@@ -1,6 +1,6 @@
 use crate::attack_builder::AttackBuilder;
 use crate::effect::CustomEffect;
-use crate::state::{Effect, Player, InPlayCard, Type, EffectTarget, Card};
+use crate::state::{Effect, Player, InPlayCard, Type, EffectTarget, EffectSource, Card};
 use crate::engine::{GameEngine, Resistance, Weakness, Attack};
 
 // TODO: the attackbuilder builds a bunch of objects, maybe it
@@ -110,7 +110,6 @@ impl CustomEffect for RevengeKnockOut {
 
         // TODO: Revenge knock out: Sky Return?
         // TODO: Revenge knock out: Quick shooting?
-        println!("knocked out? {} && {}", engine.is_someone_attacking(), effect.target.is_player(engine.player()));
         if opponents_turn && this_pokemon && engine.is_someone_attacking() {
             AttackBuilder::new()
                 .knock_out_attacking()
@@ -211,3 +210,211 @@ impl CustomEffect for EnergyTypeTransform {
         }
     }
 }
+
+pub struct ReflectDamageDuringOpponentsTurn {}
+impl CustomEffect for ReflectDamageDuringOpponentsTurn {
+    fn identifier() -> String {
+        "REFLECT_DAMAGE_DURING_OPPONENTS_TURN".into()
+    }
+
+    fn on_damaged(&self, effect: &Effect, in_play: &InPlayCard, engine: &GameEngine, damage: usize) -> Option<AttackBuilder> {
+        if !engine.is_someone_attacking() {
+            return None;
+        }
+
+        let opponents_turn = !effect.target.is_player(engine.player());
+        let this_pokemon = effect.target.is_in_play(in_play);
+
+        if opponents_turn && this_pokemon {
+            AttackBuilder::new()
+                .counterattack(damage)
+                .into()
+        } else {
+            None
+        }
+    }
+}
+
+pub struct BlockPokemonPowers {}
+impl CustomEffect for BlockPokemonPowers {
+    fn identifier() -> String {
+        "BLOCK_POKEMON_POWERS".into()
+    }
+
+    fn get_poke_powers(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine, _poke_powers: Vec<Attack>) -> Option<Vec<Attack>> {
+        Some(vec![])
+    }
+}
+
+pub struct DisablePokePower {}
+impl CustomEffect for DisablePokePower {
+    fn identifier() -> String {
+        "DISABLE_POKE_POWER".into()
+    }
+
+    fn get_poke_powers(&self, effect: &Effect, in_play: &InPlayCard, _engine: &GameEngine, poke_powers: Vec<Attack>) -> Option<Vec<Attack>> {
+        let disabled = effect.get_parameter_string(0).unwrap();
+        let this_pokemon = effect.target.is_in_play(in_play);
+
+        if this_pokemon {
+            Some(poke_powers.into_iter().filter(|p| p.name() != &disabled).collect())
+        } else {
+            None
+        }
+    }
+}
+
+pub struct ChangeType {}
+impl CustomEffect for ChangeType {
+    fn identifier() -> String {
+        "CHANGE_TYPE".into()
+    }
+
+    fn get_types(&self, effect: &Effect, in_play: &InPlayCard, _engine: &GameEngine, _types: Vec<Type>) -> Option<Vec<Type>> {
+        let new_type = effect.get_parameter_type(0).unwrap();
+        let this_pokemon = effect.target.is_in_play(in_play);
+
+        if this_pokemon {
+            Some(vec![new_type])
+        } else {
+            None
+        }
+    }
+}
+
+pub struct ReduceDamageDuringOpponentsTurn {}
+impl CustomEffect for ReduceDamageDuringOpponentsTurn {
+    fn identifier() -> String {
+        "REDUCE_DAMAGE_DURING_OPPONENTS_TURN".into()
+    }
+
+    fn defending_damage(&self, effect: &Effect, in_play: &InPlayCard, engine: &GameEngine, damage: usize) -> Option<usize> {
+        let opponents_turn = !effect.target.is_player(engine.player());
+        let this_pokemon = effect.target.is_in_play(in_play);
+
+        let reduction = effect.get_parameter_usize(0).unwrap();
+        if opponents_turn && this_pokemon {
+            Some(damage.saturating_sub(reduction))
+        } else {
+            None
+        }
+    }
+}
+
+// Placed on the Defending Pokémon: whatever it attacks next turn takes less damage.
+pub struct ReduceDamageFromTargetDuringOpponentsTurn {}
+impl CustomEffect for ReduceDamageFromTargetDuringOpponentsTurn {
+    fn identifier() -> String {
+        "REDUCE_DAMAGE_FROM_TARGET_DURING_OPPONENTS_TURN".into()
+    }
+
+    fn defending_damage(&self, effect: &Effect, _in_play: &InPlayCard, engine: &GameEngine, damage: usize) -> Option<usize> {
+        if !engine.is_someone_attacking() {
+            return None;
+        }
+
+        let reduction = effect.get_parameter_usize(0).unwrap();
+        if effect.target.is_in_play(engine.attacking()) {
+            Some(damage.saturating_sub(reduction))
+        } else {
+            None
+        }
+    }
+}
+
+// Placed on the Defending Pokémon: it can't attack the Pokémon that caused
+// the effect while that one is still the active Pokémon (Tail Wag, Leer).
+pub struct BlockAttacksAgainstSource {}
+impl CustomEffect for BlockAttacksAgainstSource {
+    fn identifier() -> String {
+        "BLOCK_ATTACKS_AGAINST_SOURCE".into()
+    }
+
+    fn get_attacks(&self, effect: &Effect, in_play: &InPlayCard, engine: &GameEngine, _attacks: Vec<Attack>) -> Option<Vec<Attack>> {
+        let source = match effect.source {
+            EffectSource::Attack(_, id) => id,
+            _ => { return None; },
+        };
+
+        let this_pokemon = effect.target.is_in_play(in_play);
+        let source_is_active = engine.state.side(in_play.owner.opponent()).active.iter().any(|a| a.id == source);
+
+        if this_pokemon && source_is_active {
+            Some(vec![])
+        } else {
+            None
+        }
+    }
+}
+
+pub struct PreventRetreat {}
+impl CustomEffect for PreventRetreat {
+    fn identifier() -> String {
+        "PREVENT_RETREAT".into()
+    }
+
+    fn can_retreat(&self, effect: &Effect, in_play: &InPlayCard, _engine: &GameEngine) -> Option<bool> {
+        if effect.target.is_in_play(in_play) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct SwordsDance {}
+impl CustomEffect for SwordsDance {
+    fn identifier() -> String {
+        "SWORDS_DANCE".into()
+    }
+
+    fn get_attacks(&self, effect: &Effect, in_play: &InPlayCard, _engine: &GameEngine, attacks: Vec<Attack>) -> Option<Vec<Attack>> {
+        if !effect.target.is_in_play(in_play) {
+            return None;
+        }
+
+        Some(attacks.into_iter().map(|attack| {
+            if attack.name() == "Slash" {
+                Attack::new("Slash", Self::slash)
+            } else {
+                attack
+            }
+        }).collect())
+    }
+}
+impl SwordsDance {
+    fn slash(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage(60)
+    }
+}
+
+// Remembers how much damage an attack did to a Pokémon until the end of its
+// owner's next turn (Mirror Move).
+pub struct DamagedByAttack {}
+impl CustomEffect for DamagedByAttack {
+    fn identifier() -> String {
+        "DAMAGED_BY_ATTACK".into()
+    }
+}
+
+pub struct Recall {}
+impl CustomEffect for Recall {
+    fn identifier() -> String {
+        "RECALL".into()
+    }
+
+    fn get_attacks(&self, effect: &Effect, in_play: &InPlayCard, engine: &GameEngine, mut attacks: Vec<Attack>) -> Option<Vec<Attack>> {
+        if !effect.target.is_in_play(in_play) {
+            return None;
+        }
+
+        let archetype = effect.get_parameter_string(0).unwrap();
+        let attack_name = effect.get_parameter_string(1).unwrap();
+        let recalled = engine.format.behavior_from_id(&archetype).ok()?.attacks().into_iter().find(|a| a.name() == &attack_name)?;
+
+        attacks.push(recalled);
+        Some(attacks)
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+use crate::engine::Stage;
+use crate::registry::{CardRegistry, UnknownCard};
+use crate::state::Player;
+
+pub const DECK_SIZE: usize = 60;
+pub const MAX_COPIES: usize = 4;
+
+// Any number of these can go in a deck.
+const BASIC_ENERGY: &[&str] = &[
+    "Fighting Energy",
+    "Fire Energy",
+    "Grass Energy",
+    "Lightning Energy",
+    "Psychic Energy",
+    "Water Energy",
+];
+
+#[derive(Debug)]
+pub enum DeckError {
+    Io(std::io::Error),
+    Syntax(usize, String),
+    UnknownCard(usize, UnknownCard),
+    NameMismatch(usize, String, String),
+}
+
+impl std::fmt::Display for DeckError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            DeckError::Io(err) => write!(f, "couldn't read deck: {}", err),
+            DeckError::Syntax(line, message) => write!(f, "line {}: {}", line, message),
+            DeckError::UnknownCard(line, err) => write!(f, "line {}: {}", line, err),
+            DeckError::NameMismatch(line, identifier, name) => write!(f, "line {}: {} is not a {}", line, identifier, name),
+        }
+    }
+}
+
+impl std::error::Error for DeckError {}
+
+impl From<std::io::Error> for DeckError {
+    fn from(err: std::io::Error) -> Self {
+        DeckError::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Pokemon,
+    Trainer,
+    Energy,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckEntry {
+    pub count: usize,
+    pub archetype: String,
+    pub section: Option<Section>,
+}
+
+// A deck list resolved against a format's cards. Understands the PTCGO and
+// PTCGL exports ("4 Abra BS 43" under "Pokémon:"/"##Pokémon - 12" headers) as
+// well as the older "4 Abra (BS 43)" lines, with '#' and '//' comments.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeckList {
+    pub entries: Vec<DeckEntry>,
+}
+
+impl DeckList {
+    pub fn parse(text: &str, registry: &CardRegistry) -> Result<Self, DeckError> {
+        let mut deck = Self::default();
+        let mut section = None;
+
+        for (index, line) in text.lines().enumerate() {
+            let number = index + 1;
+            let line = line.trim();
+            let line = line.strip_prefix("* ").unwrap_or(line);
+
+            if let Some(header) = Self::section_header(line) {
+                section = Some(header);
+                continue;
+            }
+
+            if line.is_empty() || line.starts_with('#') || line.starts_with("//") || line.starts_with("***") || line.starts_with("Total Cards") {
+                continue;
+            }
+
+            let (count, card) = line
+                .split_once(' ')
+                .ok_or_else(|| DeckError::Syntax(number, format!("expected a count and a card, got {:?}", line)))?;
+            let count = match count.parse::<usize>() {
+                Ok(count) if count > 0 => count,
+                _ => return Err(DeckError::Syntax(number, format!("expected a card count, got {:?}", count))),
+            };
+
+            let archetype = Self::resolve(number, card.trim(), registry)?;
+            deck.entries.push(DeckEntry { count, archetype, section });
+        }
+
+        Ok(deck)
+    }
+
+    pub fn load(path: &std::path::Path, registry: &CardRegistry) -> Result<Self, DeckError> {
+        Self::parse(&std::fs::read_to_string(path)?, registry)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.iter().map(|entry| entry.count).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // One identifier per card, as GameState::initial expects.
+    pub fn cards(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .flat_map(|entry| std::iter::repeat_n(entry.archetype.clone(), entry.count))
+            .collect()
+    }
+
+    // "Pokémon: 12" (PTCGL) or "##Pokémon - 12" (PTCGO)
+    fn section_header(line: &str) -> Option<Section> {
+        let line = line.trim_start_matches('#').trim();
+        match line.split([':', '-']).next()?.trim() {
+            "Pokémon" | "Pokemon" => Some(Section::Pokemon),
+            "Trainer" | "Trainer Cards" => Some(Section::Trainer),
+            "Energy" => Some(Section::Energy),
+            _ => None,
+        }
+    }
+
+    fn resolve(line: usize, card: &str, registry: &CardRegistry) -> Result<String, DeckError> {
+        // "Abra (BS 43)"
+        if card.ends_with(')') {
+            return registry
+                .by_archetype(card)
+                .map(|archetype| archetype.identifier())
+                .map_err(|err| DeckError::UnknownCard(line, err));
+        }
+
+        // "Abra BS 43"
+        let words = card.split_whitespace().collect::<Vec<_>>();
+        if let [name @ .., set, number] = words.as_slice() {
+            if let Ok(number) = number.parse::<usize>() {
+                let name = name.join(" ");
+                let archetype = registry
+                    .by_set_number(set, number)
+                    .map_err(|err| DeckError::UnknownCard(line, err))?;
+
+                if archetype.name() != name {
+                    return Err(DeckError::NameMismatch(line, format!("{} {}", set, number), name));
+                }
+
+                return Ok(archetype.identifier());
+            }
+        }
+
+        // "Psychic Energy", the first printing wins
+        registry
+            .by_name(card)
+            .map(|printings| printings[0].identifier())
+            .map_err(|err| DeckError::UnknownCard(line, err))
+    }
+}
+
+// What's wrong (if anything) with a deck in a given format. Copies are
+// counted by card name, so a Base Set and a Base Set 2 Abra add up.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DeckReport {
+    pub size: usize,
+    pub too_many_copies: Vec<(String, usize)>,
+    pub banned: Vec<String>,
+    pub not_in_format: Vec<String>,
+    pub has_basic_pokemon: bool,
+}
+
+impl DeckReport {
+    pub fn new(deck: &[String], registry: &CardRegistry, banned: &[String]) -> Self {
+        let mut report = Self { size: deck.len(), ..Self::default() };
+        let mut copies = BTreeMap::new();
+
+        for identifier in deck {
+            let archetype = match registry.by_archetype(identifier) {
+                Ok(archetype) => archetype,
+                Err(_) => {
+                    if !report.not_in_format.contains(identifier) {
+                        report.not_in_format.push(identifier.clone());
+                    }
+                    continue;
+                },
+            };
+
+            if banned.contains(identifier) && !report.banned.contains(identifier) {
+                report.banned.push(identifier.clone());
+            }
+            if archetype.stage() == Some(Stage::Basic) {
+                report.has_basic_pokemon = true;
+            }
+            if !BASIC_ENERGY.contains(&archetype.name().as_str()) {
+                *copies.entry(archetype.name()).or_insert(0) += 1;
+            }
+        }
+
+        report.too_many_copies = copies.into_iter().filter(|(_, count)| *count > MAX_COPIES).collect();
+        report
+    }
+
+    pub fn is_legal(&self) -> bool {
+        self.is_playable()
+            && self.size == DECK_SIZE
+            && self.too_many_copies.is_empty()
+            && self.banned.is_empty()
+    }
+
+    // Casual games skip the construction rules, but the engine still needs
+    // to know every card and to have a Basic Pokémon to start with.
+    pub fn is_playable(&self) -> bool {
+        self.not_in_format.is_empty() && self.has_basic_pokemon
+    }
+}
+
+impl std::fmt::Display for DeckReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let mut problems = vec![];
+
+        if self.size != DECK_SIZE {
+            problems.push(format!("{} cards instead of {}", self.size, DECK_SIZE));
+        }
+        for (name, count) in self.too_many_copies.iter() {
+            problems.push(format!("{} copies of {} (at most {})", count, name, MAX_COPIES));
+        }
+        for identifier in self.banned.iter() {
+            problems.push(format!("{} is banned", identifier));
+        }
+        for identifier in self.not_in_format.iter() {
+            problems.push(format!("{} is not legal in this format", identifier));
+        }
+        if !self.has_basic_pokemon {
+            problems.push("no Basic Pokémon".into());
+        }
+
+        if problems.is_empty() {
+            write!(f, "legal")
+        } else {
+            write!(f, "{}", problems.join(", "))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IllegalDeck {
+    pub player: Player,
+    pub report: DeckReport,
+}
+
+impl std::fmt::Display for IllegalDeck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "Player {:?}'s deck is illegal: {}", self.player, self.report)
+    }
+}
+
+impl std::error::Error for IllegalDeck {}
@@ -26,7 +26,7 @@ pub fn from_poke_power() -> AttackEffectBuilder {
     let mut builder = AttackEffectBuilder::default();
 
     builder.source = Some(Box::new(|ab: &AttackBuilderContext| {
-        EffectSource::Attack(ab.player(), ab.this_pokemon().id)
+        EffectSource::Ability(ab.player(), ab.this_pokemon().id)
     }));
 
     builder
@@ -49,6 +49,14 @@ impl AttackEffectBuilder {
         self
     }
 
+    pub fn on_this_pokemon(mut self) -> Self {
+        self.target = Some(Box::new(|ab: &AttackBuilderContext| {
+            EffectTarget::InPlayPokemon(ab.player(), ab.this_pokemon().id)
+        }));
+
+        self
+    }
+
     pub fn on_in_play_card(mut self, card: &Card) -> Self {
         let card = card.clone();
         self.target = Some(Box::new(move |_ab: &AttackBuilderContext| {
@@ -74,6 +82,14 @@ impl AttackEffectBuilder {
         self
     }
 
+    pub fn until_end_of_next_turn(mut self) -> Self {
+        self.expires = Some(Box::new(|ab: &AttackBuilderContext| {
+            EffectExpiration::EndOfTurn(ab.player(), 1)
+        }));
+
+        self
+    }
+
     pub fn while_active(mut self) -> Self {
         self.expires = Some(Box::new(|_ab| {
             EffectExpiration::DefendingPokemon
@@ -138,6 +154,18 @@ impl AttackEffectBuilder {
     }
 }
 
+// A format was asked for a custom effect it doesn't register.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownEffect(pub String);
+
+impl std::fmt::Display for UnknownEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "unknown effect {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownEffect {}
+
 pub trait CustomEffect {
     fn identifier() -> String where Self: Sized;
 
@@ -146,13 +174,17 @@ pub trait CustomEffect {
     fn get_resistance(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine, _resistance: Resistance) -> Option<Resistance> { None }
     fn get_weakness(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine, _weakness: Weakness) -> Option<Weakness> { None }
     fn get_attacks(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine, _actions: Vec<Attack>) -> Option<Vec<Attack>> { None }
+    fn get_poke_powers(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine, _poke_powers: Vec<Attack>) -> Option<Vec<Attack>> { None }
+    fn get_types(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine, _types: Vec<Type>) -> Option<Vec<Type>> { None }
     fn get_provides(&self, _effect: &Effect, _card: &Card, _engine: &GameEngine, _provides: Vec<Type>) -> Option<Vec<Type>> { None }
+    fn can_retreat(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine) -> Option<bool> { None }
 
     fn on_attempt_to_attack(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine) -> Option<AttackBuilder> { None }
     fn on_affected(&self) -> Option<AttackBuilder> { None }
     fn on_turn_end(&self) -> Option<AttackBuilder> { None }
     fn on_would_be_knocked_out(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine) -> Option<AttackBuilder> { None }
     fn on_knocked_out(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine) -> Option<AttackBuilder> { None }
+    fn on_damaged(&self, _effect: &Effect, _in_play: &InPlayCard, _engine: &GameEngine, _damage: usize) -> Option<AttackBuilder> { None }
     fn on_trainer(&self) -> Option<AttackBuilder> { None }
     fn on_energy_attachment(&self, _effect: &Effect, _player: Player) -> Option<AttackBuilder> { None }
 }
@@ -1,7 +1,14 @@
 use crate::state::*;
-use crate::cli::CLIDrawTarget;
 use crate::attack_builder::AttackBuilder;
-use crate::effect::CustomEffect;
+use crate::effect::{CustomEffect, UnknownEffect};
+use crate::custom_effects::DamagedByAttack;
+use crate::registry::{CardRegistry, UnknownCard};
+use crate::deck::DeckReport;
+use crate::observer::Observer;
+use crate::events::GameEvent;
+use crate::view::{ActionView, InPlayView, PlayerView, PrizeView};
+use crate::snapshot::{Snapshot, SnapshotAction, SnapshotError, SNAPSHOT_VERSION};
+use serde::{Deserialize, Serialize};
 
 pub type Weakness = (usize, Vec<Type>);
 pub type Resistance = (usize, Vec<Type>);
@@ -53,9 +60,19 @@ pub trait CardArchetype {
     fn is_trainer(&self, _card: &Card, _engine: &GameEngine) -> bool {
         false
     }
+    fn is_supporter(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        false
+    }
+    fn is_energy(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        false
+    }
     fn attachable_as_energy_for_turn(&self, _card: &Card, _engine: &GameEngine) -> bool {
         false
     }
+    // eg: Rainbow Energy, Potion Energy. `card` is already attached to `target`.
+    fn on_attach_from_hand(&self, _card: &Card, _target: &InPlayCard, _engine: &GameEngine) -> Option<GameEngine> {
+        None
+    }
 
     // TODO: turn this into a effect::Effect somehow? it's the same API
     fn defending_damage_effect(&self, _card: &Card, _engine: &GameEngine, _damage: usize) -> Option<usize> {
@@ -67,6 +84,14 @@ pub trait CardArchetype {
     fn on_turn_end(&self, _card: &Card, _engine: &GameEngine) -> Option<GameEngine> {
         None
     }
+    // Between turns, for cards in play (see CheckupStep::Cards).
+    fn on_pokemon_checkup(&self, _card: &Card, _engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        None
+    }
+    // Right after the card is played from hand onto `in_play` (eg: Summon Minions).
+    fn on_play_from_hand(&self, _in_play: &InPlayCard, _engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        None
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -75,15 +100,112 @@ pub enum AttackingEffectsWhen {
     AfterWR,
 }
 
+// How Confusion plays out, which changed after the Base era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConfusionRules {
+    // Flip to attack, 20 damage to itself on tails. Flip to retreat as well,
+    // after paying the retreat cost.
+    Classic,
+    // Flip to attack, 3 damage counters on itself on tails. Retreats as usual.
+    Modern,
+}
+
+impl ConfusionRules {
+    pub fn self_damage(&self) -> usize {
+        match self {
+            ConfusionRules::Classic => 20,
+            ConfusionRules::Modern => 30,
+        }
+    }
+
+    pub fn flip_to_retreat(&self) -> bool {
+        *self == ConfusionRules::Classic
+    }
+}
+
+// How Burn plays out between turns, which changed over the eras.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BurnRules {
+    // Flip, 2 damage counters on tails. It stays Burned until something cures it.
+    FlipForDamage,
+    // 2 damage counters, then flip: heads cures it.
+    FlipToRecover,
+}
+
+// The steps of Pokémon Checkup. Formats list the ones they use, in the order
+// they happen, and each step goes through both players before the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckupStep {
+    Poison,
+    Burn,
+    Sleep,
+    Paralysis,
+    // cards in play acting between turns (see CardArchetype::on_pokemon_checkup).
+    Cards,
+}
+
+// What happens when both players win at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimultaneousWin {
+    // Play a new game with one prize card each.
+    SuddenDeath,
+    Tie,
+    // The player who took more prize cards wins, Sudden Death if they took as many.
+    MostPrizesTaken,
+}
+
+// Things a player can't do on their first turn. Formats list the ones they use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FirstTurnRestriction {
+    // the player going first can't attack during their first turn.
+    NoAttack,
+    // neither player can evolve their Pokémon during their first turn.
+    NoEvolution,
+    // the player going first can't play a Supporter during their first turn.
+    NoSupporter,
+}
+
+// The rules a format can play by besides its cards, kept together so a
+// snapshot can bring them back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rules {
+    pub confusion: ConfusionRules,
+    pub burn: BurnRules,
+    pub pokemon_checkup: Vec<CheckupStep>,
+    pub simultaneous_win: SimultaneousWin,
+    pub first_turn_restrictions: Vec<FirstTurnRestriction>,
+}
+
 pub trait Format {
-    fn behavior_from_id(&self, id: &String) -> &dyn CardArchetype;
+    fn name(&self) -> String;
+    fn registry(&self) -> &CardRegistry;
+    fn behavior_from_id(&self, id: &str) -> Result<&dyn CardArchetype, UnknownCard>;
     fn behavior(&self, card: &Card) -> &dyn CardArchetype;
-    fn effect(&self, id: &String) -> &dyn CustomEffect;
+    fn effect(&self, id: &str) -> Result<&dyn CustomEffect, UnknownEffect>;
+
+    fn banned_cards(&self) -> Vec<String>;
+    fn validate_deck(&self, deck: &[String]) -> DeckReport {
+        DeckReport::new(deck, self.registry(), &self.banned_cards())
+    }
 
     fn attacking_effects(&self) -> AttackingEffectsWhen;
-    fn basic_for_stage2(&self, card: &Card) -> String;
+    fn basic_for_stage2(&self, card: &Card) -> Result<String, UnknownCard>;
     fn available_types(&self) -> Vec<Type>;
     fn all_special_conditions_prevent_pokemon_powers(&self) -> bool;
+    fn confusion(&self) -> ConfusionRules;
+    fn burn(&self) -> BurnRules;
+    fn pokemon_checkup(&self) -> Vec<CheckupStep>;
+    fn simultaneous_win(&self) -> SimultaneousWin;
+    fn first_turn_restrictions(&self) -> Vec<FirstTurnRestriction>;
+    fn rules(&self) -> Rules {
+        Rules {
+            confusion: self.confusion(),
+            burn: self.burn(),
+            pokemon_checkup: self.pokemon_checkup(),
+            simultaneous_win: self.simultaneous_win(),
+            first_turn_restrictions: self.first_turn_restrictions(),
+        }
+    }
 
     fn boxed_clone(&self) -> Box<dyn Format>;
 }
@@ -114,6 +236,10 @@ impl Flips {
         Flips { results }
     }
 
+    pub fn results(&self) -> &[bool] {
+        &self.results
+    }
+
     pub fn is_heads(&self) -> bool {
         *self.results.first().unwrap()
     }
@@ -131,7 +257,12 @@ impl Flips {
     }
 }
 
+// DecisionMakers never get to hold the GameState: they're shown each player's
+// view before every step, and the choices they're asked to make are filtered
+// the same way: Pokémon and actions come as InPlayViews and ActionViews,
+// prizes as PrizeViews, and deck cards are only shown to the deck's owner.
 pub trait DecisionMaker {
+    fn observe(&mut self, _view: &PlayerView) {}
     fn shuffler(&mut self) -> &mut dyn Shuffler;
     fn flip(&mut self, number_of_coins: usize) -> Flips;
 
@@ -139,20 +270,24 @@ pub trait DecisionMaker {
     fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection;
     fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card;
     fn confirm_mulligan_draw(&mut self, p: Player, upto: usize) -> usize;
-    fn confirm_setup_bench_selection(&mut self, p: Player, cards: &Vec<Card>) -> Vec<Card>;
+    fn confirm_setup_bench_selection(&mut self, p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card>;
+    fn confirm_going_first(&mut self, p: Player) -> bool;
     fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type;
-    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayCard, &'a InPlayCard, usize)>) -> &'a (&'a InPlayCard, &'a InPlayCard, usize);
-    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayCard)>) -> &'a (&'a Card, &'a InPlayCard);
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize);
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView);
     fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack;
-    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<Action>) -> &'a Action;
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView;
     fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage;
     fn pick_from_hand<'a>(&mut self, p: Player, whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card>;
     fn pick_from_discard<'a>(&mut self, p: Player, whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card>;
-    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayCard>) -> Vec<&'a InPlayCard>;
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView>;
     fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card>;
-    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a Vec<PrizeCard>) -> Vec<&'a PrizeCard>;
-    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Card>) -> Vec<&'a Card>;
+    fn pick_energy_discard<'a>(&mut self, p: Player, cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card>;
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView>;
+    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>>;
     fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card>;
+    fn accept_challenge(&mut self, p: Player) -> bool;
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player;
 }
 
 #[derive(Default)]
@@ -170,20 +305,66 @@ impl DecisionMaker for FakeDM {
     fn confirm_setup_active_or_mulligan(&mut self, _p: Player, _maybe: &Vec<Card>) -> SetupActiveSelection { SetupActiveSelection::Mulligan }
     fn confirm_setup_active(&mut self, _p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card { if !yes.is_empty() { yes[0].clone() } else { maybe[0].clone() } }
     fn confirm_mulligan_draw(&mut self, _p: Player, upto: usize) -> usize { upto }
-    fn confirm_setup_bench_selection(&mut self, _p: Player, _cards: &Vec<Card>) -> Vec<Card> { vec![] }
-    fn pick_move_damage_counters<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a InPlayCard, &'a InPlayCard, usize)>) -> &'a (&'a InPlayCard, &'a InPlayCard, usize) { &possibilities[0] }
-    fn pick_attach_from_hand<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayCard)>) -> &'a (&'a Card, &'a InPlayCard) { &possibilities[0] }
+    fn confirm_setup_bench_selection(&mut self, _p: Player, _upto: usize, _cards: &Vec<Card>) -> Vec<Card> { vec![] }
+    fn confirm_going_first(&mut self, _p: Player) -> bool { true }
+    fn pick_move_damage_counters<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) { &possibilities[0] }
+    fn pick_attach_from_hand<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) { &possibilities[0] }
     fn pick_type<'a>(&mut self, _p: Player, types: &'a Vec<Type>) -> &'a Type { &types[0] }
     fn pick_attack<'a>(&mut self, _p: Player, attacks: &'a Vec<Attack>) -> &'a Attack { &attacks[0] }
-    fn pick_action<'a>(&mut self, _p: Player, actions: &'a Vec<Action>) -> &'a Action { &actions[0] }
+    fn pick_action<'a>(&mut self, _p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView { &actions[0] }
     fn pick_stage<'a>(&mut self, _p: Player, items: &'a Vec<Stage>) -> &'a Stage { &items[0] }
     fn pick_from_hand<'a>(&mut self, _p: Player, _whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> { hand[0..how_many].iter().collect() }
     fn pick_from_discard<'a>(&mut self, _p: Player, _whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> { searchable[0..how_many].iter().collect() }
-    fn pick_in_play<'a>(&mut self, _p: Player, how_many: usize, searchable: &'a Vec<InPlayCard>) -> Vec<&'a InPlayCard> { searchable[0..how_many].iter().collect() }
+    fn pick_in_play<'a>(&mut self, _p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> { searchable[0..how_many].iter().collect() }
     fn pick_attached<'a>(&mut self, _p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> { searchable[0..*how_many.end()].iter().collect() }
-    fn pick_from_prizes<'a>(&mut self, _who: Player, _whose: Player, how_many: usize, searchable: &'a Vec<PrizeCard>) -> Vec<&'a PrizeCard> { searchable[0..how_many].iter().collect() }
-    fn search_deck<'a>(&mut self, _p: Player, _whose: Player, how_many: usize, deck: &'a Vec<Card>) -> Vec<&'a Card> { deck[0..how_many].iter().collect() }
+    fn pick_energy_discard<'a>(&mut self, _p: Player, _cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> { &options[0] }
+    fn pick_from_prizes<'a>(&mut self, _who: Player, _whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> { searchable[0..how_many].iter().collect() }
+    fn search_deck<'a>(&mut self, _p: Player, _whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> { deck[0..how_many].iter().collect() }
     fn rearrange<'a>(&mut self, _p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> { cards.iter().collect() }
+    fn accept_challenge(&mut self, _p: Player) -> bool { false }
+    fn pick_player<'a>(&mut self, _p: Player, players: &'a [Player]) -> &'a Player { &players[0] }
+}
+
+// What the engine asks through: each of these shows the decision maker only
+// what `p` may see, and hands back the real thing that was picked.
+impl dyn DecisionMaker + '_ {
+    pub fn pick_move_damage_counters_between<'a>(&mut self, p: Player, possibilities: &[(&'a InPlayCard, &'a InPlayCard, usize)]) -> (&'a InPlayCard, &'a InPlayCard, usize) {
+        let views = possibilities.iter().map(|(from, to, n)| (InPlayView::new(from, p), InPlayView::new(to, p), *n)).collect::<Vec<_>>();
+        let offered = views.iter().map(|(from, to, n)| (from, to, *n)).collect::<Vec<_>>();
+        let chosen = self.pick_move_damage_counters(p, &offered);
+
+        possibilities[offered.iter().position(|o| std::ptr::eq(o, chosen)).unwrap()]
+    }
+
+    pub fn pick_attach_from_hand_to<'a>(&mut self, p: Player, possibilities: &[(&'a Card, &'a InPlayCard)]) -> (&'a Card, &'a InPlayCard) {
+        let views = possibilities.iter().map(|(card, target)| (*card, InPlayView::new(target, p))).collect::<Vec<_>>();
+        let offered = views.iter().map(|(card, target)| (*card, target)).collect::<Vec<_>>();
+        let chosen = self.pick_attach_from_hand(p, &offered);
+
+        possibilities[offered.iter().position(|o| std::ptr::eq(o, chosen)).unwrap()]
+    }
+
+    pub fn pick_available_action<'a>(&mut self, p: Player, actions: &'a [Action]) -> &'a Action {
+        let views = actions.iter().map(|action| ActionView::new(action, p)).collect::<Vec<_>>();
+        let chosen = self.pick_action(p, &views);
+
+        &actions[views.iter().position(|view| std::ptr::eq(view, chosen)).unwrap()]
+    }
+
+    pub fn pick_in_play_cards<'a>(&mut self, p: Player, how_many: usize, searchable: &'a [InPlayCard]) -> Vec<&'a InPlayCard> {
+        let views = searchable.iter().map(|in_play| InPlayView::new(in_play, p)).collect::<Vec<_>>();
+        let chosen = self.pick_in_play(p, how_many, &views);
+
+        chosen.into_iter().map(|view| &searchable[views.iter().position(|v| std::ptr::eq(v, view)).unwrap()]).collect()
+    }
+
+    // Only whoever owns the deck gets to look through it.
+    pub fn search_deck_cards<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a [Card]) -> Vec<&'a Card> {
+        let views = deck.iter().map(|card| (p == whose).then(|| card.clone())).collect::<Vec<_>>();
+        let chosen = self.search_deck(p, whose, how_many, &views);
+
+        chosen.into_iter().map(|view| &deck[views.iter().position(|v| std::ptr::eq(v, view)).unwrap()]).collect()
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -247,7 +428,78 @@ impl std::fmt::Debug for Action {
     }
 }
 
-#[derive(Clone, Debug)]
+impl Action {
+    // Whether both are the same move, going by cards and in-play ids rather
+    // than by the snapshot of the Pokémon each carries.
+    pub fn same_as(&self, other: &Action) -> bool {
+        match (self, other) {
+            (Action::Pass, Action::Pass) => true,
+            (Action::TrainerFromHand(p, a), Action::TrainerFromHand(q, b))
+            | (Action::AttachFromHand(p, a), Action::AttachFromHand(q, b))
+            | (Action::BenchFromHand(p, a), Action::BenchFromHand(q, b))
+            | (Action::EvolveFromHand(p, a), Action::EvolveFromHand(q, b)) => p == q && a == b,
+            (Action::Attack(p, a, x), Action::Attack(q, b, y))
+            | (Action::PokePower(p, a, x), Action::PokePower(q, b, y)) => p == q && a.id == b.id && x.name() == y.name(),
+            (Action::Retreat(p, a), Action::Retreat(q, b)) => p == q && a.id == b.id,
+            _ => false,
+        }
+    }
+
+    pub fn player(&self) -> Option<Player> {
+        match self {
+            Action::Pass => None,
+            Action::TrainerFromHand(player, _)
+            | Action::AttachFromHand(player, _)
+            | Action::BenchFromHand(player, _)
+            | Action::EvolveFromHand(player, _)
+            | Action::Attack(player, _, _)
+            | Action::PokePower(player, _, _)
+            | Action::Retreat(player, _) => Some(*player),
+        }
+    }
+}
+
+// Why GameEngine::apply_action turned an action down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IllegalAction {
+    GameOver,
+    NotYourTurn,
+    NotInHand,
+    NotInPlay,
+    NotActive,
+    AlreadyAttachedEnergy,
+    CostNotMet,
+    Paralyzed,
+    Asleep,
+    BenchFull,
+    FirstTurn,
+    AlreadyRetreated,
+    NotAvailable,
+}
+
+impl std::fmt::Display for IllegalAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            IllegalAction::GameOver => write!(f, "the game is over"),
+            IllegalAction::NotYourTurn => write!(f, "it's not that player's turn to act"),
+            IllegalAction::NotInHand => write!(f, "that card isn't in the player's hand"),
+            IllegalAction::NotInPlay => write!(f, "that Pokémon isn't in play"),
+            IllegalAction::NotActive => write!(f, "that Pokémon isn't Active"),
+            IllegalAction::AlreadyAttachedEnergy => write!(f, "energy was already attached this turn"),
+            IllegalAction::CostNotMet => write!(f, "the energy cost isn't met"),
+            IllegalAction::Paralyzed => write!(f, "that Pokémon is Paralyzed"),
+            IllegalAction::Asleep => write!(f, "that Pokémon is Asleep"),
+            IllegalAction::BenchFull => write!(f, "the bench is full"),
+            IllegalAction::FirstTurn => write!(f, "that isn't allowed on the first turn"),
+            IllegalAction::AlreadyRetreated => write!(f, "a Pokémon already retreated this turn"),
+            IllegalAction::NotAvailable => write!(f, "that action isn't available"),
+        }
+    }
+}
+
+impl std::error::Error for IllegalAction {}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PrizeAward {
     player: Player,
     how_many: usize,
@@ -271,16 +523,64 @@ impl GameEngine {
         }
     }
 
-    pub fn play(&self, dm: &mut dyn DecisionMaker) -> Self {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            format: self.format.name(),
+            rules: self.format.rules(),
+            state: self.state.clone(),
+            resolving_actions: self.resolving_actions.iter().map(SnapshotAction::from_action).collect(),
+            attack_target_stack: self.attack_target_stack.clone(),
+            good: self.good,
+            prize_queue: self.prize_queue.clone(),
+        }
+    }
+
+    pub fn from_snapshot(snapshot: &Snapshot, format: Box<dyn Format>) -> Result<Self, SnapshotError> {
+        if snapshot.format != format.name() {
+            return Err(SnapshotError::FormatMismatch(snapshot.format.clone(), format.name()));
+        }
+        if snapshot.rules != format.rules() {
+            return Err(SnapshotError::RulesMismatch(snapshot.format.clone()));
+        }
+
+        for effect in snapshot.state.effects.iter() {
+            format.effect(&effect.consequence).map_err(|err| SnapshotError::UnknownEffect(err.0))?;
+        }
+
+        let resolving_actions = snapshot.resolving_actions.iter()
+            .map(|action| action.to_action(format.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            state: snapshot.state.clone(),
+            resolving_actions,
+            attack_target_stack: snapshot.attack_target_stack.clone(),
+            good: snapshot.good,
+            format,
+            prize_queue: snapshot.prize_queue.clone(),
+        })
+    }
+
+    pub fn play(&self, dm: &mut dyn DecisionMaker, observer: &mut dyn Observer) -> Self {
         let mut engine = self.clone();
+        observer.on_start(&engine);
+
         while !engine.is_finished() {
-            CLIDrawTarget::print(&engine);
-            engine = engine.step(dm);
+            let next = engine.step(dm);
+            observer.on_step(&engine, &next);
+            engine = next;
         }
 
+        observer.on_finish(&engine);
         engine
     }
 
+    // Ends the game with `player`'s opponent as the winner.
+    pub fn forfeit(&self, player: Player) -> Self {
+        self.with_state(self.state.with_stage(GameStage::Winner(player.opponent())))
+    }
+
     pub fn is_finished(&self) -> bool {
         match self.state.stage {
             GameStage::Winner(_) | GameStage::Tie => { true },
@@ -288,11 +588,102 @@ impl GameEngine {
         }
     }
 
+    // Each step starts with a clean slate of events, see GameEngine::events.
     pub fn step(&self, dm: &mut dyn DecisionMaker) -> Self {
+        dm.observe(&self.view(Player::One));
+        dm.observe(&self.view(Player::Two));
+
+        self.with_state(self.state.without_events()).advance(dm)
+    }
+
+    // Like step, but with the action picked by someone other than `dm`, eg: a
+    // network client or a test script. It has to be one available_actions
+    // offers; anything it asks along the way still goes to `dm`.
+    pub fn apply_action(&self, action: &Action, dm: &mut dyn DecisionMaker) -> Result<Self, IllegalAction> {
+        let (player, action) = self.legal_action(action)?;
+
+        dm.observe(&self.view(Player::One));
+        dm.observe(&self.view(Player::Two));
+
+        Ok(self.with_state(self.state.without_events()).perform(player, &action, dm))
+    }
+
+    // The available action matching `action`, or why there isn't one.
+    pub fn legal_action(&self, action: &Action) -> Result<(Player, Action), IllegalAction> {
+        let player = match self.state.stage {
+            GameStage::Winner(_) | GameStage::Tie => { return Err(IllegalAction::GameOver); },
+            GameStage::Turn(player) if action.player().unwrap_or(player) == player => player,
+            _ => { return Err(IllegalAction::NotYourTurn); },
+        };
+
+        if let Some(available) = self.available_actions(player).into_iter().find(|available| available.same_as(action)) {
+            return Ok((player, available));
+        }
+
+        let side = self.state.side(player);
+        let status = |in_play: &InPlayCard| match side.in_play(&in_play.id).map(|in_play| &in_play.rotational_status) {
+            None => Err(IllegalAction::NotInPlay),
+            Some(_) if !side.active.iter().any(|active| active.id == in_play.id) => Err(IllegalAction::NotActive),
+            Some(RotationalStatus::Paralyzed) => Err(IllegalAction::Paralyzed),
+            Some(RotationalStatus::Asleep) => Err(IllegalAction::Asleep),
+            Some(_) => Ok(side.in_play(&in_play.id).unwrap()),
+        };
+
+        Err(match action {
+            Action::TrainerFromHand(_, card)
+            | Action::AttachFromHand(_, card)
+            | Action::BenchFromHand(_, card)
+            | Action::EvolveFromHand(_, card) if !side.hand.contains(card) => IllegalAction::NotInHand,
+            Action::AttachFromHand(_, _) if side.manual_attachments_this_turn > 0 => IllegalAction::AlreadyAttachedEnergy,
+            Action::BenchFromHand(_, _) if !self.has_bench_space(player) => IllegalAction::BenchFull,
+            Action::TrainerFromHand(_, card) if self.is_supporter(card) && self.is_first_turn_restricted(player, FirstTurnRestriction::NoSupporter) => IllegalAction::FirstTurn,
+            Action::EvolveFromHand(_, _) if self.is_first_turn_restricted(player, FirstTurnRestriction::NoEvolution) => IllegalAction::FirstTurn,
+            Action::Attack(_, _, _) if self.is_first_turn_restricted(player, FirstTurnRestriction::NoAttack) => IllegalAction::FirstTurn,
+            Action::Attack(_, in_play, attack) => match status(in_play) {
+                Err(reason) => reason,
+                Ok(in_play) if !self.is_attack_energy_cost_met(in_play, &self.attack_cost(in_play, attack)) => IllegalAction::CostNotMet,
+                Ok(_) => IllegalAction::NotAvailable,
+            },
+            Action::Retreat(_, in_play) => match status(in_play) {
+                Err(reason) => reason,
+                Ok(_) if side.retreats_this_turn > 0 => IllegalAction::AlreadyRetreated,
+                Ok(in_play) if !self.is_energy_cost_met(&self.retreat_cost(in_play), self.attached_energy(in_play)) => IllegalAction::CostNotMet,
+                Ok(_) => IllegalAction::NotAvailable,
+            },
+            Action::PokePower(_, in_play, _) if side.in_play(&in_play.id).is_none() => IllegalAction::NotInPlay,
+            _ => IllegalAction::NotAvailable,
+        })
+    }
+
+    pub fn view(&self, player: Player) -> PlayerView {
+        let mut view = PlayerView::new(&self.state, player);
+
+        // Clairvoyance (Omanyte): the opponent plays with their hand face up.
+        for owner in [Player::One, Player::Two] {
+            if self.state.side(owner).all_in_play().iter().any(|in_play| self.has_pokemon_power(in_play, "Clairvoyance")) {
+                let revealed = owner.opponent();
+                view.side_mut(revealed).hand = self.state.side(revealed).hand.iter().cloned().map(Some).collect();
+            }
+        }
+
+        view
+    }
+
+    // What happened during the last step.
+    pub fn events(&self) -> &[GameEvent] {
+        &self.state.events
+    }
+
+    fn advance(&self, dm: &mut dyn DecisionMaker) -> Self {
         match self.state.stage {
             GameStage::Uninitialized => { self.setup(dm) },
             GameStage::Winner(_) => { self.clone() },
             GameStage::Tie => { self.clone() },
+            GameStage::SuddenDeath => {
+                self
+                    .with_state(self.state.sudden_death())
+                    .with_event(GameEvent::SuddenDeath)
+            },
             GameStage::StartOfTurn(player) => {
                 if self.state.side(player).deck.is_empty() {
                     self.with_state(self.state.with_stage(GameStage::Winner(player.opponent())))
@@ -302,57 +693,9 @@ impl GameEngine {
             },
             GameStage::Turn(player) => {
                 let actions = self.available_actions(player);
-                let action = dm.pick_action(player, &actions);
-
-                match &action {
-                    Action::Pass => {
-                        self.end_turn()
-                    },
-                    Action::TrainerFromHand(_, card) => {
-                        self
-                            .push_action(action.clone())
-                            .then(|e| self.archetype(card).execute(player, card, &e, dm))
-                            .check_kos_and_stuff(dm)
-                            .pop_action()
-                    },
-                    Action::AttachFromHand(player, card) => {
-                        self
-                            .manual_attach_energy_card(*player, card, dm)
-                            .check_kos_and_stuff(dm)
-                    },
-                    Action::EvolveFromHand(player, card) => {
-                        self
-                            .evolve(*player, card, dm)
-                            .check_kos_and_stuff(dm)
-                    },
-                    Action::Retreat(player, in_play) => {
-                        self
-                            .retreat(*player, in_play, dm)
-                            .check_kos_and_stuff(dm)
-                    },
-                    Action::Attack(player, attacking, attack) => {
-                       self
-                            .push_action(action.clone())
-                            .push_target(attacking, &self.state.side(player.opponent()).active[0])
-                            .then(|e| e.execute_attack(attack, dm))
-                            .check_kos_and_stuff(dm)
-                            .pop_target()
-                            .pop_action()
-                            .end_turn()
-                    },
-                    Action::PokePower(_player, _attacking, attack) => {
-                       self
-                            .push_action(action.clone())
-                            .then(|e| e.execute_poke_power(attack, dm))
-                            .check_kos_and_stuff(dm)
-                            .pop_action()
-                    },
-                    Action::BenchFromHand(_, card) => {
-                        self
-                            .bench_from_hand(player, card)
-                            .check_kos_and_stuff(dm)
-                    },
-                }
+                let action = dm.pick_available_action(player, &actions);
+
+                self.perform(player, action, dm)
             },
             GameStage::EndOfTurn(_) => {
                 self.goto_pokemon_checkup()
@@ -361,38 +704,126 @@ impl GameEngine {
                 self
                     .pokemon_checkup(player, dm)
                     .check_kos_and_stuff(dm)
-                    .then(|e| e.with_state(e.state.with_stage(GameStage::StartOfTurn(player.opponent())).next_turn(player.opponent())))
+                    .then(|e| if e.is_over() {
+                        e.clone()
+                    } else {
+                        e.with_state(e.state.with_stage(GameStage::StartOfTurn(player.opponent())).next_turn(player.opponent()))
+                    })
             }
         }
     }
 
+    fn perform(&self, player: Player, action: &Action, dm: &mut dyn DecisionMaker) -> Self {
+        match action {
+            Action::Pass => {
+                self.end_turn()
+            },
+            Action::TrainerFromHand(_, card) => {
+                self
+                    .push_action(action.clone())
+                    .then(|e| self.archetype(card).execute(player, card, e, dm))
+                    .check_kos_and_stuff(dm)
+                    .pop_action()
+            },
+            Action::AttachFromHand(player, card) => {
+                self
+                    .manual_attach_energy_card(*player, card, dm)
+                    .check_kos_and_stuff(dm)
+            },
+            Action::EvolveFromHand(player, card) => {
+                self
+                    .evolve(*player, card, dm)
+                    .check_kos_and_stuff(dm)
+            },
+            Action::Retreat(player, in_play) => {
+                self
+                    .retreat(*player, in_play, dm)
+                    .check_kos_and_stuff(dm)
+            },
+            Action::Attack(player, attacking, attack) => {
+               self
+                    .with_event(GameEvent::AttackDeclared { player: *player, pokemon: attacking.id, attack: attack.name().clone() })
+                    .push_action(action.clone())
+                    .push_target(attacking, &self.state.side(player.opponent()).active[0])
+                    .then(|e| e.execute_attack(attack, dm))
+                    .check_kos_and_stuff(dm)
+                    .pop_target()
+                    .pop_action()
+                    .end_turn()
+            },
+            Action::PokePower(_player, _attacking, attack) => {
+               self
+                    .push_action(action.clone())
+                    .then(|e| e.execute_poke_power(attack, dm))
+                    .check_kos_and_stuff(dm)
+                    .pop_action()
+            },
+            Action::BenchFromHand(_, card) => {
+                self
+                    .bench_from_hand(player, card)
+                    .check_kos_and_stuff(dm)
+            },
+        }
+    }
+
     pub fn pokemon_checkup(&self, player: Player, dm: &mut dyn DecisionMaker) -> Self {
         let mut engine = self.clone();
 
-        engine = engine.pokemon_checkup_special_conditions(player, dm);
-        engine = engine.pokemon_checkup_special_conditions(player.opponent(), dm);
+        for step in self.format.pokemon_checkup() {
+            engine = engine.pokemon_checkup_step(step, player, dm);
+            engine = engine.pokemon_checkup_step(step, player.opponent(), dm);
+        }
+
         engine
     }
 
-    pub fn pokemon_checkup_special_conditions(&self, player: Player, dm: &mut dyn DecisionMaker) -> Self {
+    pub fn pokemon_checkup_step(&self, step: CheckupStep, player: Player, dm: &mut dyn DecisionMaker) -> Self {
         let mut engine = self.clone();
 
-        // poisoned, burned, asleep, paralyzed
-        for in_play in engine.state.side(player).active.clone().iter() {
-            if let Some(poison) = &in_play.poisoned {
-                engine = engine.with_state(engine.state.add_damage_counters(in_play, poison.counters));
+        if step == CheckupStep::Cards {
+            let cards = engine.state.side(player).all_in_play().into_iter().flat_map(|in_play| in_play.cards()).cloned().collect::<Vec<_>>();
+            for card in cards {
+                if let Some(new_engine) = self.archetype(&card).on_pokemon_checkup(&card, &engine, dm) {
+                    engine = new_engine;
+                }
             }
 
-            // TODO: burned (didn't exist in base set)
+            return engine;
+        }
 
-            match in_play.rotational_status {
-                RotationalStatus::Asleep => {
-                    if dm.flip(1).heads() == 1 {
-                        println!("{:?} woke up!", in_play);
+        for in_play in engine.state.side(player).active.clone().iter() {
+            match step {
+                CheckupStep::Poison => {
+                    if let Some(poison) = &in_play.poisoned {
+                        engine = engine.with_state(engine.state.add_damage_counters(in_play, poison.counters));
+                    }
+                },
+                CheckupStep::Burn if in_play.burned => {
+                    let flips;
+                    match self.format.burn() {
+                        BurnRules::FlipForDamage => {
+                            (engine, flips) = engine.flip(player, 1, dm);
+                            if flips.is_tails() {
+                                engine = engine.with_state(engine.state.add_damage_counters(in_play, 2));
+                            }
+                        },
+                        BurnRules::FlipToRecover => {
+                            engine = engine.with_state(engine.state.add_damage_counters(in_play, 2));
+                            (engine, flips) = engine.flip(player, 1, dm);
+                            if flips.is_heads() {
+                                engine = engine.with_state(engine.state.cure_burn(in_play));
+                            }
+                        },
+                    }
+                },
+                CheckupStep::Sleep if in_play.rotational_status == RotationalStatus::Asleep => {
+                    let flips;
+                    (engine, flips) = engine.flip(player, 1, dm);
+                    if flips.is_heads() {
                         engine = engine.with_state(engine.state.wake_up(in_play));
                     }
                 },
-                RotationalStatus::Paralyzed => {
+                CheckupStep::Paralysis if in_play.rotational_status == RotationalStatus::Paralyzed => {
                     if let GameStage::PokemonCheckup(p) = self.state.stage {
                         if p == player {
                             engine = engine.with_state(engine.state.cure_paralysis(in_play));
@@ -433,12 +864,31 @@ impl GameEngine {
         } else if b > 0 && b > a {
             self.with_state(self.state.with_stage(GameStage::Winner(Player::Two)))
         } else if b > 0 && b == a {
-            self.with_state(self.state.with_stage(GameStage::Tie))
+            self.simultaneous_win()
         } else {
             self.clone()
         }
     }
 
+    fn simultaneous_win(&self) -> Self {
+        let prizes_left = |player| self.state.side(player).prizes.len();
+
+        let stage = match self.format.simultaneous_win() {
+            SimultaneousWin::Tie => GameStage::Tie,
+            SimultaneousWin::MostPrizesTaken if prizes_left(Player::One) < prizes_left(Player::Two) => GameStage::Winner(Player::One),
+            SimultaneousWin::MostPrizesTaken if prizes_left(Player::Two) < prizes_left(Player::One) => GameStage::Winner(Player::Two),
+            _ => GameStage::SuddenDeath,
+        };
+
+        self.with_state(self.state.with_stage(stage))
+    }
+
+    // Whether there's nothing left to do in this game, either because it's
+    // finished or because it's about to be replayed as Sudden Death.
+    pub fn is_over(&self) -> bool {
+        self.is_finished() || self.state.stage == GameStage::SuddenDeath
+    }
+
     pub fn check_promotion_needed(&self, dm: &mut dyn DecisionMaker) -> Self {
         // Q. When both active Pokémon are knocked out, who places a new active first?
         // A. The player whose turn would be next.
@@ -453,7 +903,7 @@ impl GameEngine {
         for who in [who_first, who_first.opponent()] {
             // TODO: 2v2 games
             while engine.state.side(who).active.len() < 1 && !engine.state.side(who).bench.is_empty() {
-                let chosen = dm.pick_in_play(who, 1, &engine.state.side(who).bench);
+                let chosen = dm.pick_in_play_cards(who, 1, &engine.state.side(who).bench);
                 engine.state = engine.state.promote(chosen[0]);
             }
         }
@@ -500,11 +950,13 @@ impl GameEngine {
         // TODO: (billowing smoke) how do I know that the damage was from an attack?
 
         let prizes = self.state.side(player).prizes.clone();
-        let choices = dm.pick_from_prizes(player, player, how_many, &prizes);
+        let views = prizes.iter().map(PrizeView::new).collect::<Vec<_>>();
+        let choices = dm.pick_from_prizes(player, player, how_many, &views);
 
         let mut engine = self.clone();
         for chosen in choices {
-            engine = engine.with_state(engine.state.prize_to_hand(player, &chosen));
+            let prize = prizes.iter().find(|prize| prize.id == chosen.id).unwrap();
+            engine = engine.with_state(engine.state.prize_to_hand(player, prize));
         }
 
         engine
@@ -513,6 +965,14 @@ impl GameEngine {
     pub fn execute_attack(&self, attack: &Attack, dm: &mut dyn DecisionMaker) -> Self {
         let mut engine = self.clone();
 
+        if self.attacking().rotational_status == RotationalStatus::Confused {
+            let (flipped, flips) = engine.flip(self.attacking().owner, 1, dm);
+            engine = flipped;
+            if flips.is_tails() {
+                return engine.hurt_by_confusion(self.attacking());
+            }
+        }
+
         let attack_effects = self.state.effects.iter()
             .flat_map(|e| self.effect(e).on_attempt_to_attack(e, self.attacking(), self))
             .collect::<Vec<_>>();
@@ -524,7 +984,39 @@ impl GameEngine {
             }
         }
 
+        // Transparency (Haunter): if the attack does anything to it, on heads
+        // none of that happens. Damage, Special Conditions and effects are
+        // undone; cards the attack moved stay where they went.
+        let defending = self.defending().clone();
+        let before = engine.clone();
         engine = attack.run(&engine, dm);
+        if defending.id != self.attacking().id && before.has_pokemon_power(&defending, "Transparency") && engine.was_touched_since(&before, &defending) {
+            let (flipped, flips) = engine.flip(defending.owner, 1, dm);
+            engine = flipped;
+            if flips.is_heads() {
+                engine = engine.untouched_since(&before, &defending);
+            }
+        }
+
+        engine
+    }
+
+    fn was_touched_since(&self, before: &GameEngine, in_play: &InPlayCard) -> bool {
+        let effects_on = |engine: &GameEngine| engine.state.effects.iter().filter(|e| e.target.is_in_play(in_play)).count();
+
+        self.state.in_play(&in_play.id) != before.state.in_play(&in_play.id) || effects_on(self) != effects_on(before)
+    }
+
+    fn untouched_since(&self, before: &GameEngine, in_play: &InPlayCard) -> Self {
+        let was = before.state.in_play(&in_play.id).unwrap();
+        let mut engine = self.clone();
+
+        if engine.state.in_play(&in_play.id).is_some() {
+            engine = engine.with_state(engine.state.with_damage_and_conditions_of(was));
+        }
+
+        engine.state.effects.retain(|e| !e.target.is_in_play(in_play));
+        engine.state.effects.extend(before.state.effects.iter().filter(|e| e.target.is_in_play(in_play)).cloned());
         engine
     }
 
@@ -557,17 +1049,32 @@ impl GameEngine {
         let knock_out_effects = self.state.effects.iter()
             .flat_map(|e| self.effect(e).on_knocked_out(&e, in_play, &self))
             .collect::<Vec<_>>();
-        println!("knocked out, all effects: {}", self.state.effects.len());
-        println!("knocked out, kod effects: {}", knock_out_effects.len());
         for effect in knock_out_effects {
             let ctx = effect.apply(engine, dm);
             engine = ctx.engine();
         }
 
+        // Final Beam (Dark Gyarados): on heads, 20 damage to the attacker for
+        // each Water Energy attached.
+        if self.is_someone_attacking() && self.attacking().id != in_play.id && self.has_pokemon_power(in_play, "Final Beam") {
+            let water = in_play.attached.iter()
+                .filter(|c| engine.is_energy(c.card()) && engine.provides(c.card()).contains(&Type::Water))
+                .count();
+            let (flipped, flips) = engine.flip(in_play.owner, 1, dm);
+            engine = flipped;
+            if flips.heads() == 1 {
+                let attacking = engine.attacking().clone();
+                let (damaged, _) = engine.push_target(in_play, &attacking).damage_ignoring_weakness_and_resistance(20 * water, dm);
+                engine = damaged.pop_target();
+            }
+        }
+
         // TODO: calculate prizes (Leap Through Time, Hero's Medal)
         // TODO: implement multi prizers (EX, GX, V, VMAX, VSTAR)
         let prizes = 1;
 
+        engine = engine.with_event(GameEvent::KnockedOut { player: in_play.owner, pokemon: in_play.id });
+
         // TODO: effects that affect discarded cards (Splash Energy, Leap Through Time, Exp. Share)
         for card in in_play.cards() {
             engine = engine.with_state(engine.state.move_card_to_discard(card));
@@ -578,7 +1085,9 @@ impl GameEngine {
     }
 
     pub fn attach_from_hand(&self, card: &Card, target: &InPlayCard) -> Self {
-        self.with_state(self.state.attach_from_hand(card, target))
+        self
+            .with_state(self.state.attach_from_hand(card, target))
+            .on_attach_from_hand(card)
     }
 
     pub fn attach_from_hand_possibilities(&self) -> Vec<(&Card, &InPlayCard)> {
@@ -609,6 +1118,7 @@ impl GameEngine {
     pub fn current_attack_name(&self) -> Option<String> {
         match self.resolving_actions.last() {
             Some(Action::Attack(_, _, attack)) => Some(attack.name().clone()),
+            Some(Action::PokePower(_, _, poke_power)) => Some(poke_power.name().clone()),
             _ => None,
         }
     }
@@ -632,8 +1142,9 @@ impl GameEngine {
     pub fn opponent(&self) -> Player {
         match self.resolving_actions.last() {
             Some(Action::Attack(player, _, _)) => player.opponent(),
+            Some(Action::PokePower(player, _, _)) => player.opponent(),
             Some(Action::TrainerFromHand(player, _)) => player.opponent(),
-            _ => { panic!("Error accessing GameEngine::opponent() while not attacking or using a trainer card"); }
+            _ => { panic!("Error accessing GameEngine::opponent() while not attacking, using an ability, or using a trainer card"); }
         }
     }
 
@@ -675,26 +1186,88 @@ impl GameEngine {
         engine
     }
 
-    pub fn damage(&self, mut damage: usize) -> (Self, usize) {
+    pub fn damage(&self, damage: usize, dm: &mut dyn DecisionMaker) -> (Self, usize) {
         // TODO: don't apply weakness and resistance by default to benched pokémon
         // TODO: leave the door open for some attacks that do apply them.
+        self.do_damage(damage, true, dm)
+    }
 
+    pub fn damage_ignoring_weakness_and_resistance(&self, damage: usize, dm: &mut dyn DecisionMaker) -> (Self, usize) {
+        self.do_damage(damage, false, dm)
+    }
+
+    fn do_damage(&self, base: usize, weakness_and_resistance: bool, dm: &mut dyn DecisionMaker) -> (Self, usize) {
+        let mut damage = base;
         if self.format.attacking_effects() == AttackingEffectsWhen::BeforeWR {
             damage = self.effects_on_attacking(damage);
         }
-        damage = self.apply_weakness(damage);
-        damage = self.apply_resistance(damage);
+        if weakness_and_resistance {
+            damage = self.apply_weakness(damage);
+        }
+        let after_weakness = damage;
+        if weakness_and_resistance {
+            damage = self.apply_resistance(damage);
+        }
+        let after_resistance = damage;
         if self.format.attacking_effects() == AttackingEffectsWhen::AfterWR {
             damage = self.effects_on_attacking(damage);
         }
         damage = self.effects_on_defending(damage);
 
-        (self.with_state(self.state.add_damage_counters(self.defending(), damage/10)), damage)
+        let defending = self.defending();
+        let mut engine = self
+            .with_state(self.state.add_damage_counters(defending, damage/10))
+            .with_event(GameEvent::DamageDealt { player: defending.owner, pokemon: defending.id, base, after_weakness, after_resistance, dealt: damage });
+
+        // remember what the attack did, for attacks that look back at the last turn (eg: Mirror Move).
+        if damage > 0 && self.is_someone_attacking() {
+            let defending = self.defending();
+            engine = engine.with_effect(Effect {
+                source: EffectSource::Attack(self.attacking().owner, self.attacking().id),
+                target: EffectTarget::InPlayPokemon(defending.owner, defending.id),
+                expires: EffectExpiration::EndOfTurn(defending.owner, 0),
+                consequence: DamagedByAttack::identifier(),
+                parameters: vec![EffectParameter::USize(damage)],
+                name: "Damaged by attack".into(),
+            });
+        }
+
+        // Frenzy (Dark Primeape): an opponent's Pokémon that damages it while
+        // it's the Active Pokémon is now Confused, even if it's Knocked Out.
+        if damage > 0 && self.is_someone_attacking() && self.attacking().owner != defending.owner {
+            let is_active = self.state.side(defending.owner).active.iter().any(|active| active.id == defending.id);
+            if is_active && self.has_pokemon_power(defending, "Frenzy") {
+                let attacking = self.attacking().clone();
+                engine = engine.confuse(&attacking);
+            }
+        }
+
+        // the damage is done, trigger response effects (eg: Mirror Shell).
+        if damage > 0 {
+            let damaged_effects = engine.state.effects.iter()
+                .flat_map(|e| engine.effect(e).on_damaged(e, engine.defending(), &engine, damage))
+                .collect::<Vec<_>>();
+            for effect in damaged_effects {
+                engine = effect.apply(engine, dm).engine();
+            }
+        }
+
+        (engine, damage)
+    }
+
+    // The attack doesn't happen, the Confused Pokémon damages itself instead.
+    // Weakness, resistance and other effects on damage don't apply.
+    fn hurt_by_confusion(&self, in_play: &InPlayCard) -> Self {
+        let damage = self.format.confusion().self_damage();
+
+        self
+            .with_state(self.state.add_damage_counters(in_play, damage / 10))
+            .with_event(GameEvent::DamageDealt { player: in_play.owner, pokemon: in_play.id, base: damage, after_weakness: damage, after_resistance: damage, dealt: damage })
     }
 
-    pub fn damage_self(&self, damage: usize) -> (Self, usize) {
+    pub fn damage_self(&self, damage: usize, dm: &mut dyn DecisionMaker) -> (Self, usize) {
         let engine = self.clone().push_target(self.attacking(), self.attacking());
-        let (engine, counters) = engine.damage(damage);
+        let (engine, counters) = engine.damage(damage, dm);
         let engine = engine.pop_target();
 
         (engine, counters)
@@ -704,12 +1277,35 @@ impl GameEngine {
         self.format.behavior(card)
     }
 
+    // Effects only make it into a game through the engine itself or a
+    // snapshot checked against the format, so a miss here is a bug.
     pub fn effect(&self, effect: &Effect) -> &dyn CustomEffect {
-        self.format.effect(&effect.consequence)
+        self.format
+            .effect(&effect.consequence)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    // Transform (Ditto): as the Active Pokémon it's the same card as the
+    // Defending Pokémon, except that it can't evolve and keeps Transform
+    // instead of the Defending Pokémon's Pokémon Powers. It's only Ditto
+    // again while Asleep, Confused or Paralyzed.
+    fn transformed_into(&self, in_play: &InPlayCard) -> Option<&Card> {
+        let side = self.state.side(in_play.owner);
+        if !side.active.iter().any(|active| active.id == in_play.id) || !self.has_pokemon_power(in_play, "Transform") {
+            return None;
+        }
+
+        self.state.side(in_play.owner.opponent()).active.first().map(|defending| defending.stack[0].card())
+    }
+
+    // The card `in_play` counts as for HP, type, weakness, resistance,
+    // retreat cost and attacks.
+    fn printed_as<'a>(&'a self, in_play: &'a InPlayCard) -> &'a Card {
+        self.transformed_into(in_play).unwrap_or_else(|| in_play.stack[0].card())
     }
 
     pub fn get_weakness(&self, in_play: &InPlayCard) -> Resistance {
-        let mut weakness = self.archetype(in_play.stack[0].card()).weakness();
+        let mut weakness = self.archetype(self.printed_as(in_play)).weakness();
 
         for effect in self.state.effects.iter() {
             if let Some(new_weakness) = self.effect(effect).get_weakness(effect, in_play, self, weakness.clone()) {
@@ -721,7 +1317,7 @@ impl GameEngine {
     }
 
     pub fn get_resistance(&self, in_play: &InPlayCard) -> Resistance {
-        let mut resistance = self.archetype(in_play.stack[0].card()).resistance();
+        let mut resistance = self.archetype(self.printed_as(in_play)).resistance();
 
         for effect in self.state.effects.iter() {
             if let Some(new_resistance) = self.effect(effect).get_resistance(effect, in_play, self, resistance.clone()) {
@@ -733,7 +1329,15 @@ impl GameEngine {
     }
 
     pub fn pokemon_types(&self, in_play: &InPlayCard) -> Vec<Type> {
-        self.archetype(in_play.stack[0].card()).pokemon_type()
+        let mut types = self.archetype(self.printed_as(in_play)).pokemon_type();
+
+        for effect in self.state.effects.iter() {
+            if let Some(new_types) = self.effect(effect).get_types(effect, in_play, self, types.clone()) {
+                types = new_types;
+            }
+        }
+
+        types
     }
 
     pub fn apply_weakness(&self, mut damage: usize) -> usize {
@@ -752,7 +1356,7 @@ impl GameEngine {
     pub fn apply_resistance(&self, mut damage: usize) -> usize {
         let (offset, types) = self.get_resistance(self.defending());
         for weakness in types {
-            if self.archetype(self.attacking().stack[0].card()).pokemon_type().contains(&weakness) {
+            if self.pokemon_types(self.attacking()).contains(&weakness) {
                 damage = damage.saturating_sub(offset);
             }
         }
@@ -796,24 +1400,49 @@ impl GameEngine {
     // end attack in flight
     pub fn paralyze(&self, target: &InPlayCard) -> Self {
         affected!(self, target);
+        if self.is_thick_skinned(target) {
+            return self.clone();
+        }
         self.with_state(self.state.paralyze(target))
     }
 
     pub fn asleep(&self, target: &InPlayCard) -> Self {
         affected!(self, target);
+        if self.is_thick_skinned(target) {
+            return self.clone();
+        }
         self.with_state(self.state.asleep(target))
     }
 
     pub fn poison(&self, target: &InPlayCard, counters: usize) -> Self {
         affected!(self, target);
+        if self.is_thick_skinned(target) {
+            return self.clone();
+        }
         self.with_state(self.state.poison(target, counters))
     }
 
+    pub fn burn(&self, target: &InPlayCard) -> Self {
+        affected!(self, target);
+        self.with_state(self.state.burn(target))
+    }
+
     pub fn confuse(&self, target: &InPlayCard) -> Self {
         affected!(self, target);
+        if self.is_thick_skinned(target) {
+            return self.clone();
+        }
         self.with_state(self.state.confuse(target))
     }
 
+    // Thick Skinned (Snorlax): it can't become Asleep, Confused, Paralyzed or Poisoned.
+    fn is_thick_skinned(&self, target: &InPlayCard) -> bool {
+        match self.state.in_play(&target.id) {
+            Some(in_play) => self.has_pokemon_power(in_play, "Thick Skinned"),
+            None => false,
+        }
+    }
+
     // trainer in flight?
     // TODO: We have an AttackBuilder, we should have a TrainerBuilder
     pub fn trainer_card(&self) -> &Card {
@@ -929,10 +1558,9 @@ impl GameEngine {
     pub fn search_any_deck_to_hand(&self, who: Player, how_many: usize, dm: &mut dyn DecisionMaker) -> Self {
         let mut engine = self.ensure_deck_not_empty(who);
 
-        let deck_cards = engine.state.side(who).deck.cards().into_iter()
-            .collect();
+        let deck_cards = engine.state.side(who).deck.cards();
 
-        let chosen = dm.search_deck(who, who, how_many, &deck_cards);
+        let chosen = dm.search_deck_cards(who, who, how_many, &deck_cards);
         for searched in chosen {
             engine.state = engine.state.tutor_to_hand(who, searched);
         }
@@ -946,9 +1574,9 @@ impl GameEngine {
 
         let deck_cards = engine.state.side(who).deck.cards().into_iter()
             .filter(|card| filter(&engine, card))
-            .collect();
+            .collect::<Vec<_>>();
 
-        let chosen = dm.search_deck(who, who, how_many, &deck_cards);
+        let chosen = dm.search_deck_cards(who, who, how_many.min(deck_cards.len()), &deck_cards);
         for searched in chosen {
             engine.state = engine.state.tutor_to_hand(who, searched);
         }
@@ -983,7 +1611,7 @@ impl GameEngine {
     pub fn end_turn(&self) -> Self {
         let mut engine = self.clone();
 
-        if self.is_finished() {
+        if self.is_over() {
             return engine;
         }
 
@@ -994,10 +1622,14 @@ impl GameEngine {
 
         engine.state = engine.state.with_stage(GameStage::EndOfTurn(player));
 
-        engine.state.effects.retain(|e| match e.expires {
-            EffectExpiration::EndOfTurn(p, 0) => p != player,
-            _ => true,
+        let (expired, effects) = engine.state.effects.iter().cloned().partition::<Vec<_>, _>(|e| match e.expires {
+            EffectExpiration::EndOfTurn(p, 0) => p == player,
+            _ => false,
         });
+        engine.state.effects = effects;
+        for effect in expired {
+            engine = engine.with_event(GameEvent::EffectExpired { name: effect.name, target: effect.target });
+        }
         for effect in engine.state.effects.iter_mut() {
             match effect.expires {
                 EffectExpiration::EndOfTurn(p, t) => {
@@ -1015,15 +1647,28 @@ impl GameEngine {
             }
         }
 
-        engine
+        let turn = engine.state.turn;
+        engine.with_event(GameEvent::TurnEnded { player, turn })
     }
 
     pub fn with_effect(&self, effect: Effect) -> Self {
-        let mut engine = self.clone();
+        let mut engine = self.with_event(GameEvent::EffectAdded { name: effect.name.clone(), target: effect.target.clone() });
         engine.state.effects.push(effect);
         engine
     }
 
+    pub fn with_event(&self, event: GameEvent) -> Self {
+        self.with_state(self.state.clone().with_event(event))
+    }
+
+    // Flips through the DecisionMaker, keeping track of the results.
+    pub fn flip(&self, player: Player, how_many: usize, dm: &mut dyn DecisionMaker) -> (Self, Flips) {
+        let flips = dm.flip(how_many);
+        let engine = self.with_event(GameEvent::CoinFlipped { player, results: flips.results().to_vec() });
+
+        (engine, flips)
+    }
+
     fn with_state(&self, state: GameState) -> Self {
         Self {
             state,
@@ -1095,7 +1740,20 @@ impl GameEngine {
     }
 
     pub fn retreat_cost(&self, in_play: &InPlayCard) -> Vec<Type> {
-        let how_many = self.archetype(in_play.stack[0].card()).retreat();
+        let mut how_many = self.archetype(self.printed_as(in_play)).retreat();
+
+        // Retreat Aid (Dodrio): one Colorless less for each one on the bench.
+        let side = self.state.side(in_play.owner);
+        if side.active.iter().any(|active| active.id == in_play.id) {
+            let aids = side.bench.iter().filter(|benched| self.has_pokemon_power(benched, "Retreat Aid")).count();
+            how_many = how_many.saturating_sub(aids);
+
+            // Sticky Goo (Dark Muk): two Colorless more while it's the opponent's Active Pokémon.
+            let opponents_active = &self.state.side(in_play.owner.opponent()).active;
+            if opponents_active.iter().any(|active| self.has_pokemon_power(active, "Sticky Goo")) {
+                how_many += 2;
+            }
+        }
 
         let mut cost = vec![];
         for _ in 0..how_many {
@@ -1106,44 +1764,131 @@ impl GameEngine {
     }
 
     pub fn can_retreat(&self, player: Player, in_play: &InPlayCard) -> bool {
-        if self.state.side(player).bench.is_empty() {
+        let side = self.state.side(player);
+        if side.bench.is_empty() || side.retreats_this_turn > 0 {
             return false;
         }
 
-        let mut energy = vec![];
-        for attached in in_play.attached.iter() {
-            if self.is_energy(attached.card()) {
-                energy.extend(self.provides(attached.card()));
+        for effect in self.state.effects.iter() {
+            if let Some(false) = self.effect(effect).can_retreat(effect, in_play, self) {
+                return false;
             }
         }
 
         let cost = self.retreat_cost(in_play);
 
-        self.is_energy_cost_met(&cost, energy)
+        self.is_energy_cost_met(&cost, self.attached_energy(in_play))
     }
 
-    pub fn discard_attached_energies(&self, _player: Player, in_play: &InPlayCard, _cost: &[Type], _dm: &mut dyn DecisionMaker) -> Self {
-        // TODO: pick energies to discard instead of discarding everything
+    pub fn discard_attached_energies(&self, player: Player, in_play: &InPlayCard, cost: &[Type], dm: &mut dyn DecisionMaker) -> Self {
+        let options = self.energy_discard_options(in_play, cost);
+
+        self.discard_chosen_energy(player, cost, &options, dm)
+    }
+
+    pub fn discard_attached_energy_cards(&self, player: Player, in_play: &InPlayCard, cost: &[Type], dm: &mut dyn DecisionMaker) -> (Self, ActionResult) {
+        let options = self.energy_card_discard_options(in_play, cost);
+        let engine = self.discard_chosen_energy(player, cost, &options, dm);
+
+        let paid = options.first().map(|option| self.energy_cards_paid(cost, option)).unwrap_or(0);
+        let result = if paid == cost.len() {
+            ActionResult::Full
+        } else if paid == 0 {
+            ActionResult::Nothing
+        } else {
+            ActionResult::Partial
+        };
+
+        (engine, result)
+    }
+
+    fn discard_chosen_energy(&self, player: Player, cost: &[Type], options: &[Vec<Card>], dm: &mut dyn DecisionMaker) -> Self {
+        let chosen = match options.len() {
+            0 => { return self.clone(); },
+            1 => &options[0],
+            _ => dm.pick_energy_discard(player, cost, options),
+        };
+
         let mut state = self.state.clone();
-        for attached in in_play.attached.iter() {
-            if self.is_energy(attached.card()) {
-                state = state.move_card_to_discard(attached.card());
-            }
+        for card in chosen {
+            state = state.move_card_to_discard(card);
         }
 
         self.with_state(state)
     }
 
-    pub fn discard_attached_energy_cards(&self, _player: Player, in_play: &InPlayCard, _cost: &[Type], _dm: &mut dyn DecisionMaker) -> (Self, ActionResult) {
-        // TODO: pick energies to discard instead of discarding everything
-        let mut state = self.state.clone();
-        for attached in in_play.attached.iter() {
-            if self.is_energy(attached.card()) {
-                state = state.move_card_to_discard(attached.card());
+    // The different sets of energy cards attached to `in_play` that could be
+    // discarded to pay `cost` (eg: a retreat cost), counting every energy they
+    // provide. Nothing in them is left unused.
+    pub fn energy_discard_options(&self, in_play: &InPlayCard, cost: &[Type]) -> Vec<Vec<Card>> {
+        self.discard_options(in_play, |cards| {
+            let provided = cards.iter().flat_map(|card| self.provides(card)).collect();
+            cost.len() - self.missing_energy(cost, provided)
+        })
+    }
+
+    // Same as energy_discard_options, but for costs that count energy cards
+    // (eg: "discard 2 Energy cards attached to Charizard"). If there isn't
+    // enough energy for all of it, these pay as much of it as possible.
+    pub fn energy_card_discard_options(&self, in_play: &InPlayCard, cost: &[Type]) -> Vec<Vec<Card>> {
+        self.discard_options(in_play, |cards| self.energy_cards_paid(cost, cards))
+    }
+
+    fn energy_cards_paid(&self, cost: &[Type], cards: &[Card]) -> usize {
+        self.match_energy_cards(cost, cards).iter().filter(|paid_by| paid_by.is_some()).count()
+    }
+
+    fn discard_options<F>(&self, in_play: &InPlayCard, paid: F) -> Vec<Vec<Card>> where F: Fn(&[Card]) -> usize {
+        let energy = in_play.attached.iter()
+            .map(|attached| attached.card().clone())
+            .filter(|card| self.is_energy(card))
+            .collect::<Vec<_>>();
+
+        let payable = paid(&energy);
+        if payable == 0 {
+            return vec![];
+        }
+
+        // Two Fire Energy are as good as any other two Fire Energy, so only
+        // the number of cards of each archetype matters.
+        let mut kinds: Vec<Vec<Card>> = vec![];
+        for card in energy {
+            match kinds.iter_mut().find(|kind| kind[0].archetype == card.archetype) {
+                Some(kind) => kind.push(card),
+                None => kinds.push(vec![card]),
             }
         }
 
-        (self.with_state(state), ActionResult::Full)
+        // Every card in an option pays for at least one part of the cost, so
+        // no option has more cards than there is cost to pay.
+        let mut options = Self::energy_multisets(&kinds, payable).into_iter()
+            .filter(|subset| paid(subset) >= payable)
+            .filter(|subset| (0..subset.len()).all(|i| {
+                let mut smaller = subset.clone();
+                smaller.remove(i);
+                paid(&smaller) < payable
+            }))
+            .collect::<Vec<_>>();
+
+        options.sort_by_key(|option| option.len());
+        options
+    }
+
+    // Every way of picking at most `budget` cards out of `kinds`, taking the
+    // first cards of each kind.
+    fn energy_multisets(kinds: &[Vec<Card>], budget: usize) -> Vec<Vec<Card>> {
+        let Some((kind, rest)) = kinds.split_first() else {
+            return vec![vec![]];
+        };
+
+        let mut subsets = vec![];
+        for taken in 0..=kind.len().min(budget) {
+            for mut subset in Self::energy_multisets(rest, budget - taken) {
+                subset.splice(0..0, kind[..taken].iter().cloned());
+                subsets.push(subset);
+            }
+        }
+        subsets
     }
 
     pub fn discard_all_attached_energy_cards(&self, _player: Player, in_play: &InPlayCard, _dm: &mut dyn DecisionMaker) -> Self {
@@ -1180,18 +1925,60 @@ impl GameEngine {
 
     pub fn retreat(&self, player: Player, in_play: &InPlayCard, dm: &mut dyn DecisionMaker) -> Self {
         let possible_targets = self.state.side(player).bench.clone();
-        let chosen = dm.pick_in_play(player, 1, &possible_targets);
+        let chosen = dm.pick_in_play_cards(player, 1, &possible_targets);
 
         let cost = self.retreat_cost(in_play);
+        let mut engine = self.discard_attached_energies(player, in_play, &cost, dm);
+        engine.state = engine.state.count_retreat(player);
+
+        // a Confused Pokémon pays the retreat cost whether it gets away or not.
+        if in_play.rotational_status == RotationalStatus::Confused && self.format.confusion().flip_to_retreat() {
+            let (flipped, flips) = engine.flip(player, 1, dm);
+            engine = flipped;
+            if flips.is_tails() {
+                return engine;
+            }
+        }
 
-        self
-            .discard_attached_energies(player, in_play, &cost, dm)
-            .just_switch(player, in_play, &chosen[0])
+        engine = engine.just_switch(player, in_play, chosen[0]);
+
+        // Sinkhole (Dark Dugtrio): 10 damage to the retreating Pokémon for each
+        // tails out of 2 coins, flipped by the one retreating.
+        for dugtrio in self.state.side(player.opponent()).all_in_play() {
+            if engine.has_pokemon_power(dugtrio, "Sinkhole") {
+                let (flipped, flips) = engine.flip(player, 2, dm);
+                let retreated = flipped.state.in_play(&in_play.id).unwrap().clone();
+                let (damaged, _) = flipped
+                    .push_target(dugtrio, &retreated)
+                    .damage_ignoring_weakness_and_resistance(10 * flips.tails(), dm);
+                engine = damaged.pop_target();
+            }
+        }
+
+        engine
+    }
+
+    pub fn just_switch(&self, _player: Player, this: &InPlayCard, with: &InPlayCard) -> Self {
+        let engine = self.clear_conditions_and_effects(this);
+
+        engine.with_state(engine.state.switch_active_with(with))
     }
 
-    pub fn just_switch(&self, _player: Player, _this: &InPlayCard, with: &InPlayCard) -> Self {
-        // TODO: clear effects and special conditions
-        self.with_state(self.state.switch_active_with(with))
+    // Special Conditions and the effects of attacks on a Pokémon end when it
+    // leaves the Active Spot (retreat, Switch, Gust of Wind...) or play, and
+    // when it evolves or devolves.
+    pub fn clear_conditions_and_effects(&self, in_play: &InPlayCard) -> Self {
+        let mut engine = self.with_state(self.state.remove_special_conditions(in_play));
+
+        let (ended, effects) = engine.state.effects.iter().cloned().partition::<Vec<_>, _>(|e| {
+            e.target.is_in_play(in_play) && (matches!(e.expires, EffectExpiration::DefendingPokemon) || matches!(e.source, EffectSource::Attack(_, _)))
+        });
+        engine.state.effects = effects;
+        for effect in ended {
+            engine = engine.with_event(GameEvent::EffectExpired { name: effect.name, target: effect.target });
+        }
+
+        engine
     }
 
     pub fn are_action_requirements_met(&self, action: &Action) -> bool {
@@ -1216,6 +2003,16 @@ impl GameEngine {
         }
     }
 
+    // The energy an attack asks for, as declared with AttackBuilder::attack_cost.
+    pub fn attack_cost(&self, in_play: &InPlayCard, attack: &Attack) -> Vec<Type> {
+        let mut dm = FakeDM{};
+        let engine = self
+            .push_action(Action::Attack(in_play.owner, in_play.clone(), attack.clone()))
+            .push_target(in_play, &self.state.side(in_play.owner.opponent()).active[0]);
+
+        attack.build().only_requirements().apply(engine, &mut dm).attack_cost().to_vec()
+    }
+
     pub fn in_play_actions(&self, player: Player, in_play: &InPlayCard, active: bool) -> Vec<Action> {
         let mut actions = vec![];
 
@@ -1240,7 +2037,7 @@ impl GameEngine {
     }
 
     pub fn attacks(&self, in_play: &InPlayCard) -> Vec<Attack> {
-        let mut attacks = self.archetype(in_play.stack[0].card()).attacks();
+        let mut attacks = self.archetype(self.printed_as(in_play)).attacks();
 
         for effect in self.state.effects.iter() {
             if let Some(new_attacks) = self.effect(effect).get_attacks(effect, in_play, self, attacks.clone()) {
@@ -1251,16 +2048,74 @@ impl GameEngine {
         attacks
     }
 
-    pub fn can_attack(&self, _player: Player, _in_play: &InPlayCard) -> bool {
-        true
+    pub fn can_attack(&self, player: Player, _in_play: &InPlayCard) -> bool {
+        !self.is_first_turn_restricted(player, FirstTurnRestriction::NoAttack)
+    }
+
+    // Whether it's `player`'s first turn and the format doesn't let them do
+    // `restriction` on it.
+    pub fn is_first_turn_restricted(&self, player: Player, restriction: FirstTurnRestriction) -> bool {
+        if !self.format.first_turn_restrictions().contains(&restriction) {
+            return false;
+        }
+
+        match restriction {
+            FirstTurnRestriction::NoEvolution => {
+                self.state.turns.last() == Some(&player) && self.state.turns.iter().filter(|&&t| t == player).count() == 1
+            },
+            FirstTurnRestriction::NoAttack | FirstTurnRestriction::NoSupporter => self.state.turns == [player],
+        }
     }
 
     pub fn poke_powers(&self, in_play: &InPlayCard) -> Vec<Attack> {
-        let poke_powers = self.archetype(in_play.stack[0].card()).poke_powers();
+        let mut poke_powers = self.poke_powers_ignoring_toxic_gas(in_play);
+
+        // Toxic Gas (Muk): every Pokémon Power other than Toxic Gas is ignored.
+        let toxic_gas = self.state.all_in_play().into_iter().any(|other| {
+            self.poke_power_affected_by_special_condition(other) &&
+                self.poke_powers_ignoring_toxic_gas(other).iter().any(|p| p.name() == "Toxic Gas")
+        });
+        if toxic_gas {
+            poke_powers.retain(|p| p.name() == "Toxic Gas");
+        }
+
+        poke_powers
+    }
+
+    fn poke_powers_ignoring_toxic_gas(&self, in_play: &InPlayCard) -> Vec<Attack> {
+        let mut poke_powers = self.archetype(in_play.stack[0].card()).poke_powers();
+
+        for effect in self.state.effects.iter() {
+            if let Some(new_poke_powers) = self.effect(effect).get_poke_powers(effect, in_play, self, poke_powers.clone()) {
+                poke_powers = new_poke_powers;
+            }
+        }
 
         poke_powers
     }
 
+    // Whether one of the Pokémon Powers that are always on rather than used
+    // (see AttackBuilder::passive) is working for `in_play` right now.
+    pub fn has_pokemon_power(&self, in_play: &InPlayCard, name: &str) -> bool {
+        self.poke_power_affected_by_special_condition(in_play) &&
+            self.poke_powers(in_play).iter().any(|p| p.name() == name)
+    }
+
+    // Runs a passive Pokémon Power that goes off by itself (eg: Summon Minions
+    // when the card is played), if it's working. The requirements that keep it
+    // from being offered as an action are skipped.
+    pub fn trigger_poke_power(&self, in_play: &InPlayCard, name: &str, dm: &mut dyn DecisionMaker) -> Self {
+        if !self.has_pokemon_power(in_play, name) {
+            return self.clone();
+        }
+
+        let poke_power = self.poke_powers(in_play).into_iter().find(|p| p.name() == name).unwrap();
+        self
+            .push_action(Action::PokePower(in_play.owner, in_play.clone(), poke_power.clone()))
+            .then(|e| poke_power.build().ignore_all_costs().apply(e.clone(), dm).engine())
+            .pop_action()
+    }
+
     pub fn can_use_pokemon_power(&self, _player: Player, _in_play: &InPlayCard) -> bool {
         true
     }
@@ -1291,14 +2146,25 @@ impl GameEngine {
 
     pub fn evolve(&self, player: Player, card: &Card, dm: &mut dyn DecisionMaker) -> Self {
         let possible_targets = self.evolution_targets(card);
-        let target = dm.pick_in_play(player, 1, &possible_targets);
+        let target = dm.pick_in_play_cards(player, 1, &possible_targets);
+
+        self.evolve_from_hand(target[0], card, dm)
+    }
+
+    pub fn evolve_from_hand(&self, in_play: &InPlayCard, card: &Card, dm: &mut dyn DecisionMaker) -> Self {
+        let engine = self.evolve_into(in_play, card);
+        let evolved = engine.state.in_play(&in_play.id).unwrap().clone();
 
-        // TODO: clear effects and special conditions
-        self.with_state(self.state.evolve_from_hand(player, card, &target[0].id))
+        match engine.archetype(card).on_play_from_hand(&evolved, &engine, dm) {
+            Some(triggered) => triggered,
+            None => engine,
+        }
     }
 
     pub fn evolve_into(&self, in_play: &InPlayCard, card: &Card) -> Self {
-        self.with_state(self.state.evolve_from_hand(card.owner, card, &in_play.id))
+        let engine = self.clear_conditions_and_effects(in_play);
+
+        engine.with_state(engine.state.evolve_from_hand(card.owner, card, &in_play.id))
     }
 
     pub fn devolve(&self, in_play: &InPlayCard, stage: &Stage, destination_zone: &Zone) -> Self {
@@ -1318,8 +2184,7 @@ impl GameEngine {
             }
         }
 
-        // TODO: clear effects and special conditions
-        engine
+        engine.clear_conditions_and_effects(in_play)
     }
 
     pub fn can_evolve(&self, card: &Card) -> bool {
@@ -1327,6 +2192,20 @@ impl GameEngine {
     }
 
     pub fn ready_to_evolve(&self, in_play: &InPlayCard) -> bool {
+        if self.is_first_turn_restricted(in_play.owner, FirstTurnRestriction::NoEvolution) {
+            return false;
+        }
+
+        // Prehistoric Power (Aerodactyl): no Evolution cards can be played.
+        if self.state.all_in_play().into_iter().any(|other| self.has_pokemon_power(other, "Prehistoric Power")) {
+            return false;
+        }
+
+        // the ones placed during setup have been there since before the first turn
+        if in_play.put_in_play_turn == 0 {
+            return true;
+        }
+
         self.state.turns[in_play.put_in_play_turn.saturating_sub(1) ..= self.state.turn.saturating_sub(1)].iter().filter(|&&t| t == in_play.owner).count() > 1
     }
 
@@ -1379,6 +2258,15 @@ impl GameEngine {
     }
 
     pub fn can_play_trainer_from_hand(&self, card: &Card) -> bool {
+        if self.is_supporter(card) && self.is_first_turn_restricted(card.owner, FirstTurnRestriction::NoSupporter) {
+            return false;
+        }
+
+        // Hay Fever (Dark Vileplume): no Trainer cards can be played.
+        if self.state.all_in_play().into_iter().any(|in_play| self.has_pokemon_power(in_play, "Hay Fever")) {
+            return false;
+        }
+
         self.state.effects.iter()
             .filter(|e| e.target == EffectTarget::Player(card.owner))
             .map(|e| self.effect(e).on_trainer())
@@ -1388,6 +2276,10 @@ impl GameEngine {
     }
 
     pub fn is_attack_energy_cost_met(&self, in_play: &InPlayCard, cost: &[Type]) -> bool {
+        self.is_energy_cost_met(cost, self.attached_energy(in_play))
+    }
+
+    pub fn attached_energy(&self, in_play: &InPlayCard) -> Vec<Type> {
         let mut energy = vec![];
         for attached in in_play.attached.iter() {
             if self.is_energy(attached.card()) {
@@ -1395,36 +2287,137 @@ impl GameEngine {
             }
         }
 
-        self.is_energy_cost_met(cost, energy)
+        // Transform (Ditto): its Energy counts as any type.
+        if self.transformed_into(in_play).is_some() {
+            energy = energy.iter().map(|_| Type::Any).collect();
+        }
 
+        energy
     }
 
-    pub fn is_energy_cost_met(&self, cost: &[Type], mut provided: Vec<Type>) -> bool {
-        // TODO: This assumes that colorless requirements are at the end to work. Also, it's not
-        // ready to work with rainbow energies or any energy that provides more than one type.
-        for required in cost {
-            match required {
-                Type::Colorless => {
-                    if provided.is_empty() { return false; }
-                    provided.remove(0);
-                },
-                _ => {
-                    match provided.iter().position(|c| c == required) {
-                        Some(p) => { provided.remove(p); },
-                        None => { return false; }
-                    };
-                },
+    pub fn is_energy_cost_met(&self, cost: &[Type], provided: Vec<Type>) -> bool {
+        self.missing_energy(cost, provided) == 0
+    }
+
+    // How many more energy `provided` needs to pay for `cost`.
+    pub fn missing_energy(&self, cost: &[Type], provided: Vec<Type>) -> usize {
+        Self::match_energy(cost, &provided).iter().filter(|paid_by| paid_by.is_none()).count()
+    }
+
+    // Which of `cards` pays each symbol of `cost`, in the order of the cost, or None
+    // if they can't pay for all of it. Cards that provide more than one energy (eg:
+    // Double Colorless Energy) show up once for every symbol they pay.
+    pub fn energy_payment(&self, cost: &[Type], cards: &[Card]) -> Option<Vec<Card>> {
+        let mut owners = vec![];
+        let mut provided = vec![];
+        for card in cards {
+            for energy in self.provides(card) {
+                owners.push(card.clone());
+                provided.push(energy);
             }
         }
 
-        true
+        Self::match_energy(cost, &provided)
+            .into_iter()
+            .map(|paid_by| paid_by.map(|i| owners[i].clone()))
+            .collect()
+    }
+
+    pub fn attack_energy_payment(&self, in_play: &InPlayCard, cost: &[Type]) -> Option<Vec<Card>> {
+        let energy = in_play.attached.iter()
+            .map(|attached| attached.card().clone())
+            .filter(|card| self.is_energy(card))
+            .collect::<Vec<_>>();
+
+        self.energy_payment(cost, &energy)
+    }
+
+    // Maximum matching between the symbols of `cost` and the energies in `provided`,
+    // as the index of the energy paying each symbol. Colorless can be paid by any
+    // energy and Type::Any pays for any symbol, so the order doesn't matter.
+    fn match_energy(cost: &[Type], provided: &[Type]) -> Vec<Option<usize>> {
+        Self::match_symbols(cost, provided.len(), &|symbol, e| Self::pays(&provided[e], symbol))
+    }
+
+    // Like match_energy, but every card pays for one symbol at most, which is how
+    // "discard an Energy card" costs count. Type::Any in those costs is any card.
+    fn match_energy_cards(&self, cost: &[Type], cards: &[Card]) -> Vec<Option<usize>> {
+        let provided = cards.iter().map(|card| self.provides(card)).collect::<Vec<_>>();
+
+        Self::match_symbols(cost, cards.len(), &|symbol, c| {
+            provided[c].iter().filter_map(|energy| Self::pays(energy, symbol)).min()
+        })
+    }
+
+    // Whether `energy` can pay for `symbol`, and how much we'd rather not use it
+    // for that: the exact type goes first and Type::Any last, so that wildcards
+    // are left over for the symbols that need them.
+    fn pays(energy: &Type, symbol: &Type) -> Option<usize> {
+        if energy == symbol {
+            Some(0)
+        } else if *energy == Type::Any {
+            Some(2)
+        } else if *symbol == Type::Colorless || *symbol == Type::Any {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn match_symbols(cost: &[Type], payers: usize, pays: &dyn Fn(&Type, usize) -> Option<usize>) -> Vec<Option<usize>> {
+        fn augment(symbol: usize, cost: &[Type], pays: &dyn Fn(&Type, usize) -> Option<usize>, paying: &mut [Option<usize>], seen: &mut [bool]) -> bool {
+            let mut candidates = (0..paying.len())
+                .filter_map(|p| pays(&cost[symbol], p).map(|preference| (preference, p)))
+                .collect::<Vec<_>>();
+            candidates.sort();
+
+            for (_, payer) in candidates {
+                if seen[payer] {
+                    continue;
+                }
+                seen[payer] = true;
+
+                if paying[payer].is_none_or(|other| augment(other, cost, pays, paying, seen)) {
+                    paying[payer] = Some(symbol);
+                    return true;
+                }
+            }
+
+            false
+        }
+
+        // Typed symbols first, they have fewer energies to pick from.
+        let mut order = (0..cost.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&s| cost[s] == Type::Colorless || cost[s] == Type::Any);
+
+        let mut paying = vec![None; payers];
+        for symbol in order {
+            augment(symbol, cost, pays, &mut paying, &mut vec![false; payers]);
+        }
+
+        let mut paid_by = vec![None; cost.len()];
+        for (payer, symbol) in paying.iter().enumerate() {
+            if let Some(symbol) = symbol {
+                paid_by[*symbol] = Some(payer);
+            }
+        }
+
+        paid_by
     }
 
     pub fn manual_attach_energy_card(&self, player: Player, card: &Card, dm: &mut dyn DecisionMaker) -> Self {
         let targets = self.attachment_from_hand_targets(player, card);
-        let target = dm.pick_in_play(player, 1, &targets)[0];
+        let target = dm.pick_in_play_cards(player, 1, &targets)[0];
+
+        self
+            .with_state(self.state.manual_attach_from_hand(player, card, target))
+            .on_attach_from_hand(card)
+    }
 
-        self.with_state(self.state.manual_attach_from_hand(player, card, &target))
+    fn on_attach_from_hand(&self, card: &Card) -> Self {
+        let target = self.in_play_card(card).unwrap();
+
+        self.archetype(card).on_attach_from_hand(card, &target, self).unwrap_or_else(|| self.clone())
     }
 
     pub fn can_attach_energy_from_hand(&self, player: Player) -> bool {
@@ -1454,7 +2447,7 @@ impl GameEngine {
     }
 
     pub fn scoop_up<F>(&self, in_play: &InPlayCard, filter: F) -> Self where F: Fn(&GameEngine, &Card) -> bool {
-        let mut engine = self.clone();
+        let mut engine = self.clear_conditions_and_effects(in_play);
         for card in in_play.cards() {
             if filter(&engine, card) {
                 engine.state = engine.state.move_card_to_hand(card);
@@ -1466,15 +2459,75 @@ impl GameEngine {
         engine
     }
 
+    pub fn shuffle_into_deck<F>(&self, in_play: &InPlayCard, filter: F) -> Self where F: Fn(&GameEngine, &Card) -> bool {
+        let mut engine = self.clear_conditions_and_effects(in_play);
+        for card in in_play.cards() {
+            if filter(&engine, card) {
+                engine.state = engine.state.move_card_to_deck(card);
+            } else {
+                engine.state = engine.state.move_card_to_discard(card);
+            }
+        }
+
+        engine.state = engine.state.shuffle_deck(in_play.owner);
+        engine
+    }
+
+    pub fn shuffle_deck(&self, player: Player) -> Self {
+        self.with_state(self.state.shuffle_deck(player))
+    }
+
+    pub fn shuffle_from_hand_into_deck(&self, player: Player, card: &Card) -> Self {
+        self.with_state(self.state.shuffle_from_hand_into_deck(player, card))
+    }
+
+    pub fn shuffle_from_discard_into_deck(&self, player: Player, cards: &[&Card]) -> Self {
+        let mut engine = self.clone();
+        for card in cards {
+            engine.state = engine.state.move_card_to_deck(card);
+        }
+
+        engine.state = engine.state.shuffle_deck(player);
+        engine
+    }
+
+    pub fn evolve_from_deck(&self, in_play: &InPlayCard, card: &Card) -> Self {
+        self.with_state(self.state.evolve_from_deck(card.owner, card, &in_play.id).shuffle_deck(card.owner))
+    }
+
+    pub fn attach_from_deck(&self, card: &Card, target: &InPlayCard) -> Self {
+        self.with_state(self.state.attach_from_deck(card, target).shuffle_deck(card.owner))
+    }
+
+    pub fn bench_from_deck(&self, player: Player, card: &Card) -> Self {
+        self.with_state(self.state.bench_from_deck(player, card))
+    }
+
+    pub fn move_attached_card(&self, card: &Card, target: &InPlayCard) -> Self {
+        self.with_state(self.state.move_attached_card(card, target))
+    }
+
+    pub fn reveal_prizes(&self, player: Player) -> Self {
+        self.with_state(self.state.reveal_prizes(player))
+    }
+
     pub fn gust(&self, player: Player, dm: &mut dyn DecisionMaker) -> Self {
         let target = player.opponent();
-        let chosen = dm.pick_in_play(player, 1, &self.state.side(target).bench);
+        if self.state.side(target).bench.is_empty() {
+            return self.clone();
+        }
+
+        let chosen = dm.pick_in_play_cards(player, 1, &self.state.side(target).bench);
 
         self.just_switch(target, &self.state.side(target).active[0], &chosen[0])
     }
 
     pub fn switch(&self, player: Player, dm: &mut dyn DecisionMaker) -> Self {
-        let chosen = dm.pick_in_play(player, 1, &self.state.side(player).bench);
+        if self.state.side(player).bench.is_empty() {
+            return self.clone();
+        }
+
+        let chosen = dm.pick_in_play_cards(player, 1, &self.state.side(player).bench);
 
         self.just_switch(player, &self.state.side(player).active[0], &chosen[0])
     }
@@ -1483,6 +2536,15 @@ impl GameEngine {
         self.with_state(self.state.draw_n_to_hand(player, how_many, dm.shuffler()))
     }
 
+    pub fn discard_from_top_of_deck(&self, player: Player, how_many: usize, dm: &mut dyn DecisionMaker) -> Self {
+        let mut engine = self.clone();
+        for _ in 0..how_many {
+            engine.state = engine.state.discard_top_of_deck(player, dm.shuffler());
+        }
+
+        engine
+    }
+
     pub fn bench_from_hand(&self, player: Player, card: &Card) -> Self {
         self.with_state(self.state.bench_from_hand(player, card))
     }
@@ -1627,19 +2689,28 @@ impl GameEngine {
             }
         }
 
-        // TODO: flip coin to decide who goes first, or check for First Ticket DRV 19.
-        engine = engine.setup_bench(dm).setup_prizes(dm).setup_reveal_pokemon();
+        // TODO: check for First Ticket DRV 19.
+        let (flipped, first) = engine.setup_first_player(dm);
+        engine = flipped.setup_bench(dm).setup_prizes(dm).setup_reveal_pokemon();
 
         // TODO: check for abilities that activate on reveal (Sableye SF 48)
 
         engine.state = engine.state
-            .with_stage(GameStage::StartOfTurn(Player::One))
-            .next_turn(Player::One);
+            .with_stage(GameStage::StartOfTurn(first))
+            .next_turn(first);
 
-        println!("Hand sizes: {}, {}", engine.state.p1.hand.len(), engine.state.p2.hand.len());
         engine
     }
 
+    // Flip a coin, whoever wins it decides who goes first.
+    pub fn setup_first_player(&self, dm: &mut dyn DecisionMaker) -> (Self, Player) {
+        let (engine, flips) = self.flip(Player::One, 1, dm);
+        let winner = if flips.heads() == 1 { Player::One } else { Player::Two };
+        let first = if dm.confirm_going_first(winner) { winner } else { winner.opponent() };
+
+        (engine.with_event(GameEvent::GoingFirst { player: first }), first)
+    }
+
     pub fn setup_bench(&self, dm: &mut dyn DecisionMaker) -> Self {
         let mut engine = self.clone();
 
@@ -1659,11 +2730,11 @@ impl GameEngine {
     pub fn setup_prizes(&self, dm: &mut dyn DecisionMaker) -> Self {
         let mut engine = self.clone();
 
-        for _ in 0..6 {
+        for _ in 0..engine.state.prize_count() {
             engine.state = engine.state.draw_to_prizes(Player::One, dm.shuffler());
         }
 
-        for _ in 0..6 {
+        for _ in 0..engine.state.prize_count() {
             engine.state = engine.state.draw_to_prizes(Player::Two, dm.shuffler());
         }
 
@@ -1684,27 +2755,22 @@ impl GameEngine {
 
         let benchable = side.hand.iter().filter(|c| self.placeable_as_benched_during_setup(c)).cloned().collect::<Vec<_>>();
 
-        dm.confirm_setup_bench_selection(player, &benchable)
+        let upto = self.bench_size(player);
+        dm.confirm_setup_bench_selection(player, upto, &benchable).into_iter().take(upto).collect()
     }
 
     pub fn confirm_setup_selection(&self, player: Player, dm: &mut dyn DecisionMaker) -> SetupActiveSelection {
         let yes = self.state.side(player).hand.iter().filter(|c| self.placeable_as_active_during_setup(c) == Maybe::Yes).cloned().collect::<Vec<_>>();
         let maybe = self.state.side(player).hand.iter().filter(|c| self.placeable_as_active_during_setup(c) == Maybe::Maybe).cloned().collect::<Vec<_>>();
 
-        println!("Player {:?}: {:?}", player, self.state.side(player).hand);
-        println!("Player {:?}: Pick from {:?}, {:?}", player, yes, maybe);
-
-        let selection = if yes.is_empty() && maybe.is_empty() {
+        if yes.is_empty() && maybe.is_empty() {
             dm.confirm_setup_mulligan(player);
             SetupActiveSelection::Mulligan
         } else if yes.is_empty() {
             dm.confirm_setup_active_or_mulligan(player, &maybe)
         } else {
             SetupActiveSelection::Place(dm.confirm_setup_active(player, &yes, &maybe))
-        };
-
-        println!("Player {:?}: selected {:?}", player, selection);
-        selection
+        }
     }
 
     pub fn placeable_as_active_during_setup(&self, card: &Card) -> Maybe {
@@ -1757,8 +2823,13 @@ impl GameEngine {
         self.archetype(card).is_trainer(card, self)
     }
 
+    pub fn is_supporter(&self, card: &Card) -> bool {
+        self.archetype(card).is_supporter(card, self)
+    }
+
     pub fn full_hp(&self, in_play: &InPlayCard) -> usize {
-        self.archetype(in_play.stack[0].card()).hp(in_play.stack[0].card(), self).unwrap_or(0)
+        let card = self.printed_as(in_play);
+        self.archetype(card).hp(card, self).unwrap_or(0)
     }
 
     pub fn remaining_hp(&self, in_play: &InPlayCard) -> usize {
@@ -1771,19 +2842,27 @@ impl GameEngine {
 
     pub fn is_energy(&self, card: &Card) -> bool {
         // TODO: Electrode?
-        self.is_basic_energy(card) || card.archetype == "Double Colorless Energy (BS 96)"
+        self.archetype(card).is_energy(card, self)
     }
 
+    // By name, so that reprints (eg: Base Set 2's) count too.
     pub fn is_basic_energy(&self, card: &Card) -> bool {
-        match card.archetype.as_str() {
-            "Fighting Energy (BS 97)" => true,
-            "Fire Energy (BS 98)" => true,
-            "Grass Energy (BS 99)" => true,
-            "Lightning Energy (BS 100)" => true,
-            "Psychic Energy (BS 101)" => true,
-            "Water Energy (BS 102)" => true,
-            _ => false,
-        }
+        self.is_energy(card) && matches!(self.archetype(card).name().as_str(),
+            "Fighting Energy" | "Fire Energy" | "Grass Energy" | "Lightning Energy" | "Psychic Energy" | "Water Energy")
+    }
+
+    // Dark and Light Pokémon are their own cards with their own names: Dark
+    // Kadabra evolves from Abra, but only Dark Kadabra evolves into Dark Alakazam.
+    pub fn is_dark_pokemon(&self, card: &Card) -> bool {
+        self.is_pokemon(card) && self.archetype(card).name().starts_with("Dark ")
+    }
+
+    pub fn is_light_pokemon(&self, card: &Card) -> bool {
+        self.is_pokemon(card) && self.archetype(card).name().starts_with("Light ")
+    }
+
+    pub fn is_named(&self, in_play: &InPlayCard, names: &[&str]) -> bool {
+        names.contains(&self.archetype(in_play.stack[0].card()).name().as_str())
     }
 
     pub fn stage(&self, card: &Card) -> Option<Stage> {
@@ -1833,13 +2912,9 @@ impl GameEngine {
             engine.state = engine.state.draw_to_working_area(whose, dm.shuffler());
         }
 
-        println!("working area before: {:?}", engine.state.side(whose).working_area);
-
         let rearranged = dm.rearrange(who, &engine.state.side(whose).working_area);
         engine.state = engine.state.rearrange_working_area(whose, &rearranged);
 
-        println!("working area after: {:?}", engine.state.side(whose).working_area);
-
         engine.state = engine.state.put_working_area_on_top_of_deck(whose);
         engine
     }
@@ -1861,7 +2936,7 @@ impl GameEngine {
         self.state.side(player).all_in_play()
     }
 
-    pub fn basic_for_stage2(&self, card: &Card) -> String {
+    pub fn basic_for_stage2(&self, card: &Card) -> Result<String, UnknownCard> {
         self.format.basic_for_stage2(card)
     }
 }
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use crate::state::{Card, EffectTarget, InPlayID, Player};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpecialCondition {
+    Asleep,
+    Confused,
+    Paralyzed,
+    Poisoned,
+    Burned,
+}
+
+// What happened during a step, in the order it happened. The engine collects
+// these as it goes (see GameEngine::events), so UIs and logs don't have to
+// diff states to find out.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    CardDrawn { card: Card },
+    EnergyAttached { card: Card, player: Player, pokemon: InPlayID },
+    Evolved { card: Card, player: Player, pokemon: InPlayID },
+    AttackDeclared { player: Player, pokemon: InPlayID, attack: String },
+    // base is what the attack asked for; after_weakness and after_resistance
+    // are the running total as each got applied (unchanged when they don't),
+    // and dealt is what landed once every other effect had its say.
+    DamageDealt { player: Player, pokemon: InPlayID, base: usize, after_weakness: usize, after_resistance: usize, dealt: usize },
+    CoinFlipped { player: Player, results: Vec<bool> },
+    SpecialConditionApplied { player: Player, pokemon: InPlayID, condition: SpecialCondition },
+    KnockedOut { player: Player, pokemon: InPlayID },
+    PrizeTaken { card: Card },
+    EffectAdded { name: String, target: EffectTarget },
+    EffectExpired { name: String, target: EffectTarget },
+    TurnEnded { player: Player, turn: usize },
+    // whoever won the opening coin flip decided `player` goes first.
+    GoingFirst { player: Player },
+    // the game ended with both players winning, a Sudden Death game starts.
+    SuddenDeath,
+}
@@ -1,3 +1,24 @@
 mod base_fossil;
 
 pub use base_fossil::BaseFossil;
+
+use crate::engine::{Format, Rules};
+
+pub fn by_name(name: &str) -> Option<Box<dyn Format>> {
+    Some(Box::new(base_fossil(name)?))
+}
+
+// A format playing by other rules than its defaults (eg: one restored from a
+// snapshot).
+pub fn by_name_with_rules(name: &str, rules: &Rules) -> Option<Box<dyn Format>> {
+    Some(Box::new(base_fossil(name)?.with_rules(rules.clone())))
+}
+
+fn base_fossil(name: &str) -> Option<BaseFossil> {
+    match name {
+        "base-fossil" => Some(BaseFossil::new()),
+        "base-rocket" => Some(BaseFossil::with_team_rocket()),
+        "base-set-2" => Some(BaseFossil::base_set_2()),
+        _ => None,
+    }
+}
@@ -1,14 +1,22 @@
+use std::collections::HashMap;
 use std::ops::Deref;
 use crate::state::{Card, Type};
-use crate::engine::{CardArchetype, Format, AttackingEffectsWhen};
-use crate::sets::{base, fossil, jungle};
-use crate::effect::CustomEffect;
+use crate::engine::{CardArchetype, Format, AttackingEffectsWhen, ConfusionRules, BurnRules, CheckupStep, SimultaneousWin, FirstTurnRestriction, Rules};
+use crate::registry::{CardRegistry, UnknownCard};
+use crate::sets::{base, base_set_2, fossil, jungle, team_rocket};
+use crate::effect::{CustomEffect, UnknownEffect};
 use crate::custom_effects::*;
 
 #[derive(Clone)]
 pub struct BaseFossil {
-    archetypes: std::rc::Rc<Vec<(String, Box<dyn CardArchetype>)>>,
-    custom_effects: std::rc::Rc<Vec<(String, Box<dyn CustomEffect>)>>,
+    name: String,
+    registry: std::rc::Rc<CardRegistry>,
+    confusion: ConfusionRules,
+    burn: BurnRules,
+    checkup: Vec<CheckupStep>,
+    simultaneous_win: SimultaneousWin,
+    first_turn_restrictions: Vec<FirstTurnRestriction>,
+    custom_effects: std::rc::Rc<HashMap<String, Box<dyn CustomEffect>>>,
 }
 
 impl BaseFossil {
@@ -19,67 +27,173 @@ impl BaseFossil {
         cards.extend(jungle::build());
         cards.extend(fossil::build());
 
+        Self::with_cards("base-fossil", cards)
+    }
+
+    // Base-Fossil plus Team Rocket (Base-Rocket), same rules otherwise.
+    pub fn with_team_rocket() -> Self {
+        let mut cards = vec![];
+
+        cards.extend(base::build());
+        cards.extend(jungle::build());
+        cards.extend(fossil::build());
+        cards.extend(team_rocket::build());
+
+        Self::with_cards("base-rocket", cards)
+    }
+
+    // Base Set 2 era: Base Set 2 replaces Base Set and Jungle, and Fossil is
+    // no longer legal.
+    pub fn base_set_2() -> Self {
+        let mut cards = vec![];
+
+        cards.extend(base_set_2::build());
+        cards.extend(team_rocket::build());
+
+        Self::with_cards("base-set-2", cards)
+    }
+
+    // Same cards, with Confusion played by other rules (eg: to try the later
+    // eras' 30 damage and free retreat).
+    pub fn with_confusion(self, confusion: ConfusionRules) -> Self {
+        Self { confusion, ..self }
+    }
+
+    pub fn with_burn(self, burn: BurnRules) -> Self {
+        Self { burn, ..self }
+    }
+
+    pub fn with_pokemon_checkup(self, checkup: Vec<CheckupStep>) -> Self {
+        Self { checkup, ..self }
+    }
+
+    pub fn with_simultaneous_win(self, simultaneous_win: SimultaneousWin) -> Self {
+        Self { simultaneous_win, ..self }
+    }
+
+    pub fn with_first_turn_restrictions(self, first_turn_restrictions: Vec<FirstTurnRestriction>) -> Self {
+        Self { first_turn_restrictions, ..self }
+    }
+
+    pub fn with_rules(self, rules: Rules) -> Self {
+        self
+            .with_confusion(rules.confusion)
+            .with_burn(rules.burn)
+            .with_pokemon_checkup(rules.pokemon_checkup)
+            .with_simultaneous_win(rules.simultaneous_win)
+            .with_first_turn_restrictions(rules.first_turn_restrictions)
+    }
+
+    fn with_cards(name: &str, cards: Vec<(String, Box<dyn CardArchetype>)>) -> Self {
+        let custom_effects: Vec<(String, Box<dyn CustomEffect>)> = vec![
+            (PreventDamageDuringOpponentsTurn::identifier(), Box::new(PreventDamageDuringOpponentsTurn{})),
+            (PreventUpToDamageDuringOpponentsTurn::identifier(), Box::new(PreventUpToDamageDuringOpponentsTurn{})),
+            (PreventDamageAndEffectsDuringOpponentsTurn::identifier(), Box::new(PreventDamageAndEffectsDuringOpponentsTurn{})),
+            (BlockTrainerFromHand::identifier(), Box::new(BlockTrainerFromHand{})),
+            (BlockAttachmentFromHand::identifier(), Box::new(BlockAttachmentFromHand{})),
+            (RevengeKnockOut::identifier(), Box::new(RevengeKnockOut{})),
+            (ChangeResistance::identifier(), Box::new(ChangeResistance{})),
+            (ChangeWeakness::identifier(), Box::new(ChangeWeakness{})),
+            (DisableAttack::identifier(), Box::new(DisableAttack{})),
+            (FlipToAttack::identifier(), Box::new(FlipToAttack{})),
+            (EnergyTypeTransform::identifier(), Box::new(EnergyTypeTransform{})),
+            (ReflectDamageDuringOpponentsTurn::identifier(), Box::new(ReflectDamageDuringOpponentsTurn{})),
+            (BlockPokemonPowers::identifier(), Box::new(BlockPokemonPowers{})),
+            (DisablePokePower::identifier(), Box::new(DisablePokePower{})),
+            (ChangeType::identifier(), Box::new(ChangeType{})),
+            (ReduceDamageDuringOpponentsTurn::identifier(), Box::new(ReduceDamageDuringOpponentsTurn{})),
+            (ReduceDamageFromTargetDuringOpponentsTurn::identifier(), Box::new(ReduceDamageFromTargetDuringOpponentsTurn{})),
+            (BlockAttacksAgainstSource::identifier(), Box::new(BlockAttacksAgainstSource{})),
+            (PreventRetreat::identifier(), Box::new(PreventRetreat{})),
+            (SwordsDance::identifier(), Box::new(SwordsDance{})),
+            (DamagedByAttack::identifier(), Box::new(DamagedByAttack{})),
+            (Recall::identifier(), Box::new(Recall{})),
+        ];
+
         Self {
-            archetypes: std::rc::Rc::new(cards),
-            custom_effects: std::rc::Rc::new(vec![
-                (PreventDamageDuringOpponentsTurn::identifier(), Box::new(PreventDamageDuringOpponentsTurn{})),
-                (BlockAttachmentFromHand::identifier(), Box::new(BlockAttachmentFromHand{})),
-                (RevengeKnockOut::identifier(), Box::new(RevengeKnockOut{})),
-                (ChangeResistance::identifier(), Box::new(ChangeResistance{})),
-                (ChangeWeakness::identifier(), Box::new(ChangeWeakness{})),
-                (DisableAttack::identifier(), Box::new(DisableAttack{})),
-                (FlipToAttack::identifier(), Box::new(FlipToAttack{})),
-                (EnergyTypeTransform::identifier(), Box::new(EnergyTypeTransform{})),
-            ]),
+            name: name.into(),
+            registry: std::rc::Rc::new(CardRegistry::new(cards)),
+            confusion: ConfusionRules::Classic,
+            burn: BurnRules::FlipForDamage,
+            checkup: vec![CheckupStep::Poison, CheckupStep::Burn, CheckupStep::Sleep, CheckupStep::Paralysis, CheckupStep::Cards],
+            simultaneous_win: SimultaneousWin::SuddenDeath,
+            first_turn_restrictions: vec![FirstTurnRestriction::NoEvolution],
+            custom_effects: std::rc::Rc::new(custom_effects.into_iter().collect()),
         }
     }
 }
 
 impl Format for BaseFossil {
-    fn behavior_from_id(&self, id: &String) -> &dyn CardArchetype {
-        for (identifier, archetype) in self.archetypes.iter() {
-            if identifier == id {
-                return archetype.deref();
-            }
-        }
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn registry(&self) -> &CardRegistry {
+        &self.registry
+    }
 
-        panic!("Couldn't find card {}", id);
+    fn behavior_from_id(&self, id: &str) -> Result<&dyn CardArchetype, UnknownCard> {
+        self.registry.by_archetype(id)
     }
 
+    // Cards only make it into a game once their deck has been checked
+    // against the registry, so a miss here is a bug.
     fn behavior(&self, card: &Card) -> &dyn CardArchetype {
-        self.behavior_from_id(&card.archetype)
+        self.registry
+            .by_archetype(&card.archetype)
+            .unwrap_or_else(|err| panic!("{}", err))
     }
 
-    fn effect(&self, id: &String) -> &dyn CustomEffect {
-        for (identifier, effect) in self.custom_effects.iter() {
-            if identifier == id {
-                return effect.deref();
-            }
-        }
+    fn effect(&self, id: &str) -> Result<&dyn CustomEffect, UnknownEffect> {
+        self.custom_effects
+            .get(id)
+            .map(|effect| effect.deref())
+            .ok_or_else(|| UnknownEffect(id.to_string()))
+    }
 
-        panic!("Couldn't find effect {}", id);
+    fn banned_cards(&self) -> Vec<String> {
+        vec![]
     }
 
     fn attacking_effects(&self) -> AttackingEffectsWhen {
         AttackingEffectsWhen::AfterWR
     }
 
-    fn basic_for_stage2(&self, card: &Card) -> String {
-        let stage1name = self.behavior(card).evolves_from().unwrap();
+    fn basic_for_stage2(&self, card: &Card) -> Result<String, UnknownCard> {
+        let stage1name = self.behavior_from_id(&card.archetype)?
+            .evolves_from()
+            .ok_or_else(|| UnknownCard::PreEvolution(card.archetype.clone()))?;
+        let stage1 = self.registry.by_name(&stage1name)?;
 
-        for (_, archetype) in self.archetypes.iter() {
-            if archetype.name() == stage1name {
-                return archetype.evolves_from().unwrap();
-            }
-        }
-
-        panic!("Couldn't find basic that matches card {}", card.archetype);
+        stage1[0]
+            .evolves_from()
+            .ok_or(UnknownCard::PreEvolution(stage1name))
     }
 
     fn all_special_conditions_prevent_pokemon_powers(&self) -> bool {
         false
     }
 
+    fn confusion(&self) -> ConfusionRules {
+        self.confusion
+    }
+
+    fn burn(&self) -> BurnRules {
+        self.burn
+    }
+
+    fn pokemon_checkup(&self) -> Vec<CheckupStep> {
+        self.checkup.clone()
+    }
+
+    fn simultaneous_win(&self) -> SimultaneousWin {
+        self.simultaneous_win
+    }
+
+    fn first_turn_restrictions(&self) -> Vec<FirstTurnRestriction> {
+        self.first_turn_restrictions.clone()
+    }
+
     fn available_types(&self) -> Vec<Type> {
         vec![
             Type::Fighting,
@@ -0,0 +1,32 @@
+pub mod state;
+pub mod engine;
+pub mod observer;
+pub mod events;
+pub mod view;
+pub mod carddb;
+pub mod registry;
+pub mod deck;
+mod card_macros;
+pub mod attack_builder;
+pub mod effect;
+pub mod custom_effects;
+pub mod snapshot;
+pub mod replay;
+pub mod server;
+pub mod simulation;
+pub mod ai;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub mod sets;
+pub mod formats;
+
+// Most card definitions pull everything in with `use crate::*`.
+use engine::*;
+
+pub use engine::{GameEngine, DecisionMaker, Format, CardArchetype, Action, Attack, Flips, IllegalAction, ConfusionRules, BurnRules, CheckupStep, SimultaneousWin, FirstTurnRestriction, Rules};
+pub use state::{GameState, Player, Card, InPlayCard, Shuffler, Type};
+pub use attack_builder::AttackBuilder;
+pub use observer::{Observer, Headless};
+pub use events::GameEvent;
+pub use view::PlayerView;
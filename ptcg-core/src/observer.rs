@@ -0,0 +1,16 @@
+use crate::engine::GameEngine;
+
+// Watches a game being played, eg: to draw it on a terminal or to collect
+// statistics. GameEngine::play calls it around every step, and everything
+// defaults to doing nothing.
+pub trait Observer {
+    fn on_start(&mut self, _engine: &GameEngine) {}
+    fn on_step(&mut self, _before: &GameEngine, _after: &GameEngine) {}
+    fn on_finish(&mut self, _engine: &GameEngine) {}
+}
+
+// For batch runs, where nobody is watching.
+#[derive(Default)]
+pub struct Headless {}
+
+impl Observer for Headless {}
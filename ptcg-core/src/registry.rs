@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use crate::engine::CardArchetype;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnknownCard {
+    Identifier(String),
+    Name(String),
+    SetNumber(String, usize),
+    // what the card evolves from, for a card that doesn't evolve
+    PreEvolution(String),
+}
+
+impl std::fmt::Display for UnknownCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            UnknownCard::Identifier(identifier) => write!(f, "unknown card {}", identifier),
+            UnknownCard::Name(name) => write!(f, "no card named {}", name),
+            UnknownCard::SetNumber(set, number) => write!(f, "no card number {} in set {}", number, set),
+            UnknownCard::PreEvolution(identifier) => write!(f, "{} doesn't evolve from anything", identifier),
+        }
+    }
+}
+
+impl std::error::Error for UnknownCard {}
+
+// Every card archetype a format knows about, indexed by identifier (eg:
+// "Abra (BS 43)"), by name (eg: "Abra") and by set code and number (eg: BS 43).
+pub struct CardRegistry {
+    archetypes: Vec<Box<dyn CardArchetype>>,
+    by_identifier: HashMap<String, usize>,
+    by_name: HashMap<String, Vec<usize>>,
+    by_set_number: HashMap<(String, usize), usize>,
+}
+
+impl CardRegistry {
+    pub fn new(cards: Vec<(String, Box<dyn CardArchetype>)>) -> Self {
+        let mut registry = Self {
+            archetypes: vec![],
+            by_identifier: HashMap::new(),
+            by_name: HashMap::new(),
+            by_set_number: HashMap::new(),
+        };
+
+        for (identifier, archetype) in cards {
+            // the first registration wins, like the old linear scan did
+            if registry.by_identifier.contains_key(&identifier) {
+                continue;
+            }
+
+            let index = registry.archetypes.len();
+            registry.by_name.entry(archetype.name()).or_default().push(index);
+            if let Some(set_number) = set_number(&identifier) {
+                registry.by_set_number.entry(set_number).or_insert(index);
+            }
+            registry.by_identifier.insert(identifier, index);
+            registry.archetypes.push(archetype);
+        }
+
+        registry
+    }
+
+    pub fn len(&self) -> usize {
+        self.archetypes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.archetypes.is_empty()
+    }
+
+    pub fn contains(&self, identifier: &str) -> bool {
+        self.by_identifier.contains_key(identifier)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn CardArchetype> {
+        self.archetypes.iter().map(|archetype| archetype.as_ref())
+    }
+
+    pub fn by_archetype(&self, identifier: &str) -> Result<&dyn CardArchetype, UnknownCard> {
+        self.by_identifier
+            .get(identifier)
+            .map(|&index| self.archetypes[index].as_ref())
+            .ok_or_else(|| UnknownCard::Identifier(identifier.into()))
+    }
+
+    // All the printings of a card, in registration order.
+    pub fn by_name(&self, name: &str) -> Result<Vec<&dyn CardArchetype>, UnknownCard> {
+        self.by_name
+            .get(name)
+            .map(|indices| indices.iter().map(|&index| self.archetypes[index].as_ref()).collect())
+            .ok_or_else(|| UnknownCard::Name(name.into()))
+    }
+
+    pub fn by_set_number(&self, set: &str, number: usize) -> Result<&dyn CardArchetype, UnknownCard> {
+        self.by_set_number
+            .get(&(set.into(), number))
+            .map(|&index| self.archetypes[index].as_ref())
+            .ok_or_else(|| UnknownCard::SetNumber(set.into(), number))
+    }
+
+    // Checks a whole deck list up front, reporting the first unknown card.
+    pub fn check(&self, identifiers: &[String]) -> Result<(), UnknownCard> {
+        match identifiers.iter().find(|identifier| !self.contains(identifier)) {
+            Some(identifier) => Err(UnknownCard::Identifier(identifier.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+// "Abra (BS 43)" => ("BS", 43)
+pub fn set_number(identifier: &str) -> Option<(String, usize)> {
+    let (_, rest) = identifier.rsplit_once('(')?;
+    let (set, number) = rest.strip_suffix(')')?.split_once(' ')?;
+
+    Some((set.into(), number.parse().ok()?))
+}
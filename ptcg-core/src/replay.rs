@@ -0,0 +1,589 @@
+use serde::{Deserialize, Serialize};
+use crate::state::*;
+use crate::engine::*;
+use crate::snapshot::{Snapshot, SnapshotError, SNAPSHOT_VERSION};
+use crate::observer::Observer;
+use crate::view::{ActionView, InPlayView, PlayerView, PrizeView};
+
+// Short, stable descriptions of the things a DecisionMaker gets to pick from.
+// They end up in replay files, so they shouldn't depend on Debug output of
+// whole structs.
+pub trait Label {
+    fn label(&self) -> String;
+}
+
+impl Label for Card {
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+// Pokémon put in play face down during setup stay anonymous until revealed.
+impl Label for InPlayCard {
+    fn label(&self) -> String {
+        match &self.stack[0] {
+            FaceCard::Up(card) => card.label(),
+            FaceCard::Down(_) => "Face down Pokémon".into(),
+        }
+    }
+}
+
+impl Label for InPlayView {
+    fn label(&self) -> String {
+        match &self.stack[0] {
+            Some(card) => card.label(),
+            None => "Face down Pokémon".into(),
+        }
+    }
+}
+
+// Cards in a deck being searched that the searcher doesn't get to see.
+impl Label for Option<Card> {
+    fn label(&self) -> String {
+        match self {
+            Some(card) => card.label(),
+            None => "Face down card".into(),
+        }
+    }
+}
+
+impl Label for PrizeView {
+    fn label(&self) -> String {
+        format!("Prize #{}", self.id)
+    }
+}
+
+impl Label for Type {
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Label for Player {
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Label for Stage {
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Label for Attack {
+    fn label(&self) -> String {
+        self.name().clone()
+    }
+}
+
+impl Label for Action {
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Label for ActionView {
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+// A set of cards picked together, eg: the energy discarded to retreat.
+impl Label for Vec<Card> {
+    fn label(&self) -> String {
+        self.iter().map(Label::label).collect::<Vec<_>>().join(" + ")
+    }
+}
+
+impl<A: Label, B: Label> Label for (&A, &B) {
+    fn label(&self) -> String {
+        format!("{} -> {}", self.0.label(), self.1.label())
+    }
+}
+
+impl<A: Label, B: Label> Label for (&A, &B, usize) {
+    fn label(&self) -> String {
+        format!("{} -> {} x{}", self.0.label(), self.1.label(), self.2)
+    }
+}
+
+pub fn labels<T: Label>(items: &[T]) -> String {
+    items.iter().map(Label::label).collect::<Vec<_>>().join(", ")
+}
+
+fn position<T>(items: &[T], chosen: &T) -> usize {
+    items.iter().position(|item| std::ptr::eq(item, chosen)).expect("decision maker picked something that wasn't offered")
+}
+
+fn positions<T>(items: &[T], chosen: &[&T]) -> Vec<usize> {
+    chosen.iter().map(|c| position(items, c)).collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Decision {
+    pub call: String,
+    pub player: Option<Player>,
+    pub summary: String,
+    pub choice: Vec<usize>,
+}
+
+impl Decision {
+    fn new(call: &str, player: Option<Player>, summary: String, choice: Vec<usize>) -> Self {
+        Self { call: call.into(), player, summary, choice }
+    }
+
+    fn describe(&self) -> String {
+        match self.player {
+            Some(player) => format!("{}({:?}, [{}])", self.call, player, self.summary),
+            None => format!("{}([{}])", self.call, self.summary),
+        }
+    }
+}
+
+// Wraps another DecisionMaker and writes down every question it was asked
+// along with the answer it gave.
+pub struct RecordingDM<'a> {
+    inner: &'a mut dyn DecisionMaker,
+    decisions: Vec<Decision>,
+}
+
+impl<'a> RecordingDM<'a> {
+    pub fn new(inner: &'a mut dyn DecisionMaker) -> Self {
+        Self { inner, decisions: vec![] }
+    }
+
+    pub fn into_decisions(self) -> Vec<Decision> {
+        self.decisions
+    }
+
+    fn record(&mut self, call: &str, player: Option<Player>, summary: String, choice: Vec<usize>) {
+        self.decisions.push(Decision::new(call, player, summary, choice));
+    }
+}
+
+impl Shuffler for RecordingDM<'_> {
+    fn random_card(&mut self, n: usize) -> usize {
+        let index = self.inner.shuffler().random_card(n);
+        self.record("random_card", None, n.to_string(), vec![index]);
+        index
+    }
+}
+
+impl DecisionMaker for RecordingDM<'_> {
+    fn observe(&mut self, view: &PlayerView) {
+        self.inner.observe(view);
+    }
+
+    fn shuffler(&mut self) -> &mut dyn Shuffler {
+        self
+    }
+
+    fn flip(&mut self, number_of_coins: usize) -> Flips {
+        let flips = self.inner.flip(number_of_coins);
+        let results = flips.results().iter().map(|&heads| heads as usize).collect();
+        self.record("flip", None, number_of_coins.to_string(), results);
+        flips
+    }
+
+    fn confirm_setup_mulligan(&mut self, p: Player) {
+        self.inner.confirm_setup_mulligan(p);
+        self.record("confirm_setup_mulligan", Some(p), String::new(), vec![]);
+    }
+
+    fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection {
+        let selection = self.inner.confirm_setup_active_or_mulligan(p, maybe);
+        let choice = match &selection {
+            SetupActiveSelection::Mulligan => vec![],
+            SetupActiveSelection::Place(card) => vec![maybe.iter().position(|c| c == card).unwrap()],
+        };
+        self.record("confirm_setup_active_or_mulligan", Some(p), labels(maybe), choice);
+        selection
+    }
+
+    fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card {
+        let card = self.inner.confirm_setup_active(p, yes, maybe);
+        let all = yes.iter().chain(maybe.iter()).collect::<Vec<_>>();
+        let choice = vec![all.iter().position(|&c| *c == card).unwrap()];
+        self.record("confirm_setup_active", Some(p), format!("{} | {}", labels(yes), labels(maybe)), choice);
+        card
+    }
+
+    fn confirm_mulligan_draw(&mut self, p: Player, upto: usize) -> usize {
+        let n = self.inner.confirm_mulligan_draw(p, upto);
+        self.record("confirm_mulligan_draw", Some(p), upto.to_string(), vec![n]);
+        n
+    }
+
+    fn confirm_setup_bench_selection(&mut self, p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> {
+        let selection = self.inner.confirm_setup_bench_selection(p, upto, cards);
+        let choice = selection.iter().map(|card| cards.iter().position(|c| c == card).unwrap()).collect();
+        self.record("confirm_setup_bench_selection", Some(p), labels(cards), choice);
+        selection
+    }
+
+    fn confirm_going_first(&mut self, p: Player) -> bool {
+        let first = self.inner.confirm_going_first(p);
+        self.record("confirm_going_first", Some(p), String::new(), vec![first as usize]);
+        first
+    }
+
+    fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type {
+        let chosen = self.inner.pick_type(p, types);
+        self.record("pick_type", Some(p), labels(types), vec![position(types, chosen)]);
+        chosen
+    }
+
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) {
+        let chosen = self.inner.pick_move_damage_counters(p, possibilities);
+        self.record("pick_move_damage_counters", Some(p), labels(possibilities), vec![position(possibilities, chosen)]);
+        chosen
+    }
+
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) {
+        let chosen = self.inner.pick_attach_from_hand(p, possibilities);
+        self.record("pick_attach_from_hand", Some(p), labels(possibilities), vec![position(possibilities, chosen)]);
+        chosen
+    }
+
+    fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack {
+        let chosen = self.inner.pick_attack(p, attacks);
+        self.record("pick_attack", Some(p), labels(attacks), vec![position(attacks, chosen)]);
+        chosen
+    }
+
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView {
+        let chosen = self.inner.pick_action(p, actions);
+        self.record("pick_action", Some(p), labels(actions), vec![position(actions, chosen)]);
+        chosen
+    }
+
+    fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage {
+        let chosen = self.inner.pick_stage(p, items);
+        self.record("pick_stage", Some(p), labels(items), vec![position(items, chosen)]);
+        chosen
+    }
+
+    fn pick_from_hand<'a>(&mut self, p: Player, whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> {
+        let chosen = self.inner.pick_from_hand(p, whose, how_many, hand);
+        self.record("pick_from_hand", Some(p), format!("{:?} {}: {}", whose, how_many, labels(hand)), positions(hand, &chosen));
+        chosen
+    }
+
+    fn pick_from_discard<'a>(&mut self, p: Player, whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        let chosen = self.inner.pick_from_discard(p, whose, how_many, searchable);
+        self.record("pick_from_discard", Some(p), format!("{:?} {}: {}", whose, how_many, labels(searchable)), positions(searchable, &chosen));
+        chosen
+    }
+
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> {
+        let chosen = self.inner.pick_in_play(p, how_many, searchable);
+        self.record("pick_in_play", Some(p), format!("{}: {}", how_many, labels(searchable)), positions(searchable, &chosen));
+        chosen
+    }
+
+    fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        let chosen = self.inner.pick_attached(p, how_many.clone(), searchable);
+        self.record("pick_attached", Some(p), format!("{:?}: {}", how_many, labels(searchable)), positions(searchable, &chosen));
+        chosen
+    }
+
+    fn pick_energy_discard<'a>(&mut self, p: Player, cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> {
+        let chosen = self.inner.pick_energy_discard(p, cost, options);
+        self.record("pick_energy_discard", Some(p), format!("{:?}: {}", cost, labels(options)), vec![position(options, chosen)]);
+        chosen
+    }
+
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> {
+        let chosen = self.inner.pick_from_prizes(who, whose, how_many, searchable);
+        self.record("pick_from_prizes", Some(who), format!("{:?} {}: {}", whose, how_many, labels(searchable)), positions(searchable, &chosen));
+        chosen
+    }
+
+    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> {
+        let chosen = self.inner.search_deck(p, whose, how_many, deck);
+        self.record("search_deck", Some(p), format!("{:?} {}: {}", whose, how_many, labels(deck)), positions(deck, &chosen));
+        chosen
+    }
+
+    fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> {
+        let chosen = self.inner.rearrange(p, cards);
+        self.record("rearrange", Some(p), labels(cards), positions(cards, &chosen));
+        chosen
+    }
+
+    fn accept_challenge(&mut self, p: Player) -> bool {
+        let accepted = self.inner.accept_challenge(p);
+        self.record("accept_challenge", Some(p), String::new(), vec![accepted as usize]);
+        accepted
+    }
+
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player {
+        let chosen = self.inner.pick_player(p, players);
+        self.record("pick_player", Some(p), labels(players), vec![position(players, chosen)]);
+        chosen
+    }
+}
+
+// Answers every question from a recorded log. If the engine asks something
+// different from what was recorded, the replay has diverged and we panic
+// right away instead of carrying on with a different game.
+pub struct ReplayDM {
+    decisions: Vec<Decision>,
+    next: usize,
+}
+
+impl ReplayDM {
+    pub fn new(decisions: Vec<Decision>) -> Self {
+        Self { decisions, next: 0 }
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.next >= self.decisions.len()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.decisions.len().saturating_sub(self.next)
+    }
+
+    fn replay(&mut self, call: &str, player: Option<Player>, summary: String, options: usize) -> Vec<usize> {
+        let asked = Decision::new(call, player, summary, vec![]);
+
+        let recorded = match self.decisions.get(self.next) {
+            Some(recorded) => recorded,
+            None => panic!("replay diverged at decision {}: log is exhausted, but the engine asked {}", self.next, asked.describe()),
+        };
+
+        if recorded.call != asked.call || recorded.player != asked.player || recorded.summary != asked.summary {
+            panic!(
+                "replay diverged at decision {}:\n  recorded: {}\n     asked: {}",
+                self.next, recorded.describe(), asked.describe(),
+            );
+        }
+
+        if let Some(index) = recorded.choice.iter().find(|&&i| i >= options) {
+            panic!("replay diverged at decision {}: recorded choice {} but only {} options were offered", self.next, index, options);
+        }
+
+        self.next += 1;
+        recorded.choice.clone()
+    }
+
+    fn replay_one(&mut self, call: &str, player: Option<Player>, summary: String, options: usize) -> usize {
+        let choice = self.replay(call, player, summary, options);
+        if choice.len() != 1 {
+            panic!("replay diverged at decision {}: expected a single choice for {}, found {:?}", self.next - 1, call, choice);
+        }
+
+        choice[0]
+    }
+}
+
+impl Shuffler for ReplayDM {
+    fn random_card(&mut self, n: usize) -> usize {
+        self.replay_one("random_card", None, n.to_string(), n)
+    }
+}
+
+impl DecisionMaker for ReplayDM {
+    fn shuffler(&mut self) -> &mut dyn Shuffler {
+        self
+    }
+
+    fn flip(&mut self, number_of_coins: usize) -> Flips {
+        let results = self.replay("flip", None, number_of_coins.to_string(), 2);
+        Flips::from_results(results.into_iter().map(|r| r == 1).collect())
+    }
+
+    fn confirm_setup_mulligan(&mut self, p: Player) {
+        self.replay("confirm_setup_mulligan", Some(p), String::new(), 0);
+    }
+
+    fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection {
+        let choice = self.replay("confirm_setup_active_or_mulligan", Some(p), labels(maybe), maybe.len());
+        match choice.first() {
+            None => SetupActiveSelection::Mulligan,
+            Some(&i) => SetupActiveSelection::Place(maybe[i].clone()),
+        }
+    }
+
+    fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card {
+        let all = yes.iter().chain(maybe.iter()).collect::<Vec<_>>();
+        let i = self.replay_one("confirm_setup_active", Some(p), format!("{} | {}", labels(yes), labels(maybe)), all.len());
+        all[i].clone()
+    }
+
+    fn confirm_mulligan_draw(&mut self, p: Player, upto: usize) -> usize {
+        self.replay_one("confirm_mulligan_draw", Some(p), upto.to_string(), upto + 1)
+    }
+
+    fn confirm_setup_bench_selection(&mut self, p: Player, _upto: usize, cards: &Vec<Card>) -> Vec<Card> {
+        let choice = self.replay("confirm_setup_bench_selection", Some(p), labels(cards), cards.len());
+        choice.into_iter().map(|i| cards[i].clone()).collect()
+    }
+
+    fn confirm_going_first(&mut self, p: Player) -> bool {
+        self.replay_one("confirm_going_first", Some(p), String::new(), 2) == 1
+    }
+
+    fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type {
+        &types[self.replay_one("pick_type", Some(p), labels(types), types.len())]
+    }
+
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) {
+        &possibilities[self.replay_one("pick_move_damage_counters", Some(p), labels(possibilities), possibilities.len())]
+    }
+
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) {
+        &possibilities[self.replay_one("pick_attach_from_hand", Some(p), labels(possibilities), possibilities.len())]
+    }
+
+    fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack {
+        &attacks[self.replay_one("pick_attack", Some(p), labels(attacks), attacks.len())]
+    }
+
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView {
+        &actions[self.replay_one("pick_action", Some(p), labels(actions), actions.len())]
+    }
+
+    fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage {
+        &items[self.replay_one("pick_stage", Some(p), labels(items), items.len())]
+    }
+
+    fn pick_from_hand<'a>(&mut self, p: Player, whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.replay("pick_from_hand", Some(p), format!("{:?} {}: {}", whose, how_many, labels(hand)), hand.len());
+        choice.into_iter().map(|i| &hand[i]).collect()
+    }
+
+    fn pick_from_discard<'a>(&mut self, p: Player, whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.replay("pick_from_discard", Some(p), format!("{:?} {}: {}", whose, how_many, labels(searchable)), searchable.len());
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> {
+        let choice = self.replay("pick_in_play", Some(p), format!("{}: {}", how_many, labels(searchable)), searchable.len());
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.replay("pick_attached", Some(p), format!("{:?}: {}", how_many, labels(searchable)), searchable.len());
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_energy_discard<'a>(&mut self, p: Player, cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> {
+        &options[self.replay_one("pick_energy_discard", Some(p), format!("{:?}: {}", cost, labels(options)), options.len())]
+    }
+
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> {
+        let choice = self.replay("pick_from_prizes", Some(who), format!("{:?} {}: {}", whose, how_many, labels(searchable)), searchable.len());
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> {
+        let choice = self.replay("search_deck", Some(p), format!("{:?} {}: {}", whose, how_many, labels(deck)), deck.len());
+        choice.into_iter().map(|i| &deck[i]).collect()
+    }
+
+    fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.replay("rearrange", Some(p), labels(cards), cards.len());
+        choice.into_iter().map(|i| &cards[i]).collect()
+    }
+
+    fn accept_challenge(&mut self, p: Player) -> bool {
+        self.replay_one("accept_challenge", Some(p), String::new(), 2) == 1
+    }
+
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player {
+        &players[self.replay_one("pick_player", Some(p), labels(players), players.len())]
+    }
+}
+
+// A self-contained replay file: where the game started and every decision
+// taken from there.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Replay {
+    pub start: Snapshot,
+    pub decisions: Vec<Decision>,
+    // how many steps the recorded game got through; the last few steps of a
+    // game (decking out, say) often don't need any decisions
+    pub steps: usize,
+}
+
+// Counts the steps of a recording, passing everything on to the real observer.
+struct StepCounter<'a> {
+    observer: &'a mut dyn Observer,
+    steps: usize,
+}
+
+impl Observer for StepCounter<'_> {
+    fn on_start(&mut self, engine: &GameEngine) {
+        self.observer.on_start(engine);
+    }
+
+    fn on_step(&mut self, before: &GameEngine, after: &GameEngine) {
+        self.steps += 1;
+        self.observer.on_step(before, after);
+    }
+
+    fn on_finish(&mut self, engine: &GameEngine) {
+        self.observer.on_finish(engine);
+    }
+}
+
+impl Replay {
+    // Plays `engine` to the end with `dm`, recording every decision. The log
+    // is returned even if the engine panics along the way, since that's
+    // usually when we want it the most.
+    pub fn record(engine: &GameEngine, dm: &mut dyn DecisionMaker, observer: &mut dyn Observer) -> (std::thread::Result<GameEngine>, Self) {
+        let start = engine.snapshot();
+        let mut recorder = RecordingDM::new(dm);
+        let mut counter = StepCounter { observer, steps: 0 };
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| engine.play(&mut recorder, &mut counter)));
+
+        (result, Self { start, decisions: recorder.into_decisions(), steps: counter.steps })
+    }
+
+    // Restores the starting point and takes as many steps as the recorded game
+    // did, returning the engine at that point. For a recording that panicked
+    // that's right before the step that brought it down. A game that finishes
+    // has to use up every recorded decision, or it didn't go the way it was
+    // recorded.
+    pub fn run(&self) -> Result<GameEngine, SnapshotError> {
+        let mut engine = self.start.restore()?;
+        let mut dm = ReplayDM::new(self.decisions.clone());
+
+        for _ in 0..self.steps {
+            if engine.is_finished() {
+                break;
+            }
+            engine = engine.step(&mut dm);
+        }
+
+        if engine.is_finished() && !dm.is_exhausted() {
+            return Err(SnapshotError::UnusedDecisions(dm.remaining()));
+        }
+
+        Ok(engine)
+    }
+
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        let replay = serde_json::from_str::<Self>(json)?;
+        if replay.start.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(replay.start.version));
+        }
+
+        Ok(replay)
+    }
+
+    pub fn save(&self, path: &std::path::Path) -> Result<(), SnapshotError> {
+        Ok(std::fs::write(path, self.to_json()?)?)
+    }
+
+    pub fn load(path: &std::path::Path) -> Result<Self, SnapshotError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
@@ -0,0 +1,476 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use crate::state::*;
+use crate::engine::*;
+use crate::observer::Observer;
+use crate::replay::Label;
+use crate::view::{ActionView, InPlayView, PlayerView, PrizeView};
+
+// The protocol is one JSON object per line. A client opens with Hello to
+// take a seat and is welcomed with a secret, which it has to repeat in its
+// Hello to take the seat back after losing it. It's then sent its View of the
+// game before every step and a Prompt whenever its player has a decision to
+// make. Prompts are answered with the indices of the chosen options, between
+// min and max of them, in order for rearrange.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Welcome { player: Player, secret: String },
+    View { view: Box<PlayerView> },
+    Prompt { id: u64, player: Player, call: String, options: Vec<String>, min: usize, max: usize },
+    Error { message: String },
+    Finished { winner: Option<Player> },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Hello {
+        player: Player,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        secret: Option<String>,
+    },
+    Answer { id: u64, choice: Vec<usize> },
+}
+
+struct Seat {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Seat {
+    fn new(stream: TcpStream, timeout: Duration) -> std::io::Result<Self> {
+        stream.set_nonblocking(false)?;
+        let seat = Self { reader: BufReader::new(stream.try_clone()?), writer: stream };
+        seat.set_timeout(timeout)?;
+
+        Ok(seat)
+    }
+
+    fn set_timeout(&self, timeout: Duration) -> std::io::Result<()> {
+        self.writer.set_read_timeout(Some(timeout))
+    }
+
+    fn send(&mut self, message: &ServerMessage) -> std::io::Result<()> {
+        writeln!(self.writer, "{}", serde_json::to_string(message)?)
+    }
+
+    // Errors are I/O trouble (including timeouts), garbage comes back as Ok(None).
+    fn receive(&mut self) -> std::io::Result<Option<ClientMessage>> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(serde_json::from_str(&line).ok())
+    }
+}
+
+fn seat_index(player: Player) -> usize {
+    match player {
+        Player::One => 0,
+        Player::Two => 1,
+    }
+}
+
+fn labels<T: Label>(items: &[T]) -> Vec<String> {
+    items.iter().map(Label::label).collect()
+}
+
+// A DecisionMaker for two players sitting at the other end of TCP
+// connections. Coins and shuffles stay on the server. A player that doesn't
+// answer within the answer timeout, or whose connection drops, is unseated and
+// has until the reconnect timeout to say Hello again, at which point they get
+// their latest view and the pending prompt once more. Past that they forfeit:
+// every prompt left in the step is answered with the first options allowed,
+// and play() hands the game to their opponent instead of using that step.
+// Connections that don't say Hello within the handshake timeout are dropped.
+pub struct RemoteDM {
+    listener: TcpListener,
+    seats: [Option<Seat>; 2],
+    secrets: [Option<String>; 2],
+    views: [Option<PlayerView>; 2],
+    handshake_timeout: Duration,
+    answer_timeout: Duration,
+    reconnect_timeout: Duration,
+    rng: StdRng,
+    next_id: u64,
+    forfeited: Option<Player>,
+}
+
+impl RemoteDM {
+    pub fn bind<A: ToSocketAddrs>(address: A) -> std::io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+
+        Ok(Self {
+            listener,
+            seats: [None, None],
+            secrets: [None, None],
+            views: [None, None],
+            handshake_timeout: Duration::from_secs(5),
+            answer_timeout: Duration::from_secs(60),
+            reconnect_timeout: Duration::from_secs(120),
+            rng: StdRng::from_entropy(),
+            next_id: 0,
+            forfeited: None,
+        })
+    }
+
+    pub fn timeouts(mut self, answer: Duration, reconnect: Duration) -> Self {
+        self.answer_timeout = answer;
+        self.reconnect_timeout = reconnect;
+        self
+    }
+
+    pub fn handshake_timeout(mut self, timeout: Duration) -> Self {
+        self.handshake_timeout = timeout;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<std::net::SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    pub fn is_seated(&self, player: Player) -> bool {
+        self.seats[seat_index(player)].is_some()
+    }
+
+    // Who didn't come back in time, if anyone.
+    pub fn forfeited(&self) -> Option<Player> {
+        self.forfeited
+    }
+
+    pub fn wait_for_players(&mut self) -> std::io::Result<()> {
+        for player in [Player::One, Player::Two] {
+            if !self.wait_for(player) {
+                let message = format!("Player {:?} didn't connect within {:?}", player, self.reconnect_timeout);
+                return Err(std::io::Error::new(std::io::ErrorKind::TimedOut, message));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Like GameEngine::play, but a player who forfeits loses then and there.
+    // Both players are told how it ended.
+    pub fn play(&mut self, engine: &GameEngine, observer: &mut dyn Observer) -> GameEngine {
+        let mut engine = engine.clone();
+        observer.on_start(&engine);
+
+        while !engine.is_finished() {
+            let next = engine.step(self);
+            let next = match self.forfeited {
+                Some(player) => engine.forfeit(player),
+                None => next,
+            };
+            observer.on_step(&engine, &next);
+            engine = next;
+        }
+
+        observer.on_finish(&engine);
+        self.finish(&engine);
+        engine
+    }
+
+    // Tells both players how it went, with one last look at the board.
+    pub fn finish(&mut self, engine: &GameEngine) {
+        let winner = match engine.state.stage {
+            GameStage::Winner(player) => Some(player),
+            _ => None,
+        };
+
+        for player in [Player::One, Player::Two] {
+            self.observe(&engine.view(player));
+            self.send(player, &ServerMessage::Finished { winner });
+        }
+    }
+
+    // Whether the player is seated before the reconnect timeout runs out.
+    fn wait_for(&mut self, player: Player) -> bool {
+        let deadline = Instant::now() + self.reconnect_timeout;
+
+        while !self.is_seated(player) {
+            if Instant::now() > deadline {
+                return false;
+            }
+
+            match self.listener.accept() {
+                Ok((stream, _)) => self.greet(stream),
+                Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => std::thread::sleep(Duration::from_millis(10)),
+                Err(_) => {},
+            }
+        }
+
+        true
+    }
+
+    // Seats whoever just connected, replacing a previous connection for the
+    // same player if they know the secret that player was given.
+    fn greet(&mut self, stream: TcpStream) {
+        let mut seat = match Seat::new(stream, self.handshake_timeout) {
+            Ok(seat) => seat,
+            Err(_) => return,
+        };
+
+        let (player, secret) = match seat.receive() {
+            Ok(Some(ClientMessage::Hello { player, secret })) => (player, secret),
+            _ => {
+                let _ = seat.send(&ServerMessage::Error { message: "expected hello".into() });
+                return;
+            },
+        };
+
+        let index = seat_index(player);
+        let secret = match (&self.secrets[index], secret) {
+            (None, _) => format!("{:032x}", StdRng::from_entropy().gen::<u128>()),
+            (Some(expected), Some(secret)) if *expected == secret => secret,
+            _ => {
+                let _ = seat.send(&ServerMessage::Error { message: format!("Player {:?} is taken", player) });
+                return;
+            },
+        };
+
+        let view = self.views[index].clone();
+        let welcomed = seat.set_timeout(self.answer_timeout)
+            .and_then(|_| seat.send(&ServerMessage::Welcome { player, secret: secret.clone() }))
+            .and_then(|_| match &view {
+                Some(view) => seat.send(&ServerMessage::View { view: Box::new(view.clone()) }),
+                None => Ok(()),
+            });
+
+        if welcomed.is_ok() {
+            self.seats[index] = Some(seat);
+            self.secrets[index] = Some(secret);
+        }
+    }
+
+    fn send(&mut self, player: Player, message: &ServerMessage) {
+        let index = seat_index(player);
+        if let Some(seat) = self.seats[index].as_mut() {
+            if seat.send(message).is_err() {
+                self.seats[index] = None;
+            }
+        }
+    }
+
+    fn ask(&mut self, player: Player, call: &str, options: Vec<String>, how_many: std::ops::RangeInclusive<usize>) -> Vec<usize> {
+        self.next_id += 1;
+        let id = self.next_id;
+        let count = options.len();
+        let prompt = ServerMessage::Prompt { id, player, call: call.into(), options, min: *how_many.start(), max: *how_many.end() };
+
+        loop {
+            if self.forfeited.is_some() {
+                return (0..*how_many.start()).collect();
+            }
+            if !self.wait_for(player) {
+                self.forfeited = Some(player);
+                continue;
+            }
+
+            let index = seat_index(player);
+            let seat = self.seats[index].as_mut().unwrap();
+
+            let error = match seat.send(&prompt).and_then(|_| seat.receive()) {
+                Ok(Some(ClientMessage::Answer { id: answered, choice })) if answered == id => {
+                    let mut distinct = choice.clone();
+                    distinct.sort();
+                    distinct.dedup();
+
+                    if how_many.contains(&choice.len()) && distinct.len() == choice.len() && choice.iter().all(|&i| i < count) {
+                        return choice;
+                    }
+                    format!("invalid choice {:?} for prompt {}", choice, id)
+                },
+                Ok(_) => format!("expected an answer to prompt {}", id),
+                Err(_) => {
+                    self.seats[index] = None;
+                    continue;
+                },
+            };
+
+            self.send(player, &ServerMessage::Error { message: error });
+        }
+    }
+
+    fn ask_one(&mut self, player: Player, call: &str, options: Vec<String>) -> usize {
+        self.ask(player, call, options, 1..=1)[0]
+    }
+
+    // "Pick 2" out of a single card means picking that one.
+    fn ask_exactly(&mut self, player: Player, call: &str, options: Vec<String>, how_many: usize) -> Vec<usize> {
+        let how_many = how_many.min(options.len());
+        self.ask(player, call, options, how_many..=how_many)
+    }
+}
+
+impl Shuffler for RemoteDM {
+    fn random_card(&mut self, n: usize) -> usize {
+        self.rng.gen_range(0..n)
+    }
+}
+
+impl DecisionMaker for RemoteDM {
+    fn observe(&mut self, view: &PlayerView) {
+        self.views[seat_index(view.player)] = Some(view.clone());
+        self.send(view.player, &ServerMessage::View { view: Box::new(view.clone()) });
+    }
+
+    fn shuffler(&mut self) -> &mut dyn Shuffler {
+        self
+    }
+
+    fn flip(&mut self, number_of_coins: usize) -> Flips {
+        Flips::from_results((0..number_of_coins).map(|_| self.rng.gen()).collect())
+    }
+
+    fn confirm_setup_mulligan(&mut self, _p: Player) {}
+
+    fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection {
+        match self.ask(p, "confirm_setup_active_or_mulligan", labels(maybe), 0..=1).first() {
+            None => SetupActiveSelection::Mulligan,
+            Some(&i) => SetupActiveSelection::Place(maybe[i].clone()),
+        }
+    }
+
+    fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card {
+        let all = yes.iter().chain(maybe.iter()).cloned().collect::<Vec<_>>();
+        all[self.ask_one(p, "confirm_setup_active", labels(&all))].clone()
+    }
+
+    fn confirm_mulligan_draw(&mut self, p: Player, upto: usize) -> usize {
+        self.ask_one(p, "confirm_mulligan_draw", (0..=upto).map(|n| n.to_string()).collect())
+    }
+
+    fn confirm_setup_bench_selection(&mut self, p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> {
+        let choice = self.ask(p, "confirm_setup_bench_selection", labels(cards), 0..=cards.len().min(upto));
+        choice.into_iter().map(|i| cards[i].clone()).collect()
+    }
+
+    fn confirm_going_first(&mut self, p: Player) -> bool {
+        self.ask_one(p, "confirm_going_first", vec!["first".into(), "second".into()]) == 0
+    }
+
+    fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type {
+        &types[self.ask_one(p, "pick_type", labels(types))]
+    }
+
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) {
+        &possibilities[self.ask_one(p, "pick_move_damage_counters", labels(possibilities))]
+    }
+
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) {
+        &possibilities[self.ask_one(p, "pick_attach_from_hand", labels(possibilities))]
+    }
+
+    fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack {
+        &attacks[self.ask_one(p, "pick_attack", labels(attacks))]
+    }
+
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView {
+        &actions[self.ask_one(p, "pick_action", labels(actions))]
+    }
+
+    fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage {
+        &items[self.ask_one(p, "pick_stage", labels(items))]
+    }
+
+    fn pick_from_hand<'a>(&mut self, p: Player, _whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.ask_exactly(p, "pick_from_hand", labels(hand), how_many);
+        choice.into_iter().map(|i| &hand[i]).collect()
+    }
+
+    fn pick_from_discard<'a>(&mut self, p: Player, _whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.ask_exactly(p, "pick_from_discard", labels(searchable), how_many);
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> {
+        let choice = self.ask_exactly(p, "pick_in_play", labels(searchable), how_many);
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.ask(p, "pick_attached", labels(searchable), how_many);
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_energy_discard<'a>(&mut self, p: Player, _cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> {
+        &options[self.ask_one(p, "pick_energy_discard", labels(options))]
+    }
+
+    fn pick_from_prizes<'a>(&mut self, who: Player, _whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> {
+        let choice = self.ask_exactly(who, "pick_from_prizes", labels(searchable), how_many);
+        choice.into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn search_deck<'a>(&mut self, p: Player, _whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> {
+        let choice = self.ask_exactly(p, "search_deck", labels(deck), how_many);
+        choice.into_iter().map(|i| &deck[i]).collect()
+    }
+
+    fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> {
+        let choice = self.ask_exactly(p, "rearrange", labels(cards), cards.len());
+        choice.into_iter().map(|i| &cards[i]).collect()
+    }
+
+    fn accept_challenge(&mut self, p: Player) -> bool {
+        self.ask_one(p, "accept_challenge", vec!["accept".into(), "decline".into()]) == 0
+    }
+
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player {
+        &players[self.ask_one(p, "pick_player", labels(players))]
+    }
+}
+
+// The other end of a RemoteDM, for bots and tests.
+pub struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Client {
+    pub fn connect<A: ToSocketAddrs>(address: A, player: Player) -> std::io::Result<Self> {
+        Self::hello(address, player, None)
+    }
+
+    // Takes back a seat with the secret it was welcomed with.
+    pub fn reconnect<A: ToSocketAddrs>(address: A, player: Player, secret: &str) -> std::io::Result<Self> {
+        Self::hello(address, player, Some(secret.into()))
+    }
+
+    fn hello<A: ToSocketAddrs>(address: A, player: Player, secret: Option<String>) -> std::io::Result<Self> {
+        let stream = TcpStream::connect(address)?;
+        let mut client = Self { reader: BufReader::new(stream.try_clone()?), writer: stream };
+
+        client.send(&ClientMessage::Hello { player, secret })?;
+        Ok(client)
+    }
+
+    pub fn send(&mut self, message: &ClientMessage) -> std::io::Result<()> {
+        writeln!(self.writer, "{}", serde_json::to_string(message)?)
+    }
+
+    pub fn answer(&mut self, id: u64, choice: Vec<usize>) -> std::io::Result<()> {
+        self.send(&ClientMessage::Answer { id, choice })
+    }
+
+    pub fn receive(&mut self) -> std::io::Result<ServerMessage> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+
+        Ok(serde_json::from_str(&line)?)
+    }
+}
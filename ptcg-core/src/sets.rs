@@ -1,3 +1,5 @@
 pub mod base;
 pub mod jungle;
 pub mod fossil;
+pub mod team_rocket;
+pub mod base_set_2;
@@ -9,6 +9,10 @@ impl CardArchetype for DoubleColorlessEnergy {
     card_name!("Double Colorless Energy");
     not_a_pokemon!();
 
+    fn is_energy(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
     fn attachable_as_energy_for_turn(&self, _card: &Card, _engine: &GameEngine) -> bool {
         true
     }
@@ -43,6 +47,10 @@ impl CardArchetype for BasicEnergy {
         self.name.clone()
     }
 
+    fn is_energy(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
     fn attachable_as_energy_for_turn(&self, _card: &Card, _engine: &GameEngine) -> bool {
         true
     }
@@ -55,7 +55,7 @@ impl TrainerCardArchetype for DevolutionSpray72 {
     }
     fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
         let choices = self.evolutions_in_play(player, engine);
-        let chosen = dm.pick_in_play(player, 1, &choices);
+        let chosen = dm.pick_in_play_cards(player, 1, &choices);
 
         let mut stages = self.stages(&chosen[0], engine);
         stages.remove(0);
@@ -161,9 +161,9 @@ impl TrainerCardArchetype for PokemonBreeder76 {
         let card = dm.pick_from_hand(player, player, 1, &stage2s)[0];
 
         let targets = self.targets(engine, card);
-        let chosen = dm.pick_in_play(player, 1, &targets)[0];
+        let chosen = dm.pick_in_play_cards(player, 1, &targets)[0];
 
-        engine.evolve_into(chosen, card)
+        engine.evolve_from_hand(chosen, card, dm)
     }
 }
 impl PokemonBreeder76 {
@@ -177,7 +177,10 @@ impl PokemonBreeder76 {
     }
 
     pub fn targets(&self, engine: &GameEngine, card: &Card) -> Vec<InPlayCard> {
-        let basic = engine.basic_for_stage2(card);
+        let basic = match engine.basic_for_stage2(card) {
+            Ok(basic) => basic,
+            Err(_) => return vec![],
+        };
 
         engine
             .in_play(engine.player())
@@ -216,9 +219,9 @@ impl TrainerCardArchetype for ScoopUp78 {
     }
 
     fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
-        let choices = engine.state.side(player).all_in_play().into_iter().cloned().collect();
+        let choices = engine.state.side(player).all_in_play().into_iter().cloned().collect::<Vec<_>>();
 
-        let chosen = dm.pick_in_play(player, 1, &choices);
+        let chosen = dm.pick_in_play_cards(player, 1, &choices);
 
         engine.scoop_up(chosen[0], |e, c| e.stage(c) == Some(Stage::Basic))
     }
@@ -238,13 +241,13 @@ impl TrainerCardArchetype for SuperEnergyRemoval79 {
 
     fn execute(&self, _player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
         let own_targets = self.energy_removal_targets(engine.player(), engine);
-        let own_target = dm.pick_in_play(engine.player(), 1, &own_targets)[0];
+        let own_target = dm.pick_in_play_cards(engine.player(), 1, &own_targets)[0];
 
         let own_cards = own_target.attached.iter().map(|c| c.card()).cloned().collect();
         let own_discarded = dm.pick_attached(engine.player(), 1..=1, &own_cards);
 
         let their_targets = self.energy_removal_targets(engine.opponent(), engine);
-        let their_target = dm.pick_in_play(engine.player(), 1, &their_targets)[0];
+        let their_target = dm.pick_in_play_cards(engine.player(), 1, &their_targets)[0];
 
         let their_cards = their_target.attached.iter().map(|c| c.card()).cloned().collect();
         let their_discarded = dm.pick_attached(engine.player(), 1..=2, &their_cards);
@@ -270,8 +273,8 @@ impl TrainerCardArchetype for Defender80 {
         true
     }
     fn execute(&self, player: Player, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
-        let targets = engine.state.side(player).all_in_play().into_iter().cloned().collect();
-        let target = dm.pick_in_play(player, 1, &targets)[0];
+        let targets = engine.state.side(player).all_in_play().into_iter().cloned().collect::<Vec<_>>();
+        let target = dm.pick_in_play_cards(player, 1, &targets)[0];
 
         engine
             .attach_from_hand(card, target)
@@ -327,7 +330,7 @@ impl TrainerCardArchetype for FullHeal82 {
     }
     fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
         let targets = self.affected_in_play(player, engine);
-        let target = dm.pick_in_play(player, 1, &targets)[0];
+        let target = dm.pick_in_play_cards(player, 1, &targets)[0];
 
         engine.remove_special_conditions(&target)
     }
@@ -366,8 +369,8 @@ impl TrainerCardArchetype for PlusPower84 {
         true
     }
     fn execute(&self, player: Player, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
-        let targets = engine.state.side(player).active.iter().cloned().collect();
-        let target = dm.pick_in_play(player, 1, &targets)[0];
+        let targets = engine.state.side(player).active.iter().cloned().collect::<Vec<_>>();
+        let target = dm.pick_in_play_cards(player, 1, &targets)[0];
 
         engine
             .attach_from_hand(card, target)
@@ -511,7 +514,7 @@ impl TrainerCardArchetype for SuperPotion90 {
     }
     fn execute(&self, _player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
         let own_targets = self.targets(engine);
-        let own_target = dm.pick_in_play(engine.player(), 1, &own_targets)[0];
+        let own_target = dm.pick_in_play_cards(engine.player(), 1, &own_targets)[0];
 
         let own_cards = own_target.attached.iter().map(|c| c.card()).cloned().collect();
         let own_discarded = dm.pick_attached(engine.player(), 1..=1, &own_cards);
@@ -562,7 +565,7 @@ impl TrainerCardArchetype for EnergyRemoval91 {
     }
     fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
         let targets = self.energy_removal_targets(engine);
-        let target = dm.pick_in_play(player, 1, &targets)[0];
+        let target = dm.pick_in_play_cards(player, 1, &targets)[0];
 
         let cards = target.attached.iter().map(|c| c.card()).cloned().collect();
         let discarded = dm.pick_attached(player, 1..=1, &cards);
@@ -605,7 +608,7 @@ impl TrainerCardArchetype for Potion93 {
     }
     fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
         let targets = engine.healable_targets(player);
-        let target = dm.pick_in_play(player, 1, &targets)[0];
+        let target = dm.pick_in_play_cards(player, 1, &targets)[0];
 
         engine.heal(target, 20)
     }
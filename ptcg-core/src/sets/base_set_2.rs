@@ -0,0 +1,169 @@
+use crate::engine::*;
+use crate::state::Type;
+use crate::carddb::{Pokemon, Reprint, Trainer};
+use crate::sets::{base, jungle};
+
+// Every card in Base Set 2 is a reprint of a Base Set or Jungle card. Clefairy
+// Doll, Devolution Spray, Pokémon Flute and Revive didn't make the cut.
+macro_rules! mk_reprint {
+    ($vec: expr, $($identifier: expr => $original: path),+ $(,)?) => {
+        $({
+                let boxed = Reprint::create($identifier, Pokemon::create::<$original>());
+                $vec.push((boxed.identifier(), boxed));
+        })+
+    }
+}
+
+macro_rules! mk_trainer_reprint {
+    ($vec: expr, $($identifier: expr => $original: path),+ $(,)?) => {
+        $({
+                let boxed = Reprint::create($identifier, Trainer::create::<$original>());
+                $vec.push((boxed.identifier(), boxed));
+        })+
+    }
+}
+
+pub fn build() -> Vec<(String, Box<dyn CardArchetype>)> {
+    let mut entries = vec![];
+
+    mk_reprint!(
+        entries,
+        "Alakazam (B2 1)" => base::Alakazam1,
+        "Blastoise (B2 2)" => base::Blastoise2,
+        "Chansey (B2 3)" => base::Chansey3,
+        "Charizard (B2 4)" => base::Charizard4,
+        "Clefable (B2 5)" => jungle::Clefable1,
+        "Clefairy (B2 6)" => base::Clefairy5,
+        "Gyarados (B2 7)" => base::Gyarados6,
+        "Hitmonchan (B2 8)" => base::Hitmonchan7,
+        "Magneton (B2 9)" => base::Magneton9,
+        "Mewtwo (B2 10)" => base::Mewtwo10,
+        "Nidoking (B2 11)" => base::Nidoking11,
+        "Nidoqueen (B2 12)" => jungle::Nidoqueen7,
+        "Ninetales (B2 13)" => base::Ninetales12,
+        "Pidgeot (B2 14)" => jungle::Pidgeot8,
+        "Poliwrath (B2 15)" => base::Poliwrath13,
+        "Raichu (B2 16)" => base::Raichu14,
+        "Scyther (B2 17)" => jungle::Scyther10,
+        "Venusaur (B2 18)" => base::Venusaur15,
+        "Wigglytuff (B2 19)" => jungle::Wigglytuff16,
+        "Zapdos (B2 20)" => base::Zapdos16,
+        "Beedrill (B2 21)" => base::Beedrill17,
+        "Dragonair (B2 22)" => base::Dragonair18,
+        "Dugtrio (B2 23)" => base::Dugtrio19,
+        "Electabuzz (B2 24)" => base::Electabuzz20,
+        "Electrode (B2 25)" => base::Electrode21,
+        "Kangaskhan (B2 26)" => jungle::Kangaskhan5,
+        "Mr. Mime (B2 27)" => jungle::MrMime6,
+        "Pidgeotto (B2 28)" => base::Pidgeotto22,
+        "Pinsir (B2 29)" => jungle::Pinsir9,
+        "Snorlax (B2 30)" => jungle::Snorlax11,
+        "Venomoth (B2 31)" => jungle::Venomoth13,
+        "Victreebel (B2 32)" => jungle::Victreebel14,
+        "Arcanine (B2 33)" => base::Arcanine23,
+        "Butterfree (B2 34)" => jungle::Butterfree33,
+        "Charmeleon (B2 35)" => base::Charmeleon24,
+        "Dewgong (B2 36)" => base::Dewgong25,
+        "Dodrio (B2 37)" => jungle::Dodrio34,
+        "Dratini (B2 38)" => base::Dratini26,
+        "Exeggutor (B2 39)" => jungle::Exeggutor35,
+        "Farfetch'd (B2 40)" => base::FarfetchD27,
+        "Fearow (B2 41)" => jungle::Fearow36,
+        "Growlithe (B2 42)" => base::Growlithe28,
+        "Haunter (B2 43)" => base::Haunter29,
+        "Ivysaur (B2 44)" => base::Ivysaur30,
+        "Jynx (B2 45)" => base::Jynx31,
+        "Kadabra (B2 46)" => base::Kadabra32,
+        "Kakuna (B2 47)" => base::Kakuna33,
+        "Lickitung (B2 48)" => jungle::Lickitung38,
+        "Machoke (B2 49)" => base::Machoke34,
+        "Magikarp (B2 50)" => base::Magikarp35,
+        "Magmar (B2 51)" => base::Magmar36,
+        "Marowak (B2 52)" => jungle::Marowak39,
+        "Nidorina (B2 53)" => jungle::Nidorina40,
+        "Nidorino (B2 54)" => base::Nidorino37,
+        "Parasect (B2 55)" => jungle::Parasect41,
+        "Persian (B2 56)" => jungle::Persian42,
+        "Poliwhirl (B2 57)" => base::Poliwhirl38,
+        "Raticate (B2 58)" => base::Raticate40,
+        "Rhydon (B2 59)" => jungle::Rhydon45,
+        "Seaking (B2 60)" => jungle::Seaking46,
+        "Seel (B2 61)" => base::Seel41,
+        "Tauros (B2 62)" => jungle::Tauros47,
+        "Wartortle (B2 63)" => base::Wartortle42,
+        "Weepinbell (B2 64)" => jungle::Weepinbell48,
+        "Abra (B2 65)" => base::Abra43,
+        "Bellsprout (B2 66)" => jungle::Bellsprout49,
+        "Bulbasaur (B2 67)" => base::Bulbasaur44,
+        "Caterpie (B2 68)" => base::Caterpie45,
+        "Charmander (B2 69)" => base::Charmander46,
+        "Cubone (B2 70)" => jungle::Cubone50,
+        "Diglett (B2 71)" => base::Diglett47,
+        "Doduo (B2 72)" => base::Doduo48,
+        "Drowzee (B2 73)" => base::Drowzee49,
+        "Exeggcute (B2 74)" => jungle::Exeggcute52,
+        "Gastly (B2 75)" => base::Gastly50,
+        "Goldeen (B2 76)" => jungle::Goldeen53,
+        "Jigglypuff (B2 77)" => jungle::Jigglypuff54,
+        "Machop (B2 78)" => base::Machop52,
+        "Magnemite (B2 79)" => base::Magnemite53,
+        "Meowth (B2 80)" => jungle::Meowth56,
+        "Metapod (B2 81)" => base::Metapod54,
+        "Nidoran ♀ (B2 82)" => jungle::NidoranF57,
+        "Nidoran ♂ (B2 83)" => base::NidoranM55,
+        "Onix (B2 84)" => base::Onix56,
+        "Paras (B2 85)" => jungle::Paras59,
+        "Pidgey (B2 86)" => base::Pidgey57,
+        "Pikachu (B2 87)" => base::Pikachu58,
+        "Poliwag (B2 88)" => base::Poliwag59,
+        "Rattata (B2 89)" => base::Rattata61,
+        "Rhyhorn (B2 90)" => jungle::Rhyhorn61,
+        "Sandshrew (B2 91)" => base::Sandshrew62,
+        "Spearow (B2 92)" => jungle::Spearow62,
+        "Squirtle (B2 93)" => base::Squirtle63,
+        "Starmie (B2 94)" => base::Starmie64,
+        "Staryu (B2 95)" => base::Staryu65,
+        "Tangela (B2 96)" => base::Tangela66,
+        "Venonat (B2 97)" => jungle::Venonat63,
+        "Voltorb (B2 98)" => base::Voltorb67,
+        "Vulpix (B2 99)" => base::Vulpix68,
+        "Weedle (B2 100)" => base::Weedle69,
+    );
+
+    mk_trainer_reprint!(
+        entries,
+        "Computer Search (B2 101)" => base::ComputerSearch71,
+        "Impostor Professor Oak (B2 102)" => base::ImpostorProfessorOak73,
+        "Item Finder (B2 103)" => base::ItemFinder74,
+        "Lass (B2 104)" => base::Lass75,
+        "Pokémon Breeder (B2 105)" => base::PokemonBreeder76,
+        "Pokémon Trader (B2 106)" => base::PokemonTrader77,
+        "Scoop Up (B2 107)" => base::ScoopUp78,
+        "Super Energy Removal (B2 108)" => base::SuperEnergyRemoval79,
+        "Defender (B2 109)" => base::Defender80,
+        "Energy Retrieval (B2 110)" => base::EnergyRetrieval81,
+        "Full Heal (B2 111)" => base::FullHeal82,
+        "Maintenance (B2 112)" => base::Maintenance83,
+        "Plus Power (B2 113)" => base::PlusPower84,
+        "Pokémon Center (B2 114)" => base::PokemonCenter85,
+        "Pokédex (B2 115)" => base::Pokedex87,
+        "Professor Oak (B2 116)" => base::ProfessorOak88,
+        "Super Potion (B2 117)" => base::SuperPotion90,
+        "Bill (B2 118)" => base::Bill90,
+        "Energy Removal (B2 119)" => base::EnergyRemoval91,
+        "Gust of Wind (B2 120)" => base::GustOfWind92,
+        "Poké Ball (B2 121)" => jungle::PokeBall64,
+        "Potion (B2 122)" => base::Potion93,
+        "Switch (B2 123)" => base::Switch94,
+    );
+
+    entries.push(("Double Colorless Energy (B2 124)".into(), Reprint::create("Double Colorless Energy (B2 124)", Box::new(base::DoubleColorlessEnergy::default()))));
+    entries.push(("Fighting Energy (B2 125)" .into(), base::BasicEnergy::create("Fighting Energy (B2 125)", "Fighting Energy", Type::Fighting)));
+    entries.push(("Fire Energy (B2 126)"     .into(), base::BasicEnergy::create("Fire Energy (B2 126)", "Fire Energy", Type::Fire)));
+    entries.push(("Grass Energy (B2 127)"    .into(), base::BasicEnergy::create("Grass Energy (B2 127)", "Grass Energy", Type::Grass)));
+    entries.push(("Lightning Energy (B2 128)".into(), base::BasicEnergy::create("Lightning Energy (B2 128)", "Lightning Energy", Type::Lightning)));
+    entries.push(("Psychic Energy (B2 129)"  .into(), base::BasicEnergy::create("Psychic Energy (B2 129)", "Psychic Energy", Type::Psychic)));
+    entries.push(("Water Energy (B2 130)"    .into(), base::BasicEnergy::create("Water Energy (B2 130)", "Water Energy", Type::Water)));
+
+    entries
+}
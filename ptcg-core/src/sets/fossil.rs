@@ -1,13 +1,53 @@
 use crate::engine::*;
-use crate::carddb::Pokemon;
+use crate::carddb::{Pokemon, Trainer};
 
 mod pokemon;
+mod trainers;
 
 pub use pokemon::*;
+pub use trainers::*;
+
+macro_rules! mk_pokemon {
+    ($vec: expr, $($name: ident),+) => {
+        $({
+                let boxed = Pokemon::create::<$name>();
+                $vec.push((boxed.identifier(), boxed));
+        })+
+    }
+}
+
+macro_rules! mk_trainer {
+    ($vec: expr, $($name: ident),+) => {
+        $({
+                let boxed = Trainer::create::<$name>();
+                $vec.push((boxed.identifier(), boxed));
+        })+
+    }
+}
 
 pub fn build() -> Vec<(String, Box<dyn CardArchetype>)> {
-    vec![
-        ("Articuno (FO 17)".into(), Pokemon::create::<Articuno17>()),
-        ("Psyduck (FO 53)".into(), Pokemon::create::<Psyduck53>()),
-    ]
+    let mut entries = vec![];
+
+    mk_pokemon!(
+        entries,
+        Aerodactyl1, Articuno2, Ditto3, Dragonite4, Gengar5,
+        Haunter6, Hitmonlee7, Hypno8, Kabutops9, Lapras10, Magneton11, Moltres12,
+        Muk13, Raichu14, Zapdos15, Aerodactyl16, Articuno17,
+        Ditto18, Dragonite19, Gengar20, Haunter21, Hitmonlee22, Hypno23,
+        Kabutops24, Lapras25, Magneton26, Moltres27, Muk28, Raichu29,
+        Zapdos30, Arbok31, Cloyster32, Gastly33, Golbat34, Golduck35,
+        Golem36, Graveler37, Kingler38, Magmar39, Omastar40,
+        Sandslash41, Seadra42, Slowbro43, Tentacruel44, Weezing45,
+        Ekans46, Geodude47, Grimer48, Horsea49, Kabuto50, Krabby51,
+        Omanyte52, Psyduck53, Shellder54, Slowpoke55, Tentacool56,
+        Zubat57
+    );
+
+    mk_trainer!(
+        entries,
+        MrFuji58, EnergySearch59, Gambler60, Recall61,
+        MysteriousFossil62
+    );
+
+    entries
 }
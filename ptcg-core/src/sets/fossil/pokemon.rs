@@ -25,6 +25,11 @@ impl CardArchetype for Aerodactyl1 {
             Attack::new("Wing Attack", Self::wing_attack),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Prehistoric Power", Self::prehistoric_power),
+        ]
+    }
 }
 impl Aerodactyl1 {
     pub fn wing_attack(builder: AttackBuilder) -> AttackBuilder {
@@ -32,6 +37,10 @@ impl Aerodactyl1 {
             .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
             .damage(30)
     }
+    pub fn prehistoric_power(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -67,8 +76,13 @@ impl Articuno2 {
             .damage(30)
             .if_heads(|e| e.paralyze())
     }
-    pub fn blizzard(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn blizzard(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Water, Type::Water, Type::Water])
+            .flip_a_coin()
+            .damage(50)
+            .if_heads(|e| e.each_opponents_bench(|e| e.damage_ignoring_weakness_and_resistance(10)))
+            .if_tails(|e| e.each_own_bench(|e| e.damage_ignoring_weakness_and_resistance(10)))
     }
 }
 
@@ -94,8 +108,17 @@ impl CardArchetype for Ditto3 {
         vec![
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Transform", Self::transform),
+        ]
+    }
 }
 impl Ditto3 {
+    pub fn transform(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -121,6 +144,11 @@ impl CardArchetype for Dragonite4 {
             Attack::new("Slam", Self::slam),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Step In", Self::step_in),
+        ]
+    }
 }
 impl Dragonite4 {
     pub fn slam(builder: AttackBuilder) -> AttackBuilder {
@@ -129,6 +157,11 @@ impl Dragonite4 {
             .flip_coins(2)
             .damage_per_heads(40)
     }
+    pub fn step_in(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .once_per_turn()
+            .switch_this_pokemon_from_bench()
+    }
 }
 
 #[derive(Default)]
@@ -154,10 +187,24 @@ impl CardArchetype for Gengar5 {
             Attack::new("Dark Mind", Self::dark_mind),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Curse", Self::curse),
+        ]
+    }
 }
 impl Gengar5 {
-    pub fn dark_mind(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn dark_mind(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Psychic])
+            .damage(30)
+            .choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
+    }
+    pub fn curse(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .once_per_turn()
+            .move_opponents_damage_counter()
     }
 }
 
@@ -184,6 +231,11 @@ impl CardArchetype for Haunter6 {
             Attack::new("Nightmare", Self::nightmare),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Transparency", Self::transparency),
+        ]
+    }
 }
 impl Haunter6 {
     pub fn nightmare(builder: AttackBuilder) -> AttackBuilder {
@@ -192,6 +244,10 @@ impl Haunter6 {
             .damage(10)
             .asleep()
     }
+    pub fn transparency(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -220,8 +276,10 @@ impl CardArchetype for Hitmonlee7 {
     }
 }
 impl Hitmonlee7 {
-    pub fn stretch_kick(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn stretch_kick(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting])
+            .choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(20))
     }
     pub fn high_jump_kick(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -256,11 +314,16 @@ impl CardArchetype for Hypno8 {
     }
 }
 impl Hypno8 {
-    pub fn prophecy(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn prophecy(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic])
+            .rearrange_top_of_either_deck(3)
     }
-    pub fn dark_mind(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn dark_mind(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Colorless])
+            .damage(30)
+            .choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -295,8 +358,11 @@ impl Kabutops9 {
             .attack_cost(&[Type::Fighting, Type::Fighting])
             .damage(30)
     }
-    pub fn absorb(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn absorb(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Fighting, Type::Fighting])
+            .damage(40)
+            .heal_attacking_half_damage_done()
     }
 }
 
@@ -326,8 +392,10 @@ impl CardArchetype for Lapras10 {
     }
 }
 impl Lapras10 {
-    pub fn water_gun(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn water_gun(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water])
+            .damage_plus_per_extra_energy_on_attacking(10, 10, Type::Water, 2)
     }
     pub fn confuse_ray(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -364,8 +432,10 @@ impl CardArchetype for Magneton11 {
     }
 }
 impl Magneton11 {
-    pub fn sonicboom(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn sonicboom(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning])
+            .damage_ignoring_weakness_and_resistance(20)
     }
     pub fn selfdestruct(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -403,8 +473,10 @@ impl CardArchetype for Moltres12 {
     }
 }
 impl Moltres12 {
-    pub fn wildfire(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn wildfire(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fire])
+            .discard_opponents_deck_per_discarded_attacking_energy_card(Type::Fire)
     }
     pub fn dive_bomb(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -437,6 +509,11 @@ impl CardArchetype for Muk13 {
             Attack::new("Sludge", Self::sludge),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Toxic Gas", Self::toxic_gas),
+        ]
+    }
 }
 impl Muk13 {
     pub fn sludge(builder: AttackBuilder) -> AttackBuilder {
@@ -446,6 +523,10 @@ impl Muk13 {
             .damage(30)
             .if_heads(|e| e.poison())
     }
+    pub fn toxic_gas(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -473,8 +554,11 @@ impl CardArchetype for Raichu14 {
     }
 }
 impl Raichu14 {
-    pub fn gigashock(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn gigashock(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Lightning, Type::Lightning])
+            .damage(60)
+            .choose_opponents_benched_pokemon_up_to(3, |e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -503,8 +587,13 @@ impl CardArchetype for Zapdos15 {
     }
 }
 impl Zapdos15 {
-    pub fn thunderstorm(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn thunderstorm(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Lightning, Type::Lightning])
+            .damage(40)
+            .flip_coins_per_opponents_benched_pokemon()
+            .each_opponents_bench_on_heads(|e| e.damage_ignoring_weakness_and_resistance(20))
+            .damage_self_per_tails(10)
     }
 }
 
@@ -531,6 +620,11 @@ impl CardArchetype for Aerodactyl16 {
             Attack::new("Wing Attack", Self::wing_attack),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Prehistoric Power", Self::prehistoric_power),
+        ]
+    }
 }
 impl Aerodactyl16 {
     pub fn wing_attack(builder: AttackBuilder) -> AttackBuilder {
@@ -538,6 +632,10 @@ impl Aerodactyl16 {
             .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
             .damage(30)
     }
+    pub fn prehistoric_power(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -573,8 +671,13 @@ impl Articuno17 {
             .damage(30)
             .if_heads(|e| e.paralyze())
     }
-    pub fn blizzard(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn blizzard(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Water, Type::Water, Type::Water])
+            .flip_a_coin()
+            .damage(50)
+            .if_heads(|e| e.each_opponents_bench(|e| e.damage_ignoring_weakness_and_resistance(10)))
+            .if_tails(|e| e.each_own_bench(|e| e.damage_ignoring_weakness_and_resistance(10)))
     }
 }
 
@@ -600,8 +703,17 @@ impl CardArchetype for Ditto18 {
         vec![
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Transform", Self::transform),
+        ]
+    }
 }
 impl Ditto18 {
+    pub fn transform(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -627,6 +739,11 @@ impl CardArchetype for Dragonite19 {
             Attack::new("Slam", Self::slam),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Step In", Self::step_in),
+        ]
+    }
 }
 impl Dragonite19 {
     pub fn slam(builder: AttackBuilder) -> AttackBuilder {
@@ -635,6 +752,11 @@ impl Dragonite19 {
             .flip_coins(2)
             .damage_per_heads(40)
     }
+    pub fn step_in(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .once_per_turn()
+            .switch_this_pokemon_from_bench()
+    }
 }
 
 #[derive(Default)]
@@ -660,10 +782,24 @@ impl CardArchetype for Gengar20 {
             Attack::new("Dark Mind", Self::dark_mind),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Curse", Self::curse),
+        ]
+    }
 }
 impl Gengar20 {
-    pub fn dark_mind(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn dark_mind(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Psychic])
+            .damage(30)
+            .choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
+    }
+    pub fn curse(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .once_per_turn()
+            .move_opponents_damage_counter()
     }
 }
 
@@ -690,6 +826,11 @@ impl CardArchetype for Haunter21 {
             Attack::new("Nightmare", Self::nightmare),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Transparency", Self::transparency),
+        ]
+    }
 }
 impl Haunter21 {
     pub fn nightmare(builder: AttackBuilder) -> AttackBuilder {
@@ -698,6 +839,10 @@ impl Haunter21 {
             .damage(10)
             .asleep()
     }
+    pub fn transparency(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -726,8 +871,10 @@ impl CardArchetype for Hitmonlee22 {
     }
 }
 impl Hitmonlee22 {
-    pub fn stretch_kick(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn stretch_kick(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting])
+            .choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(20))
     }
     pub fn high_jump_kick(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -762,11 +909,16 @@ impl CardArchetype for Hypno23 {
     }
 }
 impl Hypno23 {
-    pub fn prophecy(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn prophecy(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic])
+            .rearrange_top_of_either_deck(3)
     }
-    pub fn dark_mind(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn dark_mind(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Colorless])
+            .damage(30)
+            .choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -801,8 +953,11 @@ impl Kabutops24 {
             .attack_cost(&[Type::Fighting, Type::Fighting])
             .damage(30)
     }
-    pub fn absorb(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn absorb(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Fighting, Type::Fighting])
+            .damage(40)
+            .heal_attacking_half_damage_done()
     }
 }
 
@@ -832,8 +987,10 @@ impl CardArchetype for Lapras25 {
     }
 }
 impl Lapras25 {
-    pub fn water_gun(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn water_gun(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water])
+            .damage_plus_per_extra_energy_on_attacking(10, 10, Type::Water, 2)
     }
     pub fn confuse_ray(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -870,8 +1027,10 @@ impl CardArchetype for Magneton26 {
     }
 }
 impl Magneton26 {
-    pub fn sonicboom(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn sonicboom(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning])
+            .damage_ignoring_weakness_and_resistance(20)
     }
     pub fn selfdestruct(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -909,8 +1068,10 @@ impl CardArchetype for Moltres27 {
     }
 }
 impl Moltres27 {
-    pub fn wildfire(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn wildfire(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fire])
+            .discard_opponents_deck_per_discarded_attacking_energy_card(Type::Fire)
     }
     pub fn dive_bomb(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -943,6 +1104,11 @@ impl CardArchetype for Muk28 {
             Attack::new("Sludge", Self::sludge),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Toxic Gas", Self::toxic_gas),
+        ]
+    }
 }
 impl Muk28 {
     pub fn sludge(builder: AttackBuilder) -> AttackBuilder {
@@ -952,6 +1118,10 @@ impl Muk28 {
             .damage(30)
             .if_heads(|e| e.poison())
     }
+    pub fn toxic_gas(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -979,8 +1149,11 @@ impl CardArchetype for Raichu29 {
     }
 }
 impl Raichu29 {
-    pub fn gigashock(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn gigashock(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Lightning, Type::Lightning])
+            .damage(60)
+            .choose_opponents_benched_pokemon_up_to(3, |e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -1009,8 +1182,13 @@ impl CardArchetype for Zapdos30 {
     }
 }
 impl Zapdos30 {
-    pub fn thunderstorm(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn thunderstorm(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Lightning, Type::Lightning])
+            .damage(40)
+            .flip_coins_per_opponents_benched_pokemon()
+            .each_opponents_bench_on_heads(|e| e.damage_ignoring_weakness_and_resistance(20))
+            .damage_self_per_tails(10)
     }
 }
 
@@ -1040,8 +1218,12 @@ impl CardArchetype for Arbok31 {
     }
 }
 impl Arbok31 {
-    pub fn terror_strike(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn terror_strike(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .flip_a_coin()
+            .damage(10)
+            .if_heads(|e| e.switch_defending())
     }
     pub fn poison_fang(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1077,8 +1259,11 @@ impl CardArchetype for Cloyster32 {
     }
 }
 impl Cloyster32 {
-    pub fn clamp(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn clamp(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Water])
+            .flip_a_coin()
+            .if_heads(|e| e.damage(30).paralyze())
     }
     pub fn spike_cannon(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1121,8 +1306,11 @@ impl Gastly33 {
             .damage(10)
             .if_heads(|e| e.paralyze())
     }
-    pub fn energy_conversion(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn energy_conversion(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic])
+            .energy_cards_from_discard_to_hand(2)
+            .damage_self(10)
     }
 }
 
@@ -1157,8 +1345,11 @@ impl Golbat34 {
             .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
             .damage(30)
     }
-    pub fn leech_life(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn leech_life(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Colorless])
+            .damage(20)
+            .heal_attacking_damage_done()
     }
 }
 
@@ -1386,8 +1577,10 @@ impl CardArchetype for Omastar40 {
     }
 }
 impl Omastar40 {
-    pub fn water_gun(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn water_gun(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Colorless])
+            .damage_plus_per_extra_energy_on_attacking(20, 10, Type::Water, 2)
     }
     pub fn spike_cannon(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1462,8 +1655,10 @@ impl CardArchetype for Seadra42 {
     }
 }
 impl Seadra42 {
-    pub fn water_gun(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn water_gun(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Colorless])
+            .damage_plus_per_extra_energy_on_attacking(20, 10, Type::Water, 2)
     }
     pub fn agility(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1510,8 +1705,10 @@ impl Slowbro43 {
             .damage(20)
             .if_heads(|e| e.paralyze())
     }
-    pub fn strange_behavior(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn strange_behavior(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .move_own_damage_counter_to_this_pokemon_without_ko()
     }
 }
 
@@ -1664,8 +1861,11 @@ impl CardArchetype for Geodude47 {
     }
 }
 impl Geodude47 {
-    pub fn stone_barrage(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn stone_barrage(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Colorless])
+            .flip_until_tails()
+            .damage_per_heads(10)
     }
 }
 
@@ -1702,8 +1902,10 @@ impl Grimer48 {
             .damage(10)
             .if_heads(|e| e.paralyze())
     }
-    pub fn minimize(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn minimize(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .reduce_damage_during_opponents_next_turn(20)
     }
 }
 
@@ -1762,6 +1964,20 @@ impl CardArchetype for Kabuto50 {
             Attack::new("Scratch", Self::scratch),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Kabuto Armor", Self::kabuto_armor),
+        ]
+    }
+    fn defending_damage_effect(&self, card: &Card, engine: &GameEngine, damage: usize) -> Option<usize> {
+        // Kabuto Armor: half the damage, rounded down to the nearest 10.
+        let defending = engine.defending();
+        if defending.stack[0].card() == card && engine.has_pokemon_power(defending, "Kabuto Armor") {
+            Some(damage / 20 * 10)
+        } else {
+            None
+        }
+    }
 }
 impl Kabuto50 {
     pub fn scratch(builder: AttackBuilder) -> AttackBuilder {
@@ -1769,6 +1985,10 @@ impl Kabuto50 {
             .attack_cost(&[Type::Colorless])
             .damage(10)
     }
+    pub fn kabuto_armor(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -1797,8 +2017,11 @@ impl CardArchetype for Krabby51 {
     }
 }
 impl Krabby51 {
-    pub fn call_for_family(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn call_for_family(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water])
+            .must_have_bench_space()
+            .search_deck_to_bench(1, |e, c| e.stage(c) == Some(Stage::Basic) && e.archetype(c).name() == "Krabby")
     }
     pub fn irongrip(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1830,10 +2053,21 @@ impl CardArchetype for Omanyte52 {
             Attack::new("Water Gun", Self::water_gun),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Clairvoyance", Self::clairvoyance),
+        ]
+    }
 }
 impl Omanyte52 {
-    pub fn water_gun(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn water_gun(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water])
+            .damage_plus_per_extra_energy_on_attacking(10, 10, Type::Water, 2)
+    }
+    pub fn clairvoyance(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
     }
 }
 
@@ -1863,8 +2097,10 @@ impl CardArchetype for Psyduck53 {
     }
 }
 impl Psyduck53 {
-    pub fn headache(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn headache(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic])
+            .prevent_trainers_during_opponents_next_turn()
     }
     pub fn fury_swipes(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1940,11 +2176,18 @@ impl CardArchetype for Slowpoke55 {
     }
 }
 impl Slowpoke55 {
-    pub fn spacing_out(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn spacing_out(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .attacking_must_have_damage_counters()
+            .flip_a_coin()
+            .if_heads(|e| e.heal_attacking(10))
     }
-    pub fn scavenge(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn scavenge(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic])
+            .cost(|e| e.discard_attacking_energy_cards(&[Type::Psychic]))
+            .trainer_card_from_discard_to_hand()
     }
 }
 
@@ -1971,6 +2214,11 @@ impl CardArchetype for Tentacool56 {
             Attack::new("Acid", Self::acid),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Cowardice", Self::cowardice),
+        ]
+    }
 }
 impl Tentacool56 {
     pub fn acid(builder: AttackBuilder) -> AttackBuilder {
@@ -1978,6 +2226,12 @@ impl Tentacool56 {
             .attack_cost(&[Type::Water])
             .damage(10)
     }
+    pub fn cowardice(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .not_the_turn_this_pokemon_was_put_in_play()
+            .return_this_pokemon_to_hand()
+    }
 }
 
 #[derive(Default)]
@@ -2012,7 +2266,10 @@ impl Zubat57 {
             .flip_a_coin()
             .if_heads(|e| e.confuse())
     }
-    pub fn leech_life(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn leech_life(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Colorless])
+            .damage(10)
+            .heal_attacking_damage_done()
     }
 }
\ No newline at end of file
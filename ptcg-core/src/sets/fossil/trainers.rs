@@ -0,0 +1,139 @@
+use crate::state::*;
+use crate::engine::*;
+use crate::*;
+use crate::carddb::TrainerCardArchetype;
+use crate::custom_effects::Recall;
+use crate::effect::CustomEffect;
+
+#[derive(Default)]
+pub struct MrFuji58 {}
+impl TrainerCardArchetype for MrFuji58 {
+    identifier!("Mr. Fuji (FO 58)");
+    card_name!("Mr. Fuji");
+
+    fn cost(&self, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine.ensure(|e| !e.bench(e.player()).is_empty())
+    }
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let bench = engine.bench(player);
+        let chosen = dm.pick_in_play_cards(player, 1, &bench);
+
+        self
+            .cost(engine, dm)
+            .shuffle_into_deck(chosen[0], |_, _| true)
+    }
+}
+
+#[derive(Default)]
+pub struct EnergySearch59 {}
+impl TrainerCardArchetype for EnergySearch59 {
+    identifier!("Energy Search (FO 59)");
+    card_name!("Energy Search");
+
+    fn cost(&self, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine.ensure_deck_not_empty(engine.player())
+    }
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        self
+            .cost(engine, dm)
+            .search_deck_to_hand(player, 1, |e, c| e.is_basic_energy(c), dm)
+    }
+}
+
+#[derive(Default)]
+pub struct Gambler60 {}
+impl TrainerCardArchetype for Gambler60 {
+    identifier!("Gambler (FO 60)");
+    card_name!("Gambler");
+
+    fn execute(&self, player: Player, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let engine = engine
+            .shuffle_all_from_hand_into_deck(player, |_, c| c != card, dm)
+            .shuffle_deck(player);
+        let (engine, flips) = engine.flip(player, 1, dm);
+
+        if flips.is_heads() {
+            engine.draw(player, 8, dm)
+        } else {
+            engine.draw(player, 1, dm)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Recall61 {}
+impl TrainerCardArchetype for Recall61 {
+    identifier!("Recall (FO 61)");
+    card_name!("Recall");
+
+    fn cost(&self, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine.ensure(|e| !Self::targets(e.player(), e).is_empty())
+    }
+
+    fn execute(&self, player: Player, card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let targets = Self::targets(player, engine);
+        let target = dm.pick_in_play_cards(player, 1, &targets)[0].clone();
+
+        let (archetypes, attacks): (Vec<_>, Vec<_>) = Self::recallable_attacks(&target, engine).into_iter().unzip();
+        let chosen = dm.pick_attack(player, &attacks);
+        let index = attacks.iter().position(|a| std::ptr::eq(a, chosen)).unwrap();
+
+        engine.with_effect(Effect {
+            source: EffectSource::Trainer(player, card.clone()),
+            target: EffectTarget::InPlayPokemon(player, target.id),
+            expires: EffectExpiration::EndOfTurn(player, 0),
+            consequence: Recall::identifier(),
+            parameters: vec![
+                EffectParameter::String(archetypes[index].clone()),
+                EffectParameter::String(chosen.name().clone()),
+            ],
+            name: "Recall".into(),
+        })
+    }
+}
+impl Recall61 {
+    fn targets(player: Player, engine: &GameEngine) -> Vec<InPlayCard> {
+        engine
+            .in_play(player)
+            .into_iter()
+            .filter(|ip| !Self::recallable_attacks(ip, engine).is_empty())
+            .cloned()
+            .collect()
+    }
+
+    // the attacks on the cards beneath the top of the stack
+    fn recallable_attacks(in_play: &InPlayCard, engine: &GameEngine) -> Vec<(String, Attack)> {
+        in_play.stack[1..]
+            .iter()
+            .flat_map(|c| {
+                let archetype = engine.archetype(c.card());
+                archetype.attacks().into_iter().map(move |a| (archetype.identifier(), a))
+            })
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct MysteriousFossil62 {}
+impl TrainerCardArchetype for MysteriousFossil62 {
+    identifier!("Mysterious Fossil (FO 62)");
+    card_name!("Mysterious Fossil");
+
+    // TODO: it can be discarded at any time during its owner's turn
+    fn requirements_ok(&self, player: Player, card: &Card, engine: &GameEngine) -> bool {
+        engine.can_bench(player, card)
+    }
+
+    fn execute(&self, player: Player, card: &Card, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine.bench_from_hand(player, card)
+    }
+
+    fn hp(&self, card: &Card, engine: &GameEngine) -> Option<usize> {
+        match engine.zone(card) {
+            Zone::InPlay(_) => Some(10),
+            _ => None,
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use crate::engine::*;
-use crate::carddb::Pokemon;
+use crate::carddb::{Pokemon, Trainer};
 
 mod pokemon;
+mod trainers;
 
 pub use pokemon::*;
+pub use trainers::*;
 
 macro_rules! mk_pokemon {
     ($vec: expr, $($name: ident),+) => {
@@ -14,12 +16,37 @@ macro_rules! mk_pokemon {
     }
 }
 
+macro_rules! mk_trainer {
+    ($vec: expr, $($name: ident),+) => {
+        $({
+                let boxed = Trainer::create::<$name>();
+                $vec.push((boxed.identifier(), boxed));
+        })+
+    }
+}
+
 pub fn build() -> Vec<(String, Box<dyn CardArchetype>)> {
     let mut entries = vec![];
 
     mk_pokemon!(
         entries,
-        Kangaskhan5, Kangaskhan21
+        Clefable1, Electrode2, Flareon3, Jolteon4, Kangaskhan5, MrMime6,
+        Nidoqueen7, Pidgeot8, Pinsir9, Scyther10, Snorlax11, Vaporeon12,
+        Venomoth13, Victreebel14, Vileplume15, Wigglytuff16, Clefable17,
+        Electrode18, Flareon19, Jolteon20, Kangaskhan21, MrMime22,
+        Nidoqueen23, Pidgeot24, Pinsir25, Scyther26, Snorlax27,
+        Vaporeon28, Venomoth29, Victreebel30, Vileplume31, Wigglytuff32,
+        Butterfree33, Dodrio34, Exeggutor35, Fearow36, Gloom37,
+        Lickitung38, Marowak39, Nidorina40, Parasect41, Persian42,
+        Primeape43, Rapidash44, Rhydon45, Seaking46, Tauros47,
+        Weepinbell48, Bellsprout49, Cubone50, Eevee51, Exeggcute52,
+        Goldeen53, Jigglypuff54, Mankey55, Meowth56, NidoranF57,
+        Oddish58, Paras59, Pikachu60, Rhyhorn61, Spearow62, Venonat63
+    );
+
+    mk_trainer!(
+        entries,
+        PokeBall64
     );
 
     entries
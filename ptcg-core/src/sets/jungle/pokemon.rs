@@ -33,8 +33,10 @@ impl Clefable1 {
             .attack_cost(&[Type::Colorless])
             .copy_defending_attack_without_costs()
     }
-    pub fn minimize(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn minimize(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .reduce_damage_during_opponents_next_turn(20)
     }
 }
 
@@ -69,8 +71,11 @@ impl Electrode2 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn chain_lightning(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn chain_lightning(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Lightning])
+            .damage(20)
+            .each_bench_of_defending_type(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -218,6 +223,20 @@ impl CardArchetype for MrMime6 {
             Attack::new("Meditate", Self::meditate),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Invisible Wall", Self::invisible_wall),
+        ]
+    }
+    fn defending_damage_effect(&self, card: &Card, engine: &GameEngine, damage: usize) -> Option<usize> {
+        // Invisible Wall: attacks doing 30 or more damage do nothing at all.
+        let defending = engine.defending();
+        if defending.stack[0].card() == card && damage >= 30 && engine.has_pokemon_power(defending, "Invisible Wall") {
+            Some(0)
+        } else {
+            None
+        }
+    }
 }
 impl MrMime6 {
     pub fn meditate(builder: AttackBuilder) -> AttackBuilder {
@@ -225,6 +244,10 @@ impl MrMime6 {
             .attack_cost(&[Type::Psychic, Type::Colorless])
             .damage_plus_per_damage_counter_on_defending(10, 10)
     }
+    pub fn invisible_wall(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -253,8 +276,10 @@ impl CardArchetype for Nidoqueen7 {
     }
 }
 impl Nidoqueen7 {
-    pub fn boyfriends(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn boyfriends(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Colorless])
+            .damage_plus_per_own_pokemon_in_play_named(20, 20, &["Nidoking"])
     }
     pub fn mega_punch(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -294,8 +319,11 @@ impl Pidgeot8 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn hurricane(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn hurricane(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage(30)
+            .return_defending_to_hand_unless_knocked_out()
     }
 }
 
@@ -365,8 +393,10 @@ impl CardArchetype for Scyther10 {
     }
 }
 impl Scyther10 {
-    pub fn swords_dance(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn swords_dance(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .swords_dance()
     }
     pub fn slash(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -398,6 +428,11 @@ impl CardArchetype for Snorlax11 {
             Attack::new("Body Slam", Self::body_slam),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Thick Skinned", Self::thick_skinned),
+        ]
+    }
 }
 impl Snorlax11 {
     pub fn body_slam(builder: AttackBuilder) -> AttackBuilder {
@@ -407,6 +442,10 @@ impl Snorlax11 {
             .damage(30)
             .if_heads(|e| e.paralyze())
     }
+    pub fn thick_skinned(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -472,10 +511,25 @@ impl CardArchetype for Venomoth13 {
             Attack::new("Venom Powder", Self::venom_powder),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Shift", Self::shift),
+        ]
+    }
 }
 impl Venomoth13 {
-    pub fn venom_powder(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn venom_powder(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .flip_a_coin()
+            .damage(10)
+            .if_heads(|e| e.confuse().poison())
+    }
+    pub fn shift(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .once_per_turn()
+            .change_this_pokemon_type()
     }
 }
 
@@ -510,8 +564,12 @@ impl Victreebel14 {
             .attack_cost(&[Type::Grass])
             .gust_defending()
     }
-    pub fn acid(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn acid(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .flip_a_coin()
+            .damage(20)
+            .if_heads(|e| e.prevent_retreat_during_opponents_next_turn())
     }
 }
 
@@ -538,6 +596,11 @@ impl CardArchetype for Vileplume15 {
             Attack::new("Petal Dance", Self::petal_dance),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Heal", Self::heal),
+        ]
+    }
 }
 impl Vileplume15 {
     pub fn petal_dance(builder: AttackBuilder) -> AttackBuilder {
@@ -546,6 +609,14 @@ impl Vileplume15 {
             .flip_coins(3)
             .damage_per_heads(40)
     }
+    pub fn heal(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .must_have_damaged_pokemon()
+            .once_per_turn()
+            .flip_a_coin()
+            .if_heads(|e| e.heal_own_pokemon(10))
+    }
 }
 
 #[derive(Default)]
@@ -579,8 +650,10 @@ impl Wigglytuff16 {
             .attack_cost(&[Type::Colorless])
             .asleep()
     }
-    pub fn do_the_wave(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn do_the_wave(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage_plus_per_own_benched_pokemon(10, 10)
     }
 }
 
@@ -615,8 +688,10 @@ impl Clefable17 {
             .attack_cost(&[Type::Colorless])
             .copy_defending_attack_without_costs()
     }
-    pub fn minimize(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn minimize(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .reduce_damage_during_opponents_next_turn(20)
     }
 }
 
@@ -651,8 +726,11 @@ impl Electrode18 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn chain_lightning(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn chain_lightning(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Lightning])
+            .damage(20)
+            .each_bench_of_defending_type(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -800,6 +878,20 @@ impl CardArchetype for MrMime22 {
             Attack::new("Meditate", Self::meditate),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Invisible Wall", Self::invisible_wall),
+        ]
+    }
+    fn defending_damage_effect(&self, card: &Card, engine: &GameEngine, damage: usize) -> Option<usize> {
+        // Invisible Wall: attacks doing 30 or more damage do nothing at all.
+        let defending = engine.defending();
+        if defending.stack[0].card() == card && damage >= 30 && engine.has_pokemon_power(defending, "Invisible Wall") {
+            Some(0)
+        } else {
+            None
+        }
+    }
 }
 impl MrMime22 {
     pub fn meditate(builder: AttackBuilder) -> AttackBuilder {
@@ -807,6 +899,10 @@ impl MrMime22 {
             .attack_cost(&[Type::Psychic, Type::Colorless])
             .damage_plus_per_damage_counter_on_defending(10, 10)
     }
+    pub fn invisible_wall(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -835,8 +931,10 @@ impl CardArchetype for Nidoqueen23 {
     }
 }
 impl Nidoqueen23 {
-    pub fn boyfriends(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn boyfriends(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Colorless])
+            .damage_plus_per_own_pokemon_in_play_named(20, 20, &["Nidoking"])
     }
     pub fn mega_punch(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -876,8 +974,11 @@ impl Pidgeot24 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn hurricane(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn hurricane(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage(30)
+            .return_defending_to_hand_unless_knocked_out()
     }
 }
 
@@ -947,8 +1048,10 @@ impl CardArchetype for Scyther26 {
     }
 }
 impl Scyther26 {
-    pub fn swords_dance(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn swords_dance(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .swords_dance()
     }
     pub fn slash(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -980,6 +1083,11 @@ impl CardArchetype for Snorlax27 {
             Attack::new("Body Slam", Self::body_slam),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Thick Skinned", Self::thick_skinned),
+        ]
+    }
 }
 impl Snorlax27 {
     pub fn body_slam(builder: AttackBuilder) -> AttackBuilder {
@@ -989,6 +1097,10 @@ impl Snorlax27 {
             .damage(30)
             .if_heads(|e| e.paralyze())
     }
+    pub fn thick_skinned(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -1054,10 +1166,25 @@ impl CardArchetype for Venomoth29 {
             Attack::new("Venom Powder", Self::venom_powder),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Shift", Self::shift),
+        ]
+    }
 }
 impl Venomoth29 {
-    pub fn venom_powder(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn venom_powder(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .flip_a_coin()
+            .damage(10)
+            .if_heads(|e| e.confuse().poison())
+    }
+    pub fn shift(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .once_per_turn()
+            .change_this_pokemon_type()
     }
 }
 
@@ -1092,8 +1219,12 @@ impl Victreebel30 {
             .attack_cost(&[Type::Grass])
             .gust_defending()
     }
-    pub fn acid(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn acid(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .flip_a_coin()
+            .damage(20)
+            .if_heads(|e| e.prevent_retreat_during_opponents_next_turn())
     }
 }
 
@@ -1120,6 +1251,11 @@ impl CardArchetype for Vileplume31 {
             Attack::new("Petal Dance", Self::petal_dance),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Heal", Self::heal),
+        ]
+    }
 }
 impl Vileplume31 {
     pub fn petal_dance(builder: AttackBuilder) -> AttackBuilder {
@@ -1128,6 +1264,14 @@ impl Vileplume31 {
             .flip_coins(3)
             .damage_per_heads(40)
     }
+    pub fn heal(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .must_have_damaged_pokemon()
+            .once_per_turn()
+            .flip_a_coin()
+            .if_heads(|e| e.heal_own_pokemon(10))
+    }
 }
 
 #[derive(Default)]
@@ -1161,8 +1305,10 @@ impl Wigglytuff32 {
             .attack_cost(&[Type::Colorless])
             .asleep()
     }
-    pub fn do_the_wave(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn do_the_wave(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage_plus_per_own_benched_pokemon(10, 10)
     }
 }
 
@@ -1198,8 +1344,11 @@ impl Butterfree33 {
             .damage(20)
             .switch_defending()
     }
-    pub fn mega_drain(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mega_drain(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Grass, Type::Grass])
+            .damage(40)
+            .heal_attacking_half_damage_done()
     }
 }
 
@@ -1226,10 +1375,21 @@ impl CardArchetype for Dodrio34 {
             Attack::new("Rage", Self::rage),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Retreat Aid", Self::retreat_aid),
+        ]
+    }
 }
 impl Dodrio34 {
-    pub fn rage(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn rage(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage_plus_per_damage_counter_on_itself(10, 10)
+    }
+    pub fn retreat_aid(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
     }
 }
 
@@ -1259,11 +1419,16 @@ impl CardArchetype for Exeggutor35 {
     }
 }
 impl Exeggutor35 {
-    pub fn teleport(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn teleport(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic])
+            .switch_attacking()
     }
-    pub fn big_eggsplosion(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn big_eggsplosion(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .flip_coins_per_energy_attached_to_attacking()
+            .damage_per_heads(20)
     }
 }
 
@@ -1337,8 +1502,12 @@ impl Gloom37 {
             .attack_cost(&[Type::Grass])
             .poison()
     }
-    pub fn foul_odor(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn foul_odor(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .damage(20)
+            .confuse()
+            .confuse_attacking()
     }
 }
 
@@ -1415,8 +1584,11 @@ impl Marowak39 {
             .flip_coins(2)
             .damage_per_heads(30)
     }
-    pub fn call_for_friend(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn call_for_friend(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting])
+            .must_have_bench_space()
+            .search_deck_to_bench(1, |e, c| e.stage(c) == Some(Stage::Basic) && e.archetype(c).pokemon_type().contains(&Type::Fighting))
     }
 }
 
@@ -1529,8 +1701,11 @@ impl Persian42 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn pounce(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn pounce(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage(30)
+            .reduce_defending_damage_during_opponents_next_turn(10)
     }
 }
 
@@ -1566,8 +1741,12 @@ impl Primeape43 {
             .flip_coins(3)
             .damage_per_heads(20)
     }
-    pub fn tantrum(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn tantrum(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Colorless])
+            .flip_a_coin()
+            .damage(50)
+            .if_tails(|e| e.confuse_attacking())
     }
 }
 
@@ -1643,8 +1822,12 @@ impl Rhydon45 {
             .attack_cost(&[Type::Fighting, Type::Colorless, Type::Colorless])
             .damage(30)
     }
-    pub fn ram(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn ram(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Fighting, Type::Fighting])
+            .damage(50)
+            .damage_self(20)
+            .switch_defending()
     }
 }
 
@@ -1719,8 +1902,12 @@ impl Tauros47 {
             .if_heads(|e| e.damage(30))
             .if_tails(|e| e.damage(20))
     }
-    pub fn rampage(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn rampage(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .flip_a_coin()
+            .damage_plus_per_damage_counter_on_itself(20, 10)
+            .if_tails(|e| e.confuse_attacking())
     }
 }
 
@@ -1795,8 +1982,11 @@ impl Bellsprout49 {
             .attack_cost(&[Type::Grass])
             .damage(10)
     }
-    pub fn call_for_family(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn call_for_family(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .must_have_bench_space()
+            .search_deck_to_bench(1, |e, c| e.stage(c) == Some(Stage::Basic) && e.archetype(c).name() == "Bellsprout")
     }
 }
 
@@ -1826,11 +2016,15 @@ impl CardArchetype for Cubone50 {
     }
 }
 impl Cubone50 {
-    pub fn snivel(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn snivel(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .reduce_damage_during_opponents_next_turn(20)
     }
-    pub fn rage(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn rage(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting])
+            .damage_plus_per_damage_counter_on_itself(10, 10)
     }
 }
 
@@ -1860,8 +2054,11 @@ impl CardArchetype for Eevee51 {
     }
 }
 impl Eevee51 {
-    pub fn tail_wag(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn tail_wag(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .flip_a_coin()
+            .if_heads(|e| e.prevent_attacks_against_attacking_during_opponents_next_turn())
     }
     pub fn quick_attack(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -2079,8 +2276,11 @@ impl NidoranF57 {
             .flip_coins(3)
             .damage_per_heads(10)
     }
-    pub fn call_for_family(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn call_for_family(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .must_have_bench_space()
+            .search_deck_to_bench(5, |e, c| e.stage(c) == Some(Stage::Basic) && ["Nidoran ♂", "Nidoran ♀"].contains(&e.archetype(c).name().as_str()))
     }
 }
 
@@ -2117,8 +2317,11 @@ impl Oddish58 {
             .damage(10)
             .if_heads(|e| e.paralyze())
     }
-    pub fn sprout(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn sprout(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .must_have_bench_space()
+            .search_deck_to_bench(1, |e, c| e.stage(c) == Some(Stage::Basic) && e.archetype(c).name() == "Oddish")
     }
 }
 
@@ -2185,8 +2388,11 @@ impl CardArchetype for Pikachu60 {
     }
 }
 impl Pikachu60 {
-    pub fn spark(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn spark(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning])
+            .damage(20)
+            .choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -2216,8 +2422,11 @@ impl CardArchetype for Rhyhorn61 {
     }
 }
 impl Rhyhorn61 {
-    pub fn leer(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn leer(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .flip_a_coin()
+            .if_heads(|e| e.prevent_attacks_against_attacking_during_opponents_next_turn())
     }
     pub fn horn_attack(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -2257,8 +2466,10 @@ impl Spearow62 {
             .attack_cost(&[Type::Colorless])
             .damage(10)
     }
-    pub fn mirror_move(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mirror_move(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .damage_taken_last_turn()
     }
 }
 
@@ -2295,7 +2506,10 @@ impl Venonat63 {
             .damage(10)
             .if_heads(|e| e.paralyze())
     }
-    pub fn leech_life(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn leech_life(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .damage(10)
+            .heal_attacking_damage_done()
     }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::state::*;
+use crate::engine::*;
+use crate::*;
+use crate::carddb::TrainerCardArchetype;
+
+#[derive(Default)]
+pub struct PokeBall64 {}
+impl TrainerCardArchetype for PokeBall64 {
+    identifier!("Poké Ball (JU 64)");
+    card_name!("Poké Ball");
+
+    fn cost(&self, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine.ensure_deck_not_empty(engine.player())
+    }
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let (engine, flips) = self.cost(engine, dm).flip(player, 1, dm);
+
+        if flips.is_heads() {
+            engine.search_deck_to_hand(player, 1, |e, c| e.is_pokemon(c), dm)
+        } else {
+            engine
+        }
+    }
+}
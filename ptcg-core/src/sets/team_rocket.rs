@@ -0,0 +1,64 @@
+use crate::engine::*;
+use crate::carddb::{Pokemon, Trainer};
+
+mod pokemon;
+mod energies;
+mod trainers;
+
+pub use pokemon::*;
+pub use energies::*;
+pub use trainers::*;
+
+macro_rules! mk_pokemon {
+    ($vec: expr, $($name: ident),+) => {
+        $({
+                let boxed = Pokemon::create::<$name>();
+                $vec.push((boxed.identifier(), boxed));
+        })+
+    }
+}
+
+macro_rules! mk_trainer {
+    ($vec: expr, $($name: ident),+) => {
+        $({
+                let boxed = Trainer::create::<$name>();
+                $vec.push((boxed.identifier(), boxed));
+        })+
+    }
+}
+
+pub fn build() -> Vec<(String, Box<dyn CardArchetype>)> {
+    let mut entries = vec![];
+
+    mk_pokemon!(
+        entries,
+        DarkAlakazam1, DarkArbok2, DarkBlastoise3, DarkCharizard4,
+        DarkDragonite5, DarkDugtrio6, DarkGolbat7, DarkGyarados8, DarkHypno9,
+        DarkMachamp10, DarkMagneton11, DarkSlowbro12, DarkVileplume13,
+        DarkWeezing14, DarkAlakazam18, DarkArbok19, DarkBlastoise20,
+        DarkCharizard21, DarkDragonite22, DarkDugtrio23, DarkGolbat24,
+        DarkGyarados25, DarkHypno26, DarkMachamp27, DarkMagneton28,
+        DarkSlowbro29, DarkVileplume30, DarkWeezing31, DarkCharmeleon32,
+        DarkDragonair33, DarkElectrode34, DarkFlareon35, DarkGloom36,
+        DarkGolduck37, DarkJolteon38, DarkKadabra39, DarkMachoke40, DarkMuk41,
+        DarkPersian42, DarkPrimeape43, DarkRapidash44, DarkVaporeon45, DarkWartortle46,
+        Magikarp47, Porygon48, Abra49, Charmander50, DarkRaticate51, Diglett52, Dratini53, Drowzee54, Eevee55, Ekans56,
+        Grimer57, Koffing58, Machop59, Magnemite60, Mankey61, Meowth62,
+        Oddish63, Ponyta64, Psyduck65, Rattata66, Slowpoke67, Squirtle68,
+        Voltorb69, Zubat70, DarkRaichu83
+    );
+
+    mk_trainer!(
+        entries,
+        HereComesTeamRocket15, RocketsSneakAttack16, HereComesTeamRocket71,
+        RocketsSneakAttack72, TheBossSWay73, Challenge74, Digger75,
+        ImpostorOaksRevenge76, NightlyGarbageRun77, GoopGasAttack78, Sleep79
+    );
+
+    entries.push(("Rainbow Energy (TR 17)"  .into(), Box::new(RainbowEnergy17::default())));
+    entries.push(("Rainbow Energy (TR 80)"  .into(), Box::new(RainbowEnergy80::default())));
+    entries.push(("Full Heal Energy (TR 81)".into(), Box::new(FullHealEnergy81::default())));
+    entries.push(("Potion Energy (TR 82)"   .into(), Box::new(PotionEnergy82::default())));
+
+    entries
+}
@@ -0,0 +1,99 @@
+use crate::state::*;
+use crate::engine::*;
+use crate::*;
+
+#[derive(Default)]
+pub struct RainbowEnergy17 {}
+impl CardArchetype for RainbowEnergy17 {
+    identifier!("Rainbow Energy (TR 17)");
+    card_name!("Rainbow Energy");
+    not_a_pokemon!();
+
+    fn is_energy(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn attachable_as_energy_for_turn(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn provides(&self) -> Vec<Type> {
+        vec![Type::Any]
+    }
+
+    fn on_attach_from_hand(&self, _card: &Card, target: &InPlayCard, engine: &GameEngine) -> Option<GameEngine> {
+        Some(engine.put_damage_counters(target, 1))
+    }
+}
+
+#[derive(Default)]
+pub struct RainbowEnergy80 {}
+impl CardArchetype for RainbowEnergy80 {
+    identifier!("Rainbow Energy (TR 80)");
+    card_name!("Rainbow Energy");
+    not_a_pokemon!();
+
+    fn is_energy(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn attachable_as_energy_for_turn(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn provides(&self) -> Vec<Type> {
+        vec![Type::Any]
+    }
+
+    fn on_attach_from_hand(&self, _card: &Card, target: &InPlayCard, engine: &GameEngine) -> Option<GameEngine> {
+        Some(engine.put_damage_counters(target, 1))
+    }
+}
+
+#[derive(Default)]
+pub struct FullHealEnergy81 {}
+impl CardArchetype for FullHealEnergy81 {
+    identifier!("Full Heal Energy (TR 81)");
+    card_name!("Full Heal Energy");
+    not_a_pokemon!();
+
+    fn is_energy(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn attachable_as_energy_for_turn(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn provides(&self) -> Vec<Type> {
+        vec![Type::Colorless]
+    }
+
+    fn on_attach_from_hand(&self, _card: &Card, target: &InPlayCard, engine: &GameEngine) -> Option<GameEngine> {
+        Some(engine.remove_special_conditions(target))
+    }
+}
+
+#[derive(Default)]
+pub struct PotionEnergy82 {}
+impl CardArchetype for PotionEnergy82 {
+    identifier!("Potion Energy (TR 82)");
+    card_name!("Potion Energy");
+    not_a_pokemon!();
+
+    fn is_energy(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn attachable_as_energy_for_turn(&self, _card: &Card, _engine: &GameEngine) -> bool {
+        true
+    }
+
+    fn provides(&self) -> Vec<Type> {
+        vec![Type::Colorless]
+    }
+
+    fn on_attach_from_hand(&self, _card: &Card, target: &InPlayCard, engine: &GameEngine) -> Option<GameEngine> {
+        Some(engine.heal(target, 10))
+    }
+}
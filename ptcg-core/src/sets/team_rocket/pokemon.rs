@@ -28,11 +28,16 @@ impl CardArchetype for DarkAlakazam1 {
     }
 }
 impl DarkAlakazam1 {
-    pub fn teleport_blast(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn teleport_blast(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Colorless])
+            .damage(30)
+            .optionally_switch_attacking()
     }
-    pub fn mind_shock(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mind_shock(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Psychic])
+            .damage_ignoring_weakness_and_resistance(40)
     }
 }
 
@@ -62,11 +67,19 @@ impl CardArchetype for DarkArbok2 {
     }
 }
 impl DarkArbok2 {
-    pub fn stare(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn stare(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .opponent_must_have_benched_pokemon()
+            .gust_defending()
+            .poison()
     }
-    pub fn poison_vapor(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn poison_vapor(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Colorless])
+            .damage(20)
+            .poison()
+            .each_opponents_bench(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -96,11 +109,18 @@ impl CardArchetype for DarkBlastoise3 {
     }
 }
 impl DarkBlastoise3 {
-    pub fn hydrocannon(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn hydrocannon(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Water])
+            .damage_plus_per_extra_energy_on_attacking(30, 20, Type::Water, 2)
     }
-    pub fn rocket_tackle(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn rocket_tackle(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Water, Type::Water])
+            .damage(40)
+            .damage_self(10)
+            .flip_a_coin()
+            .if_heads(|e| e.prevent_damage_during_opponents_next_turn())
     }
 }
 
@@ -135,8 +155,12 @@ impl DarkCharizard4 {
             .attack_cost(&[Type::Colorless])
             .damage(10)
     }
-    pub fn continuous_fireball(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn continuous_fireball(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fire, Type::Fire])
+            .flip_coins_per_energy_card_on_attacking(Type::Fire)
+            .damage_per_heads(50)
+            .discard_attacking_energy_cards_per_heads(Type::Fire)
     }
 }
 
@@ -163,6 +187,14 @@ impl CardArchetype for DarkDragonite5 {
             Attack::new("Giant Tail", Self::giant_tail),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Summon Minions", Self::summon_minions),
+        ]
+    }
+    fn on_play_from_hand(&self, in_play: &InPlayCard, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        Some(engine.trigger_poke_power(in_play, "Summon Minions", dm))
+    }
 }
 impl DarkDragonite5 {
     pub fn giant_tail(builder: AttackBuilder) -> AttackBuilder {
@@ -171,6 +203,11 @@ impl DarkDragonite5 {
             .flip_a_coin()
             .if_heads(|e| e.damage(70))
     }
+    pub fn summon_minions(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+            .search_deck_to_bench(2, |e, c| e.stage(c) == Some(Stage::Basic))
+    }
 }
 
 #[derive(Default)]
@@ -196,10 +233,22 @@ impl CardArchetype for DarkDugtrio6 {
             Attack::new("Knock Down", Self::knock_down),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Sinkhole", Self::sinkhole),
+        ]
+    }
 }
 impl DarkDugtrio6 {
-    pub fn knock_down(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn knock_down(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Colorless])
+            .flip_a_coin()
+            .if_heads(|e| e.damage(40))
+    }
+    pub fn sinkhole(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
     }
 }
 
@@ -226,10 +275,25 @@ impl CardArchetype for DarkGolbat7 {
             Attack::new("Flitter", Self::flitter),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Sneak Attack", Self::sneak_attack),
+        ]
+    }
+    fn on_play_from_hand(&self, in_play: &InPlayCard, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        Some(engine.trigger_poke_power(in_play, "Sneak Attack", dm))
+    }
 }
 impl DarkGolbat7 {
-    pub fn flitter(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn flitter(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .choose_opponents_pokemon(|e| e.damage_ignoring_weakness_and_resistance(20))
+    }
+    pub fn sneak_attack(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+            .choose_opponents_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -256,6 +320,11 @@ impl CardArchetype for DarkGyarados8 {
             Attack::new("Ice Beam", Self::ice_beam),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Final Beam", Self::final_beam),
+        ]
+    }
 }
 impl DarkGyarados8 {
     pub fn ice_beam(builder: AttackBuilder) -> AttackBuilder {
@@ -265,6 +334,10 @@ impl DarkGyarados8 {
             .damage(30)
             .if_heads(|e| e.paralyze())
     }
+    pub fn final_beam(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -298,8 +371,11 @@ impl DarkHypno9 {
             .attack_cost(&[Type::Psychic])
             .damage(20)
     }
-    pub fn bench_manipulation(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn bench_manipulation(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic])
+            .flip_coins_per_opponents_benched_pokemon()
+            .damage_per_tails(20)
     }
 }
 
@@ -334,8 +410,11 @@ impl DarkMachamp10 {
             .attack_cost(&[Type::Fighting, Type::Fighting])
             .damage(30)
     }
-    pub fn fling(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn fling(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Fighting])
+            .opponent_must_have_benched_pokemon()
+            .shuffle_defending_into_deck()
     }
 }
 
@@ -365,11 +444,16 @@ impl CardArchetype for DarkMagneton11 {
     }
 }
 impl DarkMagneton11 {
-    pub fn sonicboom(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn sonicboom(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning])
+            .damage_ignoring_weakness_and_resistance(20)
     }
-    pub fn magnetic_lines(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn magnetic_lines(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Colorless])
+            .damage(30)
+            .move_defending_basic_energy_to_opponents_bench()
     }
 }
 
@@ -396,6 +480,14 @@ impl CardArchetype for DarkSlowbro12 {
             Attack::new("Fickle Attack", Self::fickle_attack),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Reel In", Self::reel_in),
+        ]
+    }
+    fn on_play_from_hand(&self, in_play: &InPlayCard, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        Some(engine.trigger_poke_power(in_play, "Reel In", dm))
+    }
 }
 impl DarkSlowbro12 {
     pub fn fickle_attack(builder: AttackBuilder) -> AttackBuilder {
@@ -404,6 +496,11 @@ impl DarkSlowbro12 {
             .flip_a_coin()
             .if_heads(|e| e.damage(40))
     }
+    pub fn reel_in(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+            .pokemon_cards_from_discard_to_hand(3)
+    }
 }
 
 #[derive(Default)]
@@ -429,10 +526,23 @@ impl CardArchetype for DarkVileplume13 {
             Attack::new("Petal Whirlwind", Self::petal_whirlwind),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Hay Fever", Self::hay_fever),
+        ]
+    }
 }
 impl DarkVileplume13 {
-    pub fn petal_whirlwind(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn petal_whirlwind(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Grass])
+            .flip_coins(3)
+            .damage_per_heads(30)
+            .if_at_least_heads(2, |e| e.confuse_attacking())
+    }
+    pub fn hay_fever(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
     }
 }
 
@@ -462,11 +572,19 @@ impl CardArchetype for DarkWeezing14 {
     }
 }
 impl DarkWeezing14 {
-    pub fn mass_explosion(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mass_explosion(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Grass])
+            .damage_per_pokemon_in_play_named(20, &["Koffing", "Weezing", "Dark Weezing"])
+            .each_pokemon_in_play_named(&["Koffing", "Weezing", "Dark Weezing"], |e| e.damage_ignoring_weakness_and_resistance(20))
     }
-    pub fn stun_gas(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn stun_gas(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .damage(20)
+            .flip_a_coin()
+            .if_heads(|e| e.poison())
+            .if_tails(|e| e.paralyze())
     }
 }
 
@@ -496,11 +614,16 @@ impl CardArchetype for DarkAlakazam18 {
     }
 }
 impl DarkAlakazam18 {
-    pub fn teleport_blast(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn teleport_blast(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Colorless])
+            .damage(30)
+            .optionally_switch_attacking()
     }
-    pub fn mind_shock(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mind_shock(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Psychic])
+            .damage_ignoring_weakness_and_resistance(40)
     }
 }
 
@@ -530,11 +653,19 @@ impl CardArchetype for DarkArbok19 {
     }
 }
 impl DarkArbok19 {
-    pub fn stare(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn stare(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass])
+            .opponent_must_have_benched_pokemon()
+            .gust_defending()
+            .poison()
     }
-    pub fn poison_vapor(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn poison_vapor(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Colorless])
+            .damage(20)
+            .poison()
+            .each_opponents_bench(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -564,11 +695,18 @@ impl CardArchetype for DarkBlastoise20 {
     }
 }
 impl DarkBlastoise20 {
-    pub fn hydrocannon(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn hydrocannon(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Water])
+            .damage_plus_per_extra_energy_on_attacking(30, 20, Type::Water, 2)
     }
-    pub fn rocket_tackle(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn rocket_tackle(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water, Type::Water, Type::Water])
+            .damage(40)
+            .damage_self(10)
+            .flip_a_coin()
+            .if_heads(|e| e.prevent_damage_during_opponents_next_turn())
     }
 }
 
@@ -603,8 +741,12 @@ impl DarkCharizard21 {
             .attack_cost(&[Type::Colorless])
             .damage(10)
     }
-    pub fn continuous_fireball(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn continuous_fireball(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fire, Type::Fire])
+            .flip_coins_per_energy_card_on_attacking(Type::Fire)
+            .damage_per_heads(50)
+            .discard_attacking_energy_cards_per_heads(Type::Fire)
     }
 }
 
@@ -631,6 +773,14 @@ impl CardArchetype for DarkDragonite22 {
             Attack::new("Giant Tail", Self::giant_tail),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Summon Minions", Self::summon_minions),
+        ]
+    }
+    fn on_play_from_hand(&self, in_play: &InPlayCard, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        Some(engine.trigger_poke_power(in_play, "Summon Minions", dm))
+    }
 }
 impl DarkDragonite22 {
     pub fn giant_tail(builder: AttackBuilder) -> AttackBuilder {
@@ -639,6 +789,11 @@ impl DarkDragonite22 {
             .flip_a_coin()
             .if_heads(|e| e.damage(70))
     }
+    pub fn summon_minions(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+            .search_deck_to_bench(2, |e, c| e.stage(c) == Some(Stage::Basic))
+    }
 }
 
 #[derive(Default)]
@@ -664,10 +819,22 @@ impl CardArchetype for DarkDugtrio23 {
             Attack::new("Knock Down", Self::knock_down),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Sinkhole", Self::sinkhole),
+        ]
+    }
 }
 impl DarkDugtrio23 {
-    pub fn knock_down(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn knock_down(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Colorless])
+            .flip_a_coin()
+            .if_heads(|e| e.damage(40))
+    }
+    pub fn sinkhole(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
     }
 }
 
@@ -694,10 +861,25 @@ impl CardArchetype for DarkGolbat24 {
             Attack::new("Flitter", Self::flitter),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Sneak Attack", Self::sneak_attack),
+        ]
+    }
+    fn on_play_from_hand(&self, in_play: &InPlayCard, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        Some(engine.trigger_poke_power(in_play, "Sneak Attack", dm))
+    }
 }
 impl DarkGolbat24 {
-    pub fn flitter(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn flitter(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless, Type::Colorless])
+            .choose_opponents_pokemon(|e| e.damage_ignoring_weakness_and_resistance(20))
+    }
+    pub fn sneak_attack(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+            .choose_opponents_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
 }
 
@@ -724,6 +906,11 @@ impl CardArchetype for DarkGyarados25 {
             Attack::new("Ice Beam", Self::ice_beam),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Final Beam", Self::final_beam),
+        ]
+    }
 }
 impl DarkGyarados25 {
     pub fn ice_beam(builder: AttackBuilder) -> AttackBuilder {
@@ -733,6 +920,10 @@ impl DarkGyarados25 {
             .damage(30)
             .if_heads(|e| e.paralyze())
     }
+    pub fn final_beam(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -766,8 +957,11 @@ impl DarkHypno26 {
             .attack_cost(&[Type::Psychic])
             .damage(20)
     }
-    pub fn bench_manipulation(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn bench_manipulation(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic])
+            .flip_coins_per_opponents_benched_pokemon()
+            .damage_per_tails(20)
     }
 }
 
@@ -802,8 +996,11 @@ impl DarkMachamp27 {
             .attack_cost(&[Type::Fighting, Type::Fighting])
             .damage(30)
     }
-    pub fn fling(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn fling(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting, Type::Fighting])
+            .opponent_must_have_benched_pokemon()
+            .shuffle_defending_into_deck()
     }
 }
 
@@ -833,11 +1030,16 @@ impl CardArchetype for DarkMagneton28 {
     }
 }
 impl DarkMagneton28 {
-    pub fn sonicboom(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn sonicboom(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning])
+            .damage_ignoring_weakness_and_resistance(20)
     }
-    pub fn magnetic_lines(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn magnetic_lines(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Colorless])
+            .damage(30)
+            .move_defending_basic_energy_to_opponents_bench()
     }
 }
 
@@ -864,6 +1066,14 @@ impl CardArchetype for DarkSlowbro29 {
             Attack::new("Fickle Attack", Self::fickle_attack),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Reel In", Self::reel_in),
+        ]
+    }
+    fn on_play_from_hand(&self, in_play: &InPlayCard, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> Option<GameEngine> {
+        Some(engine.trigger_poke_power(in_play, "Reel In", dm))
+    }
 }
 impl DarkSlowbro29 {
     pub fn fickle_attack(builder: AttackBuilder) -> AttackBuilder {
@@ -872,6 +1082,11 @@ impl DarkSlowbro29 {
             .flip_a_coin()
             .if_heads(|e| e.damage(40))
     }
+    pub fn reel_in(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+            .pokemon_cards_from_discard_to_hand(3)
+    }
 }
 
 #[derive(Default)]
@@ -897,10 +1112,23 @@ impl CardArchetype for DarkVileplume30 {
             Attack::new("Petal Whirlwind", Self::petal_whirlwind),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Hay Fever", Self::hay_fever),
+        ]
+    }
 }
 impl DarkVileplume30 {
-    pub fn petal_whirlwind(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn petal_whirlwind(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Grass])
+            .flip_coins(3)
+            .damage_per_heads(30)
+            .if_at_least_heads(2, |e| e.confuse_attacking())
+    }
+    pub fn hay_fever(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
     }
 }
 
@@ -930,11 +1158,19 @@ impl CardArchetype for DarkWeezing31 {
     }
 }
 impl DarkWeezing31 {
-    pub fn mass_explosion(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mass_explosion(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass, Type::Grass])
+            .damage_per_pokemon_in_play_named(20, &["Koffing", "Weezing", "Dark Weezing"])
+            .each_pokemon_in_play_named(&["Koffing", "Weezing", "Dark Weezing"], |e| e.damage_ignoring_weakness_and_resistance(20))
     }
-    pub fn stun_gas(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn stun_gas(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .damage(20)
+            .flip_a_coin()
+            .if_heads(|e| e.poison())
+            .if_tails(|e| e.paralyze())
     }
 }
 
@@ -969,8 +1205,11 @@ impl DarkCharmeleon32 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn fireball(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn fireball(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fire, Type::Fire])
+            .flip_a_coin()
+            .if_heads(|e| e.damage(70).discard_attacking_energy_cards(&[Type::Fire]))
     }
 }
 
@@ -997,6 +1236,11 @@ impl CardArchetype for DarkDragonair33 {
             Attack::new("Tail Strike", Self::tail_strike),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Evolutionary Light", Self::evolutionary_light),
+        ]
+    }
 }
 impl DarkDragonair33 {
     pub fn tail_strike(builder: AttackBuilder) -> AttackBuilder {
@@ -1006,6 +1250,12 @@ impl DarkDragonair33 {
             .if_heads(|e| e.damage(40))
             .if_tails(|e| e.damage(20))
     }
+    pub fn evolutionary_light(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .once_per_turn()
+            .search_deck_to_hand(1, |e, c| e.is_pokemon(c) && e.stage(c) != Some(Stage::Basic))
+    }
 }
 
 #[derive(Default)]
@@ -1039,8 +1289,12 @@ impl DarkElectrode34 {
             .attack_cost(&[Type::Colorless])
             .damage(10)
     }
-    pub fn energy_bomb(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn energy_bomb(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Lightning])
+            .must_have_benched_pokemon()
+            .damage(60)
+            .move_attacking_energy_cards_to_own_bench()
     }
 }
 
@@ -1070,11 +1324,18 @@ impl CardArchetype for DarkFlareon35 {
     }
 }
 impl DarkFlareon35 {
-    pub fn rage(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn rage(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless])
+            .damage_plus_per_damage_counter_on_itself(10, 10)
     }
-    pub fn playing_with_fire(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn playing_with_fire(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fire, Type::Colorless])
+            .discard_must_contain_energy_card(Type::Fire)
+            .flip_a_coin()
+            .if_heads(|e| e.damage(50))
+            .if_tails(|e| e.damage(30))
     }
 }
 
@@ -1101,6 +1362,11 @@ impl CardArchetype for DarkGloom36 {
             Attack::new("Poisonpowder", Self::poisonpowder),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Pollen Stench", Self::pollen_stench),
+        ]
+    }
 }
 impl DarkGloom36 {
     pub fn poisonpowder(builder: AttackBuilder) -> AttackBuilder {
@@ -1109,6 +1375,14 @@ impl DarkGloom36 {
             .damage(10)
             .poison()
     }
+    pub fn pollen_stench(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .once_per_turn()
+            .flip_a_coin()
+            .if_heads(|e| e.confuse_opponents_active())
+            .if_tails(|e| e.confuse_own_active())
+    }
 }
 
 #[derive(Default)]
@@ -1137,8 +1411,11 @@ impl CardArchetype for DarkGolduck37 {
     }
 }
 impl DarkGolduck37 {
-    pub fn third_eye(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn third_eye(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic])
+            .cost(|e| e.discard_attacking_energy_cards(&[Type::Any]))
+            .draw(3)
     }
     pub fn super_psy(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1178,8 +1455,13 @@ impl DarkJolteon38 {
             .attack_cost(&[Type::Lightning])
             .prevent_attack_on_a_flip_during_opponents_next_turn()
     }
-    pub fn thunder_attack(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn thunder_attack(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Lightning, Type::Colorless])
+            .damage(40)
+            .flip_a_coin()
+            .if_heads(|e| e.paralyze())
+            .if_tails(|e| e.damage_self(10))
     }
 }
 
@@ -1206,10 +1488,23 @@ impl CardArchetype for DarkKadabra39 {
             Attack::new("Mind Shock", Self::mind_shock),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Matter Exchange", Self::matter_exchange),
+        ]
+    }
 }
 impl DarkKadabra39 {
-    pub fn mind_shock(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mind_shock(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic, Type::Psychic, Type::Colorless])
+            .damage_ignoring_weakness_and_resistance(30)
+    }
+    pub fn matter_exchange(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .disabled_under_special_conditions()
+            .once_per_turn()
+            .discard_from_hand_to_draw()
     }
 }
 
@@ -1239,8 +1534,11 @@ impl CardArchetype for DarkMachoke40 {
     }
 }
 impl DarkMachoke40 {
-    pub fn drag_off(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn drag_off(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting])
+            .gust_defending()
+            .damage(20)
     }
     pub fn knock_back(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1273,6 +1571,11 @@ impl CardArchetype for DarkMuk41 {
             Attack::new("Sludge Punch", Self::sludge_punch),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Sticky Goo", Self::sticky_goo),
+        ]
+    }
 }
 impl DarkMuk41 {
     pub fn sludge_punch(builder: AttackBuilder) -> AttackBuilder {
@@ -1281,6 +1584,10 @@ impl DarkMuk41 {
             .damage(20)
             .poison()
     }
+    pub fn sticky_goo(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
 }
 
 #[derive(Default)]
@@ -1309,8 +1616,12 @@ impl CardArchetype for DarkPersian42 {
     }
 }
 impl DarkPersian42 {
-    pub fn fascinate(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn fascinate(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .opponent_must_have_benched_pokemon()
+            .flip_a_coin()
+            .if_heads(|e| e.gust_defending())
     }
     pub fn poison_claws(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1344,10 +1655,22 @@ impl CardArchetype for DarkPrimeape43 {
             Attack::new("Frenzied Attack", Self::frenzied_attack),
         ]
     }
+    fn poke_powers(&self) -> Vec<Attack> {
+        vec![
+            Attack::new("Frenzy", Self::frenzy),
+        ]
+    }
 }
 impl DarkPrimeape43 {
-    pub fn frenzied_attack(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn frenzy(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .passive()
+    }
+    pub fn frenzied_attack(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting, Type::Fighting])
+            .damage(40)
+            .confuse_attacking()
     }
 }
 
@@ -1382,8 +1705,11 @@ impl DarkRapidash44 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn flame_pillar(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn flame_pillar(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fire, Type::Fire])
+            .damage(30)
+            .if_discards_attacking_energy_card(Type::Fire, |e| e.choose_opponents_benched_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10)))
     }
 }
 
@@ -1458,8 +1784,10 @@ impl DarkWartortle46 {
             .flip_coins(2)
             .damage_per_heads(10)
     }
-    pub fn mirror_shell(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mirror_shell(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .reflect_damage_during_opponents_next_turn()
     }
 }
 
@@ -1494,8 +1822,10 @@ impl Magikarp47 {
             .attack_cost(&[Type::Colorless])
             .damage(10)
     }
-    pub fn rapid_evolution(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn rapid_evolution(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .evolve_attacking_from_deck(&["Gyarados", "Dark Gyarados"])
     }
 }
 
@@ -1565,8 +1895,10 @@ impl CardArchetype for Abra49 {
     }
 }
 impl Abra49 {
-    pub fn vanish(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn vanish(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic])
+            .shuffle_attacking_into_deck()
     }
     pub fn psyshock(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1674,8 +2006,10 @@ impl CardArchetype for Diglett52 {
     }
 }
 impl Diglett52 {
-    pub fn dig_under(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn dig_under(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Fighting])
+            .choose_opponents_pokemon(|e| e.damage_ignoring_weakness_and_resistance(10))
     }
     pub fn scratch(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -1860,8 +2194,12 @@ impl Grimer57 {
             .attack_cost(&[Type::Grass])
             .asleep()
     }
-    pub fn sticky_hands(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn sticky_hands(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Grass, Type::Grass])
+            .flip_a_coin()
+            .if_heads(|e| e.damage(30).paralyze())
+            .if_tails(|e| e.damage(10))
     }
 }
 
@@ -1974,8 +2312,10 @@ impl Magnemite60 {
             .attack_cost(&[Type::Colorless, Type::Colorless])
             .damage(20)
     }
-    pub fn magnetism(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn magnetism(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Lightning, Type::Colorless])
+            .damage_plus_per_own_benched_pokemon_named(10, 10, &["Magnemite", "Magneton", "Dark Magneton"])
     }
 }
 
@@ -2005,8 +2345,10 @@ impl CardArchetype for Mankey61 {
     }
 }
 impl Mankey61 {
-    pub fn mischief(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn mischief(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless])
+            .shuffle_opponents_deck()
     }
     pub fn anger(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -2042,8 +2384,10 @@ impl CardArchetype for Meowth62 {
     }
 }
 impl Meowth62 {
-    pub fn coin_hurl(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn coin_hurl(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Colorless, Type::Colorless])
+            .choose_opponents_pokemon(|e| e.flip_a_coin().if_heads(|e| e.damage_ignoring_weakness_and_resistance(20)))
     }
 }
 
@@ -2149,8 +2493,10 @@ impl Psyduck65 {
             .attack_cost(&[Type::Psychic])
             .draw(1)
     }
-    pub fn water_gun(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn water_gun(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Water])
+            .damage_plus_per_extra_energy_on_attacking(10, 10, Type::Water, 2)
     }
 }
 
@@ -2214,8 +2560,10 @@ impl CardArchetype for Slowpoke67 {
     }
 }
 impl Slowpoke67 {
-    pub fn afternoon_nap(_builder: AttackBuilder) -> AttackBuilder {
-        unimplemented!();
+    pub fn afternoon_nap(builder: AttackBuilder) -> AttackBuilder {
+        builder
+            .attack_cost(&[Type::Psychic])
+            .attach_energy_from_deck_to_attacking(Type::Psychic)
     }
     pub fn headbutt(builder: AttackBuilder) -> AttackBuilder {
         builder
@@ -0,0 +1,235 @@
+use crate::state::*;
+use crate::engine::*;
+use crate::*;
+use crate::carddb::TrainerCardArchetype;
+use crate::custom_effects::BlockPokemonPowers;
+use crate::effect::CustomEffect;
+
+#[derive(Default)]
+pub struct HereComesTeamRocket15 {}
+impl TrainerCardArchetype for HereComesTeamRocket15 {
+    identifier!("Here Comes Team Rocket! (TR 15)");
+    card_name!("Here Comes Team Rocket!");
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        HereComesTeamRocket71::play(player, engine)
+    }
+}
+
+#[derive(Default)]
+pub struct RocketsSneakAttack16 {}
+impl TrainerCardArchetype for RocketsSneakAttack16 {
+    identifier!("Rocket's Sneak Attack (TR 16)");
+    card_name!("Rocket's Sneak Attack");
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        RocketsSneakAttack72::play(player, engine, dm)
+    }
+}
+
+#[derive(Default)]
+pub struct HereComesTeamRocket71 {}
+impl TrainerCardArchetype for HereComesTeamRocket71 {
+    identifier!("Here Comes Team Rocket! (TR 71)");
+    card_name!("Here Comes Team Rocket!");
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        Self::play(player, engine)
+    }
+}
+impl HereComesTeamRocket71 {
+    pub fn play(player: Player, engine: &GameEngine) -> GameEngine {
+        engine
+            .reveal_prizes(player)
+            .reveal_prizes(player.opponent())
+    }
+}
+
+#[derive(Default)]
+pub struct RocketsSneakAttack72 {}
+impl TrainerCardArchetype for RocketsSneakAttack72 {
+    identifier!("Rocket's Sneak Attack (TR 72)");
+    card_name!("Rocket's Sneak Attack");
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        Self::play(player, engine, dm)
+    }
+}
+impl RocketsSneakAttack72 {
+    pub fn play(player: Player, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        // TODO: reveal the opponent's hand
+        let trainers = engine.state.side(player.opponent()).hand.iter().filter(|c| engine.is_trainer(c)).cloned().collect::<Vec<_>>();
+        if trainers.is_empty() {
+            return engine.clone();
+        }
+
+        let chosen = dm.pick_from_hand(player, player.opponent(), 1, &trainers);
+
+        engine.shuffle_from_hand_into_deck(player.opponent(), chosen[0])
+    }
+}
+
+#[derive(Default)]
+pub struct TheBossSWay73 {}
+impl TrainerCardArchetype for TheBossSWay73 {
+    identifier!("The Boss's Way (TR 73)");
+    card_name!("The Boss's Way");
+
+    fn cost(&self, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine.ensure_deck_not_empty(engine.player())
+    }
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        self
+            .cost(engine, dm)
+            .search_deck_to_hand(player, 1, |e, c| e.is_dark_pokemon(c) && e.stage(c) != Some(Stage::Basic), dm)
+    }
+}
+
+#[derive(Default)]
+pub struct Challenge74 {}
+impl TrainerCardArchetype for Challenge74 {
+    identifier!("Challenge! (TR 74)");
+    card_name!("Challenge!");
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let both_benches_full = !engine.has_bench_space(player) && !engine.has_bench_space(player.opponent());
+        if both_benches_full || !dm.accept_challenge(player.opponent()) {
+            return engine.draw(player, 2, dm);
+        }
+
+        // TODO: the cards go down face down and are only revealed once both players are done
+        let mut engine = engine.clone();
+        for searcher in [player, player.opponent()] {
+            engine = Self::bench_basics_from_deck(searcher, &engine, dm);
+        }
+
+        engine
+            .shuffle_deck(player)
+            .shuffle_deck(player.opponent())
+    }
+}
+impl Challenge74 {
+    fn bench_basics_from_deck(player: Player, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let space = engine.bench_size(player).saturating_sub(engine.bench(player).len());
+        if space == 0 {
+            return engine.clone();
+        }
+
+        let basics = engine.state.side(player).deck.cards().into_iter().filter(|c| engine.stage(c) == Some(Stage::Basic)).collect::<Vec<_>>();
+
+        // TODO: "any number", but players always fill as much of their bench as they can for now
+        let chosen = dm.search_deck_cards(player, player, space.min(basics.len()), &basics);
+
+        let mut engine = engine.clone();
+        for card in chosen {
+            engine = engine.bench_from_deck(player, card);
+        }
+
+        engine
+    }
+}
+
+#[derive(Default)]
+pub struct Digger75 {}
+impl TrainerCardArchetype for Digger75 {
+    identifier!("Digger (TR 75)");
+    card_name!("Digger");
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let (engine, flips) = engine.flip(player, 1, dm);
+        if flips.is_tails() {
+            let active = engine.state.side(player).active[0].clone();
+            return engine.put_damage_counters(&active, 1);
+        }
+
+        let (engine, flips) = engine.flip(player.opponent(), 1, dm);
+        if flips.is_tails() {
+            let active = engine.state.side(player.opponent()).active[0].clone();
+            return engine.put_damage_counters(&active, 1);
+        }
+
+        engine
+    }
+}
+
+#[derive(Default)]
+pub struct ImpostorOaksRevenge76 {}
+impl TrainerCardArchetype for ImpostorOaksRevenge76 {
+    identifier!("Impostor Oak's Revenge (TR 76)");
+    card_name!("Impostor Oak's Revenge");
+
+    fn cost(&self, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine
+            .ensure_discard_other(engine.player(), 1, dm)
+    }
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        self
+            .cost(engine, dm)
+            .shuffle_hand_into_deck(player.opponent(), dm)
+            .draw(player.opponent(), 4, dm)
+    }
+}
+
+#[derive(Default)]
+pub struct NightlyGarbageRun77 {}
+impl TrainerCardArchetype for NightlyGarbageRun77 {
+    identifier!("Nightly Garbage Run (TR 77)");
+    card_name!("Nightly Garbage Run");
+
+    fn cost(&self, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine
+            .ensure_discard_contains(engine.player(), 1, Self::recyclable)
+    }
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let searchable_cards = engine.state.side(player).discard.iter().filter(|c| Self::recyclable(engine, c)).cloned().collect::<Vec<_>>();
+        let chosen = dm.pick_from_discard(player, player, searchable_cards.len().min(3), &searchable_cards);
+
+        // TODO: show the chosen cards to the opponent
+        self
+            .cost(engine, dm)
+            .shuffle_from_discard_into_deck(player, &chosen)
+    }
+}
+impl NightlyGarbageRun77 {
+    fn recyclable(engine: &GameEngine, card: &Card) -> bool {
+        engine.is_pokemon(card) || engine.is_basic_energy(card)
+    }
+}
+
+#[derive(Default)]
+pub struct GoopGasAttack78 {}
+impl TrainerCardArchetype for GoopGasAttack78 {
+    identifier!("Goop Gas Attack (TR 78)");
+    card_name!("Goop Gas Attack");
+
+    fn execute(&self, player: Player, card: &Card, engine: &GameEngine, _dm: &mut dyn DecisionMaker) -> GameEngine {
+        engine.with_effect(Effect {
+            source: EffectSource::Trainer(player, card.clone()),
+            target: EffectTarget::Player(player),
+            expires: EffectExpiration::EndOfTurn(player.opponent(), 0),
+            consequence: BlockPokemonPowers::identifier(),
+            parameters: vec![],
+            name: "Goop Gas Attack".into(),
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct Sleep79 {}
+impl TrainerCardArchetype for Sleep79 {
+    identifier!("Sleep! (TR 79)");
+    card_name!("Sleep!");
+
+    fn execute(&self, player: Player, _card: &Card, engine: &GameEngine, dm: &mut dyn DecisionMaker) -> GameEngine {
+        let (engine, flips) = engine.flip(player, 1, dm);
+        if flips.is_heads() {
+            let defending = engine.state.side(player.opponent()).active[0].clone();
+            engine.asleep(&defending)
+        } else {
+            engine
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use crate::state::*;
+use crate::engine::*;
+use crate::view::{ActionView, InPlayView, PlayerView, PrizeView};
+use crate::deck::IllegalDeck;
+
+// Builds the AI for one deck in one game, given the seat it plays from and a
+// seed for whatever randomness of its own it needs.
+pub type Contender = Box<dyn Fn(Player, u64) -> Box<dyn DecisionMaker> + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deck {
+    A,
+    B,
+}
+
+impl Deck {
+    pub fn other(&self) -> Self {
+        match self {
+            Deck::A => Deck::B,
+            Deck::B => Deck::A,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ending {
+    Won(Deck),
+    Tie,
+    TurnLimit,
+    Crashed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    pub seed: u64,
+    pub first: Deck,
+    pub ending: Ending,
+    pub turns: usize,
+    // Prizes taken by deck A minus those taken by deck B.
+    pub prize_differential: isize,
+}
+
+impl GameRecord {
+    pub fn winner(&self) -> Option<Deck> {
+        match self.ending {
+            Ending::Won(deck) => Some(deck),
+            _ => None,
+        }
+    }
+}
+
+// Plays a matchup between two decks over and over. Decks take turns sitting
+// as Player One, deck A does when game N is even, and the opening coin flip
+// decides who goes first from there. Each game gets its
+// own seed, derived from the simulation's, which decides the shuffles, the
+// coins and the seeds the contenders are built with, so a run can be repeated
+// exactly (given deterministic contenders) no matter how many threads it's
+// spread over.
+pub struct Simulation {
+    format: Box<dyn Fn() -> Box<dyn Format> + Sync>,
+    decks: [Vec<String>; 2],
+    contenders: [Contender; 2],
+    games: usize,
+    threads: usize,
+    seed: u64,
+    max_turns: usize,
+}
+
+impl Simulation {
+    pub fn new<F: Fn() -> Box<dyn Format> + Sync + 'static>(format: F, a: (&[String], Contender), b: (&[String], Contender)) -> Self {
+        Self {
+            format: Box::new(format),
+            decks: [a.0.to_vec(), b.0.to_vec()],
+            contenders: [a.1, b.1],
+            games: 1000,
+            threads: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            seed: 0,
+            max_turns: 200,
+        }
+    }
+
+    pub fn games(mut self, games: usize) -> Self {
+        self.games = games;
+        self
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    // Games still going after this many turns are given up on.
+    pub fn max_turns(mut self, max_turns: usize) -> Self {
+        self.max_turns = max_turns;
+        self
+    }
+
+    pub fn run(&self) -> Result<SimulationReport, IllegalDeck> {
+        GameState::initial(&self.decks[0], &self.decks[1], (self.format)().as_ref(), true)?;
+
+        let mut games = std::thread::scope(|scope| {
+            let workers = (0..self.threads).map(|thread| {
+                scope.spawn(move || {
+                    (thread..self.games).step_by(self.threads).map(|game| self.play(game)).collect::<Vec<_>>()
+                })
+            }).collect::<Vec<_>>();
+
+            workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect::<Vec<_>>()
+        });
+        games.sort_by_key(|(game, _)| *game);
+
+        Ok(SimulationReport { games: games.into_iter().map(|(_, record)| record).collect() })
+    }
+
+    fn play(&self, game: usize) -> (usize, GameRecord) {
+        let seed = StdRng::seed_from_u64(self.seed).gen::<u64>().wrapping_add(game as u64);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let one = if game.is_multiple_of(2) { Deck::A } else { Deck::B };
+
+        let seat = |deck: Deck| if deck == one { Player::One } else { Player::Two };
+        let index = |deck: Deck| match deck { Deck::A => 0, Deck::B => 1 };
+        let deck_of = |player: Player| if player == Player::One { one } else { one.other() };
+
+        let format = (self.format)();
+        let state = GameState::initial(&self.decks[index(one)], &self.decks[index(one.other())], format.as_ref(), true).unwrap();
+        let mut engine = GameEngine::from_state(state, format);
+
+        let (a, b) = (rng.gen(), rng.gen());
+        let mut players = [
+            (self.contenders[index(one)])(Player::One, if one == Deck::A { a } else { b }),
+            (self.contenders[index(one.other())])(Player::Two, if one == Deck::A { b } else { a }),
+        ];
+        let mut dm = Seats { players: &mut players, rng };
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            while !engine.is_finished() && engine.state.turns.len() <= self.max_turns {
+                engine = engine.step(&mut dm);
+            }
+        }));
+
+        // whoever went first in the original game, not in a Sudden Death one
+        let mut original = &engine.state;
+        while let Some(parent) = &original.parent {
+            original = parent;
+        }
+        let first = original.turns.first().map(|&player| deck_of(player)).unwrap_or(one);
+
+        let record = match result {
+            Ok(()) => {
+                let ending = match engine.state.stage {
+                    GameStage::Winner(player) => Ending::Won(deck_of(player)),
+                    GameStage::Tie => Ending::Tie,
+                    _ => Ending::TurnLimit,
+                };
+                let prizes_left = |deck: Deck| engine.state.side(seat(deck)).prizes.len() as isize;
+
+                GameRecord {
+                    seed,
+                    first,
+                    ending,
+                    turns: engine.state.turns.len(),
+                    prize_differential: prizes_left(Deck::B) - prizes_left(Deck::A),
+                }
+            },
+            Err(_) => GameRecord { seed, first, ending: Ending::Crashed, turns: 0, prize_differential: 0 },
+        };
+
+        (game, record)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationReport {
+    pub games: Vec<GameRecord>,
+}
+
+impl SimulationReport {
+    pub fn wins(&self, deck: Deck) -> usize {
+        self.games.iter().filter(|game| game.winner() == Some(deck)).count()
+    }
+
+    pub fn count(&self, ending: Ending) -> usize {
+        self.games.iter().filter(|game| game.ending == ending).count()
+    }
+
+    // Out of the games that got to an end: ties count as half a win for each
+    // deck, crashes and games cut short by the turn limit don't count at all.
+    pub fn decided(&self) -> usize {
+        self.games.len() - self.count(Ending::TurnLimit) - self.count(Ending::Crashed)
+    }
+
+    pub fn win_rate(&self, deck: Deck) -> f64 {
+        rate(self.wins(deck) as f64 + self.count(Ending::Tie) as f64 / 2.0, self.decided())
+    }
+
+    // 95% Wilson score interval around win_rate.
+    pub fn confidence_interval(&self, deck: Deck) -> (f64, f64) {
+        wilson(self.win_rate(deck), self.decided())
+    }
+
+    pub fn average_turns(&self) -> f64 {
+        let finished = self.finished_games().collect::<Vec<_>>();
+        rate(finished.iter().map(|game| game.turns as f64).sum(), finished.len())
+    }
+
+    // In prizes, from deck A's point of view.
+    pub fn average_prize_differential(&self) -> f64 {
+        let finished = self.finished_games().collect::<Vec<_>>();
+        rate(finished.iter().map(|game| game.prize_differential as f64).sum(), finished.len())
+    }
+
+    // How often whoever went first won, with its 95% interval. Anything above
+    // a half is an advantage to going first.
+    pub fn first_turn_win_rate(&self) -> (f64, (f64, f64)) {
+        let decided = self.finished_games().filter(|game| game.ending != Ending::Tie).collect::<Vec<_>>();
+        let won = decided.iter().filter(|game| game.winner() == Some(game.first)).count();
+        let p = rate(won as f64, decided.len());
+
+        (p, wilson(p, decided.len()))
+    }
+
+    fn finished_games(&self) -> impl Iterator<Item = &GameRecord> {
+        self.games.iter().filter(|game| matches!(game.ending, Ending::Won(_) | Ending::Tie))
+    }
+}
+
+impl std::fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        writeln!(f, "{} games, {} ties, {} over the turn limit, {} crashed", self.games.len(), self.count(Ending::Tie), self.count(Ending::TurnLimit), self.count(Ending::Crashed))?;
+        for deck in [Deck::A, Deck::B] {
+            let (low, high) = self.confidence_interval(deck);
+            writeln!(f, "deck {:?}: {} wins, {:.1}% (95% CI {:.1}%-{:.1}%)", deck, self.wins(deck), self.win_rate(deck) * 100.0, low * 100.0, high * 100.0)?;
+        }
+        let (first, (low, high)) = self.first_turn_win_rate();
+        writeln!(f, "going first wins {:.1}% (95% CI {:.1}%-{:.1}%)", first * 100.0, low * 100.0, high * 100.0)?;
+        writeln!(f, "{:.1} turns on average", self.average_turns())?;
+        write!(f, "{:+.2} prizes for deck A on average", self.average_prize_differential())
+    }
+}
+
+fn rate(total: f64, n: usize) -> f64 {
+    if n == 0 { 0.0 } else { total / n as f64 }
+}
+
+fn wilson(p: f64, n: usize) -> (f64, f64) {
+    if n == 0 {
+        return (0.0, 1.0);
+    }
+
+    let z = 1.96f64;
+    let n = n as f64;
+    let center = (p + z * z / (2.0 * n)) / (1.0 + z * z / n);
+    let margin = z * (p * (1.0 - p) / n + z * z / (4.0 * n * n)).sqrt() / (1.0 + z * z / n);
+
+    ((center - margin).max(0.0), (center + margin).min(1.0))
+}
+
+// Sends every decision to the AI of the player making it, and keeps coins and
+// shuffles to itself so neither AI gets to cheat at them.
+struct Seats<'a> {
+    players: &'a mut [Box<dyn DecisionMaker>; 2],
+    rng: StdRng,
+}
+
+impl Seats<'_> {
+    fn of(&mut self, player: Player) -> &mut dyn DecisionMaker {
+        match player {
+            Player::One => self.players[0].as_mut(),
+            Player::Two => self.players[1].as_mut(),
+        }
+    }
+}
+
+impl Shuffler for Seats<'_> {
+    fn random_card(&mut self, n: usize) -> usize {
+        self.rng.gen_range(0..n)
+    }
+}
+
+impl DecisionMaker for Seats<'_> {
+    fn observe(&mut self, view: &PlayerView) { self.of(view.player).observe(view) }
+    fn shuffler(&mut self) -> &mut dyn Shuffler { self }
+    fn flip(&mut self, number_of_coins: usize) -> Flips {
+        Flips::from_results((0..number_of_coins).map(|_| self.rng.gen()).collect())
+    }
+
+    fn confirm_setup_mulligan(&mut self, p: Player) { self.of(p).confirm_setup_mulligan(p) }
+    fn confirm_setup_active_or_mulligan(&mut self, p: Player, maybe: &Vec<Card>) -> SetupActiveSelection { self.of(p).confirm_setup_active_or_mulligan(p, maybe) }
+    fn confirm_setup_active(&mut self, p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card { self.of(p).confirm_setup_active(p, yes, maybe) }
+    fn confirm_mulligan_draw(&mut self, p: Player, upto: usize) -> usize { self.of(p).confirm_mulligan_draw(p, upto) }
+    fn confirm_setup_bench_selection(&mut self, p: Player, upto: usize, cards: &Vec<Card>) -> Vec<Card> { self.of(p).confirm_setup_bench_selection(p, upto, cards) }
+    fn confirm_going_first(&mut self, p: Player) -> bool { self.of(p).confirm_going_first(p) }
+    fn pick_type<'a>(&mut self, p: Player, types: &'a Vec<Type>) -> &'a Type { self.of(p).pick_type(p, types) }
+    fn pick_move_damage_counters<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) { self.of(p).pick_move_damage_counters(p, possibilities) }
+    fn pick_attach_from_hand<'a>(&mut self, p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) { self.of(p).pick_attach_from_hand(p, possibilities) }
+    fn pick_attack<'a>(&mut self, p: Player, attacks: &'a Vec<Attack>) -> &'a Attack { self.of(p).pick_attack(p, attacks) }
+    fn pick_action<'a>(&mut self, p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView { self.of(p).pick_action(p, actions) }
+    fn pick_stage<'a>(&mut self, p: Player, items: &'a Vec<Stage>) -> &'a Stage { self.of(p).pick_stage(p, items) }
+    fn pick_from_hand<'a>(&mut self, p: Player, whose: Player, how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> { self.of(p).pick_from_hand(p, whose, how_many, hand) }
+    fn pick_from_discard<'a>(&mut self, p: Player, whose: Player, how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.of(p).pick_from_discard(p, whose, how_many, searchable) }
+    fn pick_in_play<'a>(&mut self, p: Player, how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> { self.of(p).pick_in_play(p, how_many, searchable) }
+    fn pick_attached<'a>(&mut self, p: Player, how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> { self.of(p).pick_attached(p, how_many, searchable) }
+    fn pick_energy_discard<'a>(&mut self, p: Player, cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> { self.of(p).pick_energy_discard(p, cost, options) }
+    fn pick_from_prizes<'a>(&mut self, who: Player, whose: Player, how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> { self.of(who).pick_from_prizes(who, whose, how_many, searchable) }
+    fn search_deck<'a>(&mut self, p: Player, whose: Player, how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> { self.of(p).search_deck(p, whose, how_many, deck) }
+    fn rearrange<'a>(&mut self, p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> { self.of(p).rearrange(p, cards) }
+    fn accept_challenge(&mut self, p: Player) -> bool { self.of(p).accept_challenge(p) }
+    fn pick_player<'a>(&mut self, p: Player, players: &'a [Player]) -> &'a Player { self.of(p).pick_player(p, players) }
+}
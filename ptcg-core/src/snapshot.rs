@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use crate::state::*;
+use crate::engine::*;
+use crate::formats;
+
+// Bump this whenever the serialized shape of the state changes. Older
+// snapshots are rejected instead of being half-loaded.
+pub const SNAPSHOT_VERSION: u32 = 4;
+
+const BINARY_MAGIC: &[u8; 4] = b"PTCG";
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Binary(bincode::Error),
+    NotASnapshot,
+    UnsupportedVersion(u32),
+    UnknownFormat(String),
+    FormatMismatch(String, String),
+    RulesMismatch(String),
+    UnknownAttack(String, String),
+    UnknownEffect(String),
+    UnusedDecisions(usize),
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            SnapshotError::Io(err) => write!(f, "couldn't read or write snapshot: {}", err),
+            SnapshotError::Json(err) => write!(f, "invalid json snapshot: {}", err),
+            SnapshotError::Binary(err) => write!(f, "invalid binary snapshot: {}", err),
+            SnapshotError::NotASnapshot => write!(f, "not a snapshot"),
+            SnapshotError::UnsupportedVersion(version) => write!(f, "unsupported snapshot version {} (expected {})", version, SNAPSHOT_VERSION),
+            SnapshotError::UnknownFormat(name) => write!(f, "unknown format {}", name),
+            SnapshotError::FormatMismatch(saved, given) => write!(f, "snapshot was saved with format {} but {} was given", saved, given),
+            SnapshotError::RulesMismatch(format) => write!(f, "snapshot was saved with other rules than the {} format given", format),
+            SnapshotError::UnknownAttack(card, attack) => write!(f, "{} has no attack or power named {}", card, attack),
+            SnapshotError::UnknownEffect(effect) => write!(f, "unknown effect {}", effect),
+            SnapshotError::UnusedDecisions(left) => write!(f, "replay finished with {} recorded decisions left over", left),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl From<std::io::Error> for SnapshotError {
+    fn from(err: std::io::Error) -> Self {
+        SnapshotError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SnapshotError {
+    fn from(err: serde_json::Error) -> Self {
+        SnapshotError::Json(err)
+    }
+}
+
+impl From<bincode::Error> for SnapshotError {
+    fn from(err: bincode::Error) -> Self {
+        SnapshotError::Binary(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Json,
+    Binary,
+}
+
+// Attacks hold a function pointer, so actions are stored with the attack name
+// and resolved back against the card's archetype when loading.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum SnapshotAction {
+    Pass,
+    TrainerFromHand(Player, Card),
+    AttachFromHand(Player, Card),
+    BenchFromHand(Player, Card),
+    EvolveFromHand(Player, Card),
+    Attack(Player, InPlayCard, String),
+    PokePower(Player, InPlayCard, String),
+    Retreat(Player, InPlayCard),
+}
+
+impl SnapshotAction {
+    pub fn from_action(action: &Action) -> Self {
+        match action {
+            Action::Pass => SnapshotAction::Pass,
+            Action::TrainerFromHand(player, card) => SnapshotAction::TrainerFromHand(*player, card.clone()),
+            Action::AttachFromHand(player, card) => SnapshotAction::AttachFromHand(*player, card.clone()),
+            Action::BenchFromHand(player, card) => SnapshotAction::BenchFromHand(*player, card.clone()),
+            Action::EvolveFromHand(player, card) => SnapshotAction::EvolveFromHand(*player, card.clone()),
+            Action::Attack(player, in_play, attack) => SnapshotAction::Attack(*player, in_play.clone(), attack.name().clone()),
+            Action::PokePower(player, in_play, attack) => SnapshotAction::PokePower(*player, in_play.clone(), attack.name().clone()),
+            Action::Retreat(player, in_play) => SnapshotAction::Retreat(*player, in_play.clone()),
+        }
+    }
+
+    pub fn to_action(&self, format: &dyn Format) -> Result<Action, SnapshotError> {
+        Ok(match self {
+            SnapshotAction::Pass => Action::Pass,
+            SnapshotAction::TrainerFromHand(player, card) => Action::TrainerFromHand(*player, card.clone()),
+            SnapshotAction::AttachFromHand(player, card) => Action::AttachFromHand(*player, card.clone()),
+            SnapshotAction::BenchFromHand(player, card) => Action::BenchFromHand(*player, card.clone()),
+            SnapshotAction::EvolveFromHand(player, card) => Action::EvolveFromHand(*player, card.clone()),
+            SnapshotAction::Attack(player, in_play, name) => {
+                let attacks = format.behavior(in_play.stack[0].card()).attacks();
+                Action::Attack(*player, in_play.clone(), Self::find(attacks, in_play, name)?)
+            },
+            SnapshotAction::PokePower(player, in_play, name) => {
+                let poke_powers = format.behavior(in_play.stack[0].card()).poke_powers();
+                Action::PokePower(*player, in_play.clone(), Self::find(poke_powers, in_play, name)?)
+            },
+            SnapshotAction::Retreat(player, in_play) => Action::Retreat(*player, in_play.clone()),
+        })
+    }
+
+    fn find(attacks: Vec<Attack>, in_play: &InPlayCard, name: &String) -> Result<Attack, SnapshotError> {
+        attacks
+            .into_iter()
+            .find(|attack| attack.name() == name)
+            .ok_or_else(|| SnapshotError::UnknownAttack(in_play.stack[0].card().archetype.clone(), name.clone()))
+    }
+}
+
+// Everything needed to resume a game, except for the format itself, which is
+// stored by name and rules and re-attached when restoring.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u32,
+    pub format: String,
+    pub rules: Rules,
+    pub state: GameState,
+    pub resolving_actions: Vec<SnapshotAction>,
+    pub attack_target_stack: Vec<(InPlayID, InPlayID)>,
+    pub good: bool,
+    pub prize_queue: Vec<PrizeAward>,
+}
+
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    version: u32,
+}
+
+impl Snapshot {
+    pub fn restore(&self) -> Result<GameEngine, SnapshotError> {
+        let format = formats::by_name_with_rules(&self.format, &self.rules)
+            .ok_or_else(|| SnapshotError::UnknownFormat(self.format.clone()))?;
+
+        GameEngine::from_snapshot(self, format)
+    }
+
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        let header = serde_json::from_str::<SnapshotHeader>(json)?;
+        if header.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(header.version));
+        }
+
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_binary(&self) -> Result<Vec<u8>, SnapshotError> {
+        let mut bytes = BINARY_MAGIC.to_vec();
+        bytes.extend(SNAPSHOT_VERSION.to_le_bytes());
+        bytes.extend(bincode::serialize(self)?);
+
+        Ok(bytes)
+    }
+
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        if bytes.len() < 8 || &bytes[0..4] != BINARY_MAGIC {
+            return Err(SnapshotError::NotASnapshot);
+        }
+
+        let version = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        Ok(bincode::deserialize(&bytes[8..])?)
+    }
+
+    pub fn save(&self, path: &std::path::Path, encoding: Encoding) -> Result<(), SnapshotError> {
+        let bytes = match encoding {
+            Encoding::Json => self.to_json()?.into_bytes(),
+            Encoding::Binary => self.to_binary()?,
+        };
+
+        Ok(std::fs::write(path, bytes)?)
+    }
+
+    // The encoding is detected from the file contents.
+    pub fn load(path: &std::path::Path) -> Result<Self, SnapshotError> {
+        let bytes = std::fs::read(path)?;
+
+        if bytes.starts_with(BINARY_MAGIC) {
+            Self::from_binary(&bytes)
+        } else {
+            Self::from_json(std::str::from_utf8(&bytes).map_err(|_| SnapshotError::NotASnapshot)?)
+        }
+    }
+}
@@ -1,4 +1,9 @@
-#[derive(Clone, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+use crate::engine::Format;
+use crate::deck::IllegalDeck;
+use crate::events::{GameEvent, SpecialCondition};
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Card {
     pub owner: Player,
     pub in_game_id: usize,
@@ -13,14 +18,14 @@ impl std::fmt::Debug for Card {
 
 pub type InPlayID = usize;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Player {
     #[default]
     One,
     Two,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Type {
     Fighting,
     Fire,
@@ -45,7 +50,7 @@ impl Player {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RotationalStatus {
     #[default]
     None,
@@ -54,7 +59,7 @@ pub enum RotationalStatus {
     Paralyzed,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Zone {
     Unknown,
     Hand(Player),
@@ -66,7 +71,7 @@ pub enum Zone {
     WorkingArea(Player),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Poison {
     pub counters: usize,
 }
@@ -76,7 +81,7 @@ pub trait Shuffler {
 }
 
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum DeckSlice {
     Shuffled(Vec<Card>),
     Ordered(Vec<Card>),
@@ -122,13 +127,17 @@ impl DeckSlice {
     }
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Deck {
     slices: Vec<DeckSlice>,
 }
 
 impl Deck {
     pub fn new(cards: &[Card]) -> Self {
+        if cards.is_empty() {
+            return Self::default();
+        }
+
         Self {
             slices: vec![DeckSlice::Ordered(cards.into())],
         }
@@ -232,7 +241,7 @@ impl Deck {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FaceCard {
     Up(Card),
     Down(Card),
@@ -268,7 +277,7 @@ impl FaceCard {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AttachedCard {
     pub card: FaceCard,
     pub attached_turn: usize,
@@ -288,7 +297,7 @@ impl AttachedCard {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InPlayCard {
     pub id: InPlayID,
     pub owner: Player,
@@ -321,13 +330,13 @@ impl InPlayCard {
 
 type PrizeCardID = usize;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PrizeCard {
     pub id: PrizeCardID,
     pub card: FaceCard,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PlayerSide {
     pub owner: Player,
     pub deck: Deck,
@@ -343,6 +352,7 @@ pub struct PlayerSide {
     pub supporter: Option<Card>,
     pub working_area: Vec<Card>,
     pub manual_attachments_this_turn: usize,
+    pub retreats_this_turn: usize,
 }
 
 impl PlayerSide {
@@ -362,6 +372,7 @@ impl PlayerSide {
             supporter: None,
             working_area: vec![],
             manual_attachments_this_turn: 0,
+            retreats_this_turn: 0,
         }
     }
 
@@ -458,7 +469,7 @@ impl PlayerSide {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameStage {
     Uninitialized,
     StartOfTurn(Player),
@@ -467,10 +478,12 @@ pub enum GameStage {
     PokemonCheckup(Player),
     Winner(Player),
     Tie,
+    // both players won at the same time, the next step starts a Sudden Death game.
+    SuddenDeath,
 }
 
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EffectTarget {
     Player(Player),
     InPlayPokemon(Player, InPlayID),
@@ -494,7 +507,7 @@ impl EffectTarget {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum EffectSource {
     Ability(Player, InPlayID),
     Attack(Player, InPlayID),
@@ -502,7 +515,7 @@ pub enum EffectSource {
     Energy(Player, Card),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum EffectExpiration {
     DefendingPokemon, // ends on: switching, retreating, evolving, zone moving
     RestOfTheGame, // thanks ADP
@@ -511,7 +524,7 @@ pub enum EffectExpiration {
 
 pub type EffectConsequence = String;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum EffectParameter {
     Type(Type),
     String(String),
@@ -541,7 +554,7 @@ impl EffectParameter {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Effect {
     pub source: EffectSource,
     pub target: EffectTarget,
@@ -577,7 +590,7 @@ impl Effect {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub p1: PlayerSide,
     pub p2: PlayerSide,
@@ -589,10 +602,32 @@ pub struct GameState {
 
     // effects
     pub effects: Vec<Effect>,
+
+    // the game this one is a Sudden Death of, if any
+    pub parent: Option<Box<GameState>>,
+
+    // what happened during the current step, not part of snapshots
+    #[serde(skip)]
+    pub events: Vec<GameEvent>,
 }
 
 impl GameState {
-    pub fn initial(a: &[String], b: &[String]) -> Self {
+    // Casual games let anything through as long as it can be played, see
+    // DeckReport::is_playable.
+    pub fn initial(a: &[String], b: &[String], format: &dyn Format, casual: bool) -> Result<Self, IllegalDeck> {
+        for (player, deck) in [(Player::One, a), (Player::Two, b)] {
+            let report = format.validate_deck(deck);
+            let ok = if casual { report.is_playable() } else { report.is_legal() };
+
+            if !ok {
+                return Err(IllegalDeck { player, report });
+            }
+        }
+
+        Ok(Self::unchecked(a, b))
+    }
+
+    pub(crate) fn unchecked(a: &[String], b: &[String]) -> Self {
         Self {
             p1: PlayerSide::new(a, Player::One, 0),
             p2: PlayerSide::new(b, Player::Two, a.len()),
@@ -600,6 +635,43 @@ impl GameState {
             turn: 0,
             turns: vec![],
             effects: vec![],
+            parent: None,
+            events: vec![],
+        }
+    }
+
+    // Sudden Death: a new game with the same decks and a single prize card
+    // each, which remembers the game it came from.
+    pub fn sudden_death(&self) -> Self {
+        let deck = |player: Player| {
+            let mut cards = self.all_cards().into_iter().filter(|card| card.owner == player).collect::<Vec<_>>();
+            cards.sort_by_key(|card| card.in_game_id);
+            cards.into_iter().map(|card| card.archetype).collect::<Vec<_>>()
+        };
+
+        let mut parent = self.clone();
+        parent.events.clear();
+
+        Self {
+            parent: Some(Box::new(parent)),
+            events: self.events.clone(),
+            ..Self::unchecked(&deck(Player::One), &deck(Player::Two))
+        }
+    }
+
+    pub fn prize_count(&self) -> usize {
+        if self.parent.is_some() { 1 } else { 6 }
+    }
+
+    pub fn with_event(mut self, event: GameEvent) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    pub fn without_events(&self) -> Self {
+        Self {
+            events: vec![],
+            ..self.clone()
         }
     }
 
@@ -684,9 +756,13 @@ impl GameState {
 
         let (deck, card) = side.deck.draw(dm);
         let mut hand = side.hand.clone();
-        if let Some(card) = card { hand.push(card); }
+        if let Some(card) = &card { hand.push(card.clone()); }
 
-        self.with_player_side(PlayerSide { deck, hand, ..side.clone() })
+        let state = self.with_player_side(PlayerSide { deck, hand, ..side.clone() });
+        match card {
+            Some(card) => state.with_event(GameEvent::CardDrawn { card }),
+            None => state,
+        }
     }
 
     pub fn draw_to_working_area(&self, player: Player, dm: &mut dyn Shuffler) -> Self {
@@ -701,6 +777,18 @@ impl GameState {
         self.with_player_side(side)
     }
 
+    pub fn discard_top_of_deck(&self, player: Player, dm: &mut dyn Shuffler) -> Self {
+        let mut side = self.side(player).clone();
+        let (deck, card) = side.deck.draw(dm);
+
+        if let Some(card) = card {
+            side.discard.push(card);
+            side.deck = deck;
+        }
+
+        self.with_player_side(side)
+    }
+
     pub fn put_working_area_on_top_of_deck(&self, player: Player) -> Self {
         let mut side = self.side(player).clone();
 
@@ -736,7 +824,7 @@ impl GameState {
         let prize = side.prizes.remove(p);
         side.hand.push(prize.card.card().clone());
 
-        self.with_player_side(side)
+        self.with_player_side(side).with_event(GameEvent::PrizeTaken { card: prize.card.card().clone() })
     }
 
     pub fn draw_n_to_hand(&self, player: Player, n: usize, dm: &mut dyn Shuffler) -> Self {
@@ -781,6 +869,14 @@ impl GameState {
         self.with_player_side(side)
     }
 
+    // Counted when the retreat cost is paid, whether the Pokémon gets away or not.
+    pub fn count_retreat(&self, player: Player) -> Self {
+        let mut side = self.side(player).clone();
+        side.retreats_this_turn += 1;
+
+        self.with_player_side(side)
+    }
+
     pub fn manual_attach_from_hand(&self, player: Player, card: &Card, target: &InPlayCard) -> Self {
         let mut side = self.side(player).clone();
         side.manual_attachments_this_turn += 1;
@@ -798,7 +894,7 @@ impl GameState {
 
         side.in_play_mut(&target.id).unwrap().attached.push(AttachedCard { card: FaceCard::Up(card.clone()), attached_turn: self.turn });
 
-        self.with_player_side(side)
+        self.with_player_side(side).with_event(GameEvent::EnergyAttached { card: card.clone(), player: target.owner, pokemon: target.id })
     }
 
     pub fn evolve_from_hand(&self, player: Player, card: &Card, target: &InPlayID) -> Self {
@@ -810,7 +906,7 @@ impl GameState {
         side.in_play_mut(target).unwrap().stack.insert(0, FaceCard::Up(card.clone()));
         side.in_play_mut(target).unwrap().put_in_play_turn = self.turn;
 
-        self.with_player_side(side)
+        self.with_player_side(side).with_event(GameEvent::Evolved { card: card.clone(), player, pokemon: *target })
     }
 
     pub fn bench_from_hand(&self, player: Player, card: &Card) -> Self {
@@ -847,6 +943,65 @@ impl GameState {
         self.with_player_side(side)
     }
 
+    pub fn bench_from_deck(&self, player: Player, card: &Card) -> Self {
+        let mut side = self.side(player).clone();
+
+        side.deck = side.deck.remove_card(card).1;
+
+        side.bench.push(InPlayCard {
+            id: self.next_play_id(),
+            owner: player,
+            stack: vec![FaceCard::Up(card.clone())],
+            put_in_play_turn: self.turn,
+            ..Default::default()
+        });
+
+        self.with_player_side(side)
+    }
+
+    pub fn evolve_from_deck(&self, player: Player, card: &Card, target: &InPlayID) -> Self {
+        let mut side = self.side(player).clone();
+
+        side.deck = side.deck.remove_card(card).1;
+
+        side.in_play_mut(target).unwrap().stack.insert(0, FaceCard::Up(card.clone()));
+        side.in_play_mut(target).unwrap().put_in_play_turn = self.turn;
+
+        self.with_player_side(side).with_event(GameEvent::Evolved { card: card.clone(), player, pokemon: *target })
+    }
+
+    pub fn attach_from_deck(&self, card: &Card, target: &InPlayCard) -> Self {
+        let mut side = self.side(target.owner).clone();
+
+        side.deck = side.deck.remove_card(card).1;
+
+        side.in_play_mut(&target.id).unwrap().attached.push(AttachedCard { card: FaceCard::Up(card.clone()), attached_turn: self.turn });
+
+        self.with_player_side(side).with_event(GameEvent::EnergyAttached { card: card.clone(), player: target.owner, pokemon: target.id })
+    }
+
+    pub fn move_attached_card(&self, card: &Card, target: &InPlayCard) -> Self {
+        let mut state = self.without_card(card); // broken_state
+
+        state.side_mut(target.owner).in_play_mut(&target.id).unwrap().attached.push(AttachedCard { card: FaceCard::Up(card.clone()), attached_turn: self.turn });
+
+        state
+    }
+
+    pub fn move_card_to_deck(&self, card: &Card) -> Self {
+        self.without_card(card).put_on_top_of_deck(card.owner, card.clone())
+    }
+
+    pub fn reveal_prizes(&self, player: Player) -> Self {
+        let mut side = self.side(player).clone();
+
+        for prize in side.prizes.iter_mut() {
+            prize.card = prize.card.up();
+        }
+
+        self.with_player_side(side)
+    }
+
     fn without_card(&self, card: &Card) -> Self {
         let mut state = self.clone();
 
@@ -1006,7 +1161,7 @@ impl GameState {
 
         side.in_play_mut(&in_play.id).unwrap().rotational_status = RotationalStatus::Confused;
 
-        self.with_player_side(side)
+        self.with_player_side(side).with_special_condition(in_play, SpecialCondition::Confused)
     }
 
     pub fn paralyze(&self, in_play: &InPlayCard) -> Self {
@@ -1014,7 +1169,7 @@ impl GameState {
 
         side.in_play_mut(&in_play.id).unwrap().rotational_status = RotationalStatus::Paralyzed;
 
-        self.with_player_side(side)
+        self.with_player_side(side).with_special_condition(in_play, SpecialCondition::Paralyzed)
     }
 
     pub fn poison(&self, in_play: &InPlayCard, counters: usize) -> Self {
@@ -1022,6 +1177,22 @@ impl GameState {
 
         side.in_play_mut(&in_play.id).unwrap().poisoned = Some(Poison { counters });
 
+        self.with_player_side(side).with_special_condition(in_play, SpecialCondition::Poisoned)
+    }
+
+    pub fn burn(&self, in_play: &InPlayCard) -> Self {
+        let mut side = self.side(in_play.owner).clone();
+
+        side.in_play_mut(&in_play.id).unwrap().burned = true;
+
+        self.with_player_side(side).with_special_condition(in_play, SpecialCondition::Burned)
+    }
+
+    pub fn cure_burn(&self, in_play: &InPlayCard) -> Self {
+        let mut side = self.side(in_play.owner).clone();
+
+        side.in_play_mut(&in_play.id).unwrap().burned = false;
+
         self.with_player_side(side)
     }
 
@@ -1030,7 +1201,11 @@ impl GameState {
 
         side.in_play_mut(&in_play.id).unwrap().rotational_status = RotationalStatus::Asleep;
 
-        self.with_player_side(side)
+        self.with_player_side(side).with_special_condition(in_play, SpecialCondition::Asleep)
+    }
+
+    fn with_special_condition(self, in_play: &InPlayCard, condition: SpecialCondition) -> Self {
+        self.with_event(GameEvent::SpecialConditionApplied { player: in_play.owner, pokemon: in_play.id, condition })
     }
 
     pub fn wake_up(&self, in_play: &InPlayCard) -> Self {
@@ -1072,6 +1247,19 @@ impl GameState {
         self.with_player_side(side)
     }
 
+    // Puts back the damage counters and Special Conditions `in_play` had.
+    pub fn with_damage_and_conditions_of(&self, in_play: &InPlayCard) -> Self {
+        let mut side = self.side(in_play.owner).clone();
+
+        let now = side.in_play_mut(&in_play.id).unwrap();
+        now.damage_counters = in_play.damage_counters;
+        now.rotational_status = in_play.rotational_status.clone();
+        now.poisoned = in_play.poisoned.clone();
+        now.burned = in_play.burned;
+
+        self.with_player_side(side)
+    }
+
     pub fn remove_special_conditions(&self, in_play: &InPlayCard) -> Self {
         let mut side = self.side(in_play.owner).clone();
 
@@ -1133,6 +1321,8 @@ impl GameState {
 
         p1.manual_attachments_this_turn = 0;
         p2.manual_attachments_this_turn = 0;
+        p1.retreats_this_turn = 0;
+        p2.retreats_this_turn = 0;
 
         Self {
             p1,
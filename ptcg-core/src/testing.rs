@@ -0,0 +1,485 @@
+use std::collections::VecDeque;
+use crate::state::*;
+use crate::engine::*;
+use crate::replay::Label;
+use crate::view::{ActionView, InPlayView, PlayerView, PrizeView};
+use crate::server::{Client, ServerMessage};
+
+// Option labels include set codes and in-game ids ("Mewtwo (BS 10) One#12"),
+// which are noise in a script. Answers can use either the full label or the
+// short one ("Mewtwo").
+fn short_label(label: &str) -> String {
+    let mut short = String::new();
+    let mut depth = 0;
+    for c in label.chars() {
+        match c {
+            '(' => { depth += 1; },
+            ')' => { depth -= 1; },
+            _ if depth == 0 => { short.push(c); },
+            _ => {},
+        }
+    }
+
+    short
+        .split_whitespace()
+        .filter(|word| !word.starts_with("One#") && !word.starts_with("Two#"))
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" :", ":")
+}
+
+fn matches(label: &str, answer: &str) -> bool {
+    label == answer || short_label(label) == answer
+}
+
+// A DecisionMaker driven by a script of "call: answer" lines, for example
+// "pick_action: Attack with Mewtwo: Psychic" or "flip: heads". Every call
+// must match the next line of the script, otherwise it panics with what the
+// engine actually asked. The views it's shown are kept around for tests to
+// look at.
+pub struct ScriptedDM {
+    script: VecDeque<(String, String)>,
+    views: Vec<PlayerView>,
+}
+
+impl ScriptedDM {
+    pub fn new(script: &[&str]) -> Self {
+        let script = script
+            .iter()
+            .map(|line| match line.split_once(':') {
+                Some((call, answer)) => (call.trim().to_string(), answer.trim().to_string()),
+                None => panic!("invalid script line {:?}, expected \"call: answer\"", line),
+            })
+            .collect();
+
+        Self { script, views: vec![] }
+    }
+
+    pub fn views(&self) -> &[PlayerView] {
+        &self.views
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.script.is_empty()
+    }
+
+    pub fn assert_done(&self) {
+        if !self.is_done() {
+            panic!("script has unused lines: {:?}", self.script);
+        }
+    }
+
+    fn next(&mut self, call: &str, options: &[String]) -> String {
+        match self.script.pop_front() {
+            Some((expected, answer)) if expected == call => answer,
+            Some((expected, answer)) => panic!("script expected {}: {}, but the engine asked {} with [{}]", expected, answer, call, options.join(", ")),
+            None => panic!("script is over, but the engine asked {} with [{}]", call, options.join(", ")),
+        }
+    }
+
+    fn pick_index(&mut self, call: &str, options: &[String]) -> usize {
+        let answer = self.next(call, options);
+        Self::find(call, options, &answer)
+    }
+
+    fn pick_indices(&mut self, call: &str, options: &[String]) -> Vec<usize> {
+        let answer = self.next(call, options);
+        if answer.is_empty() {
+            return vec![];
+        }
+
+        let mut picked: Vec<usize> = vec![];
+        for part in answer.split(',').map(str::trim) {
+            // the same answer twice means two different cards with that label
+            let index = options
+                .iter()
+                .enumerate()
+                .position(|(i, label)| !picked.contains(&i) && matches(label, part))
+                .unwrap_or_else(|| panic!("{}: no option matches {:?} in [{}]", call, part, options.join(", ")));
+            picked.push(index);
+        }
+
+        picked
+    }
+
+    fn find(call: &str, options: &[String], answer: &str) -> usize {
+        options
+            .iter()
+            .position(|label| matches(label, answer))
+            .unwrap_or_else(|| panic!("{}: no option matches {:?} in [{}]", call, answer, options.join(", ")))
+    }
+
+    fn labels<T: Label>(items: &[T]) -> Vec<String> {
+        items.iter().map(Label::label).collect()
+    }
+}
+
+// Plays one side of a networked game from the same kind of script, eg:
+// "pick_action: Pass", until the server says the game is over. Returns
+// everything the server sent.
+pub struct ScriptedClient {
+    client: Client,
+    script: ScriptedDM,
+}
+
+impl ScriptedClient {
+    pub fn new(client: Client, script: &[&str]) -> Self {
+        Self { client, script: ScriptedDM::new(script) }
+    }
+
+    pub fn run(mut self) -> std::io::Result<Vec<ServerMessage>> {
+        let mut transcript = vec![];
+
+        loop {
+            let message = self.client.receive()?;
+            transcript.push(message.clone());
+
+            match message {
+                ServerMessage::Prompt { id, call, options, .. } => {
+                    let choice = self.script.pick_indices(&call, &options);
+                    self.client.answer(id, choice)?;
+                },
+                ServerMessage::Finished { .. } => {
+                    self.script.assert_done();
+                    return Ok(transcript);
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+impl Shuffler for ScriptedDM {
+    fn random_card(&mut self, n: usize) -> usize {
+        let answer = self.next("random_card", &[n.to_string()]);
+        answer.parse().unwrap_or_else(|_| panic!("random_card: expected a number, got {:?}", answer))
+    }
+}
+
+impl DecisionMaker for ScriptedDM {
+    fn observe(&mut self, view: &PlayerView) {
+        self.views.push(view.clone());
+    }
+
+    fn shuffler(&mut self) -> &mut dyn Shuffler {
+        self
+    }
+
+    fn flip(&mut self, number_of_coins: usize) -> Flips {
+        let answer = self.next("flip", &[number_of_coins.to_string()]);
+        let results = answer
+            .split(',')
+            .map(str::trim)
+            .map(|flip| match flip {
+                "heads" => true,
+                "tails" => false,
+                _ => panic!("flip: expected heads or tails, got {:?}", flip),
+            })
+            .collect::<Vec<_>>();
+
+        if results.len() != number_of_coins {
+            panic!("flip: engine flipped {} coins, but the script has {:?}", number_of_coins, answer);
+        }
+
+        Flips::from_results(results)
+    }
+
+    fn confirm_setup_mulligan(&mut self, _p: Player) {
+        self.next("confirm_setup_mulligan", &[]);
+    }
+
+    fn confirm_setup_active_or_mulligan(&mut self, _p: Player, maybe: &Vec<Card>) -> SetupActiveSelection {
+        let options = Self::labels(maybe);
+        match self.next("confirm_setup_active_or_mulligan", &options).as_str() {
+            "mulligan" => SetupActiveSelection::Mulligan,
+            answer => SetupActiveSelection::Place(maybe[Self::find("confirm_setup_active_or_mulligan", &options, answer)].clone()),
+        }
+    }
+
+    fn confirm_setup_active(&mut self, _p: Player, yes: &Vec<Card>, maybe: &Vec<Card>) -> Card {
+        let all = yes.iter().chain(maybe.iter()).cloned().collect::<Vec<_>>();
+        all[self.pick_index("confirm_setup_active", &Self::labels(&all))].clone()
+    }
+
+    fn confirm_mulligan_draw(&mut self, _p: Player, upto: usize) -> usize {
+        let answer = self.next("confirm_mulligan_draw", &[upto.to_string()]);
+        answer.parse().unwrap_or_else(|_| panic!("confirm_mulligan_draw: expected a number, got {:?}", answer))
+    }
+
+    fn confirm_setup_bench_selection(&mut self, _p: Player, _upto: usize, cards: &Vec<Card>) -> Vec<Card> {
+        let indices = self.pick_indices("confirm_setup_bench_selection", &Self::labels(cards));
+        indices.into_iter().map(|i| cards[i].clone()).collect()
+    }
+
+    fn confirm_going_first(&mut self, _p: Player) -> bool {
+        match self.next("confirm_going_first", &["first".into(), "second".into()]).as_str() {
+            "first" => true,
+            "second" => false,
+            answer => panic!("confirm_going_first: expected first or second, got {:?}", answer),
+        }
+    }
+
+    fn pick_type<'a>(&mut self, _p: Player, types: &'a Vec<Type>) -> &'a Type {
+        &types[self.pick_index("pick_type", &Self::labels(types))]
+    }
+
+    fn pick_move_damage_counters<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a InPlayView, &'a InPlayView, usize)>) -> &'a (&'a InPlayView, &'a InPlayView, usize) {
+        &possibilities[self.pick_index("pick_move_damage_counters", &Self::labels(possibilities))]
+    }
+
+    fn pick_attach_from_hand<'a>(&mut self, _p: Player, possibilities: &'a Vec<(&'a Card, &'a InPlayView)>) -> &'a (&'a Card, &'a InPlayView) {
+        &possibilities[self.pick_index("pick_attach_from_hand", &Self::labels(possibilities))]
+    }
+
+    fn pick_attack<'a>(&mut self, _p: Player, attacks: &'a Vec<Attack>) -> &'a Attack {
+        &attacks[self.pick_index("pick_attack", &Self::labels(attacks))]
+    }
+
+    fn pick_action<'a>(&mut self, _p: Player, actions: &'a Vec<ActionView>) -> &'a ActionView {
+        &actions[self.pick_index("pick_action", &Self::labels(actions))]
+    }
+
+    fn pick_stage<'a>(&mut self, _p: Player, items: &'a Vec<Stage>) -> &'a Stage {
+        &items[self.pick_index("pick_stage", &Self::labels(items))]
+    }
+
+    fn pick_from_hand<'a>(&mut self, _p: Player, _whose: Player, _how_many: usize, hand: &'a Vec<Card>) -> Vec<&'a Card> {
+        self.pick_indices("pick_from_hand", &Self::labels(hand)).into_iter().map(|i| &hand[i]).collect()
+    }
+
+    fn pick_from_discard<'a>(&mut self, _p: Player, _whose: Player, _how_many: usize, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        self.pick_indices("pick_from_discard", &Self::labels(searchable)).into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_in_play<'a>(&mut self, _p: Player, _how_many: usize, searchable: &'a Vec<InPlayView>) -> Vec<&'a InPlayView> {
+        self.pick_indices("pick_in_play", &Self::labels(searchable)).into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_attached<'a>(&mut self, _p: Player, _how_many: std::ops::RangeInclusive<usize>, searchable: &'a Vec<Card>) -> Vec<&'a Card> {
+        self.pick_indices("pick_attached", &Self::labels(searchable)).into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn pick_energy_discard<'a>(&mut self, _p: Player, _cost: &[Type], options: &'a [Vec<Card>]) -> &'a Vec<Card> {
+        &options[self.pick_index("pick_energy_discard", &Self::labels(options))]
+    }
+
+    fn pick_from_prizes<'a>(&mut self, _who: Player, _whose: Player, _how_many: usize, searchable: &'a [PrizeView]) -> Vec<&'a PrizeView> {
+        self.pick_indices("pick_from_prizes", &Self::labels(searchable)).into_iter().map(|i| &searchable[i]).collect()
+    }
+
+    fn search_deck<'a>(&mut self, _p: Player, _whose: Player, _how_many: usize, deck: &'a Vec<Option<Card>>) -> Vec<&'a Option<Card>> {
+        self.pick_indices("search_deck", &Self::labels(deck)).into_iter().map(|i| &deck[i]).collect()
+    }
+
+    fn rearrange<'a>(&mut self, _p: Player, cards: &'a Vec<Card>) -> Vec<&'a Card> {
+        self.pick_indices("rearrange", &Self::labels(cards)).into_iter().map(|i| &cards[i]).collect()
+    }
+
+    fn accept_challenge(&mut self, _p: Player) -> bool {
+        match self.next("accept_challenge", &["accept".into(), "decline".into()]).as_str() {
+            "accept" => true,
+            "decline" => false,
+            answer => panic!("accept_challenge: expected accept or decline, got {:?}", answer),
+        }
+    }
+
+    fn pick_player<'a>(&mut self, _p: Player, players: &'a [Player]) -> &'a Player {
+        &players[self.pick_index("pick_player", &Self::labels(players))]
+    }
+}
+
+// Builds a mid-game GameState without going through setup. Pokémon are placed
+// with `active`/`bench`, and `attach`, `damage_counters`, `evolve_into` and
+// `status` apply to the last Pokémon placed. Decks keep the given order.
+pub struct GameStateBuilder {
+    state: GameState,
+    next_card_id: usize,
+    last: Option<(Player, InPlayID)>,
+}
+
+impl Default for GameStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameStateBuilder {
+    pub fn new() -> Self {
+        Self {
+            state: GameState::unchecked(&[], &[]).with_stage(GameStage::Turn(Player::One)),
+            next_card_id: 0,
+            last: None,
+        }
+        .turn(Player::One)
+    }
+
+    // Makes it `player`'s turn. A couple of earlier turns are made up so that
+    // everything in play counts as having been there since before this turn.
+    pub fn turn(mut self, player: Player) -> Self {
+        self.state.turns = vec![player, player.opponent(), player];
+        self.state.turn = self.state.turns.len();
+        self.state.stage = GameStage::Turn(player);
+        self
+    }
+
+    pub fn stage(mut self, stage: GameStage) -> Self {
+        self.state.stage = stage;
+        self
+    }
+
+    fn card(&mut self, owner: Player, archetype: &str) -> Card {
+        self.next_card_id += 1;
+        Card { owner, in_game_id: self.next_card_id, archetype: archetype.into() }
+    }
+
+    fn cards(&mut self, owner: Player, archetypes: &[&str]) -> Vec<Card> {
+        archetypes.iter().map(|archetype| self.card(owner, archetype)).collect()
+    }
+
+    fn side(&mut self, player: Player) -> &mut PlayerSide {
+        match player {
+            Player::One => &mut self.state.p1,
+            Player::Two => &mut self.state.p2,
+        }
+    }
+
+    fn in_play(&mut self, owner: Player, archetype: &str) -> InPlayCard {
+        let card = self.card(owner, archetype);
+        let id = self.state.p1.all_in_play().iter().chain(self.state.p2.all_in_play().iter()).map(|p| p.id).max().unwrap_or(0) + 1;
+        self.last = Some((owner, id));
+
+        InPlayCard {
+            id,
+            owner,
+            stack: vec![FaceCard::Up(card)],
+            ..Default::default()
+        }
+    }
+
+    pub fn active(mut self, player: Player, archetype: &str) -> Self {
+        let in_play = self.in_play(player, archetype);
+        self.side(player).active = vec![in_play];
+        self
+    }
+
+    pub fn bench(mut self, player: Player, archetype: &str) -> Self {
+        let in_play = self.in_play(player, archetype);
+        self.side(player).bench.push(in_play);
+        self
+    }
+
+    fn last_mut(&mut self) -> &mut InPlayCard {
+        let (player, id) = self.last.expect("no Pokémon placed yet");
+        self.side(player).in_play_mut(&id).unwrap()
+    }
+
+    pub fn attach(mut self, archetypes: &[&str]) -> Self {
+        let (player, _) = self.last.expect("no Pokémon placed yet");
+        let cards = self.cards(player, archetypes);
+        self.last_mut().attached.extend(cards.into_iter().map(|card| AttachedCard { card: FaceCard::Up(card), attached_turn: 0 }));
+        self
+    }
+
+    pub fn evolve_into(mut self, archetype: &str) -> Self {
+        let (player, _) = self.last.expect("no Pokémon placed yet");
+        let card = self.card(player, archetype);
+        self.last_mut().stack.insert(0, FaceCard::Up(card));
+        self
+    }
+
+    pub fn damage_counters(mut self, counters: usize) -> Self {
+        self.last_mut().damage_counters = counters;
+        self
+    }
+
+    pub fn status(mut self, status: RotationalStatus) -> Self {
+        self.last_mut().rotational_status = status;
+        self
+    }
+
+    pub fn poisoned(mut self) -> Self {
+        self.last_mut().poisoned = Some(Poison { counters: 1 });
+        self
+    }
+
+    pub fn burned(mut self) -> Self {
+        self.last_mut().burned = true;
+        self
+    }
+
+    pub fn hand(mut self, player: Player, archetypes: &[&str]) -> Self {
+        let cards = self.cards(player, archetypes);
+        self.side(player).hand.extend(cards);
+        self
+    }
+
+    // Cards are drawn in the given order, top of the deck first.
+    pub fn deck(mut self, player: Player, archetypes: &[&str]) -> Self {
+        let cards = self.cards(player, archetypes);
+        self.side(player).deck = Deck::new(&cards);
+        self
+    }
+
+    pub fn discard(mut self, player: Player, archetypes: &[&str]) -> Self {
+        let cards = self.cards(player, archetypes);
+        self.side(player).discard.extend(cards);
+        self
+    }
+
+    pub fn prizes(mut self, player: Player, archetypes: &[&str]) -> Self {
+        let cards = self.cards(player, archetypes);
+        let side = self.side(player);
+        let first = side.prizes.len();
+        side.prizes.extend(cards.into_iter().enumerate().map(|(i, card)| PrizeCard { id: first + i + 1, card: FaceCard::Down(card) }));
+        self
+    }
+
+    pub fn build(self) -> GameState {
+        self.state
+    }
+}
+
+// The rest is what the card tests of every set have in common. Anything that
+// depends on the cards takes the format they're played in.
+
+// Both players need prizes left, or the game is over as soon as anything
+// checks for a winner.
+pub fn game(format: &dyn Format) -> GameStateBuilder {
+    let prize = format.registry().by_name("Water Energy").expect("formats have Water Energy")[0].identifier();
+
+    GameStateBuilder::new()
+        .prizes(Player::One, &[prize.as_str(); 6])
+        .prizes(Player::Two, &[prize.as_str(); 6])
+}
+
+// Takes one step of `state` in `format`, making sure the whole script was used.
+pub fn run(format: &dyn Format, state: GameState, script: &[&str]) -> GameEngine {
+    let mut dm = ScriptedDM::new(script);
+    let engine = GameEngine::from_state(state, format.boxed_clone()).step(&mut dm);
+    dm.assert_done();
+
+    engine
+}
+
+// Steps through the end of the turn and the checkup without expecting any
+// decisions, until `player` can act.
+pub fn until_turn(mut engine: GameEngine, player: Player) -> GameEngine {
+    let mut dm = ScriptedDM::new(&[]);
+    while engine.state.stage != GameStage::Turn(player) {
+        engine = engine.step(&mut dm);
+    }
+
+    engine
+}
+
+pub fn active(engine: &GameEngine, player: Player) -> &InPlayCard {
+    &engine.state.side(player).active[0]
+}
+
+pub fn bench(engine: &GameEngine, player: Player) -> &Vec<InPlayCard> {
+    &engine.state.side(player).bench
+}
+
+pub fn names(cards: &[Card]) -> Vec<&str> {
+    cards.iter().map(|c| c.archetype.as_str()).collect()
+}
@@ -0,0 +1,275 @@
+use serde::{Deserialize, Serialize};
+use crate::state::*;
+use crate::engine::{Action, Attack};
+
+// The game as one player is allowed to see it. Both decks and the opponent's
+// hand are down to their sizes, and face-down cards are None to whoever can't
+// see them: prizes to both players, anything else to the opponent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerView {
+    pub player: Player,
+    pub p1: SideView,
+    pub p2: SideView,
+    pub stage: GameStage,
+    pub turn: usize,
+    pub turns: Vec<Player>,
+    pub effects: Vec<Effect>,
+}
+
+impl PlayerView {
+    pub fn new(state: &GameState, player: Player) -> Self {
+        Self {
+            player,
+            p1: SideView::new(&state.p1, player),
+            p2: SideView::new(&state.p2, player),
+            stage: state.stage.clone(),
+            turn: state.turn,
+            turns: state.turns.clone(),
+            effects: state.effects.clone(),
+        }
+    }
+
+    pub fn side(&self, player: Player) -> &SideView {
+        match player {
+            Player::One => &self.p1,
+            Player::Two => &self.p2,
+        }
+    }
+
+    pub fn side_mut(&mut self, player: Player) -> &mut SideView {
+        match player {
+            Player::One => &mut self.p1,
+            Player::Two => &mut self.p2,
+        }
+    }
+
+    // A whole GameState that agrees with everything this player can see, for
+    // AIs to try things out on. Every card they can't see is made up by
+    // `guess`, which is told whose card it is, and gets an in-game id that no
+    // visible card uses. Decks come out shuffled.
+    pub fn determinize(&self, guess: &mut dyn FnMut(Player) -> String) -> GameState {
+        let mut next_id = self.p1.cards().into_iter().chain(self.p2.cards()).map(|card| card.in_game_id + 1).max().unwrap_or(0);
+        let mut fill = |owner: Player, card: &Option<Card>| match card {
+            Some(card) => card.clone(),
+            None => {
+                next_id += 1;
+                Card { owner, in_game_id: next_id - 1, archetype: guess(owner) }
+            },
+        };
+
+        GameState {
+            p1: self.p1.determinize(&mut fill),
+            p2: self.p2.determinize(&mut fill),
+            stage: self.stage.clone(),
+            turn: self.turn,
+            turns: self.turns.clone(),
+            effects: self.effects.clone(),
+            parent: None,
+            events: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SideView {
+    pub owner: Player,
+    pub deck: usize,
+    pub hand: Vec<Option<Card>>,
+    pub discard: Vec<Card>,
+    pub lost_zone: Vec<Card>,
+    pub prizes: Vec<PrizeView>,
+    pub gx_available: bool,
+    pub vstar_available: bool,
+    pub active: Vec<InPlayView>,
+    pub bench: Vec<InPlayView>,
+    pub stadium: Option<Card>,
+    pub supporter: Option<Card>,
+    pub working_area: Vec<Option<Card>>,
+    pub manual_attachments_this_turn: usize,
+    pub retreats_this_turn: usize,
+}
+
+impl SideView {
+    pub fn new(side: &PlayerSide, viewer: Player) -> Self {
+        let own = side.owner == viewer;
+
+        Self {
+            owner: side.owner,
+            deck: side.deck.len(),
+            hand: side.hand.iter().map(|card| own.then(|| card.clone())).collect(),
+            discard: side.discard.clone(),
+            lost_zone: side.lost_zone.clone(),
+            prizes: side.prizes.iter().map(PrizeView::new).collect(),
+            gx_available: side.gx_available,
+            vstar_available: side.vstar_available,
+            active: side.active.iter().map(|in_play| InPlayView::new(in_play, viewer)).collect(),
+            bench: side.bench.iter().map(|in_play| InPlayView::new(in_play, viewer)).collect(),
+            stadium: side.stadium.clone(),
+            supporter: side.supporter.clone(),
+            working_area: side.working_area.iter().map(|card| own.then(|| card.clone())).collect(),
+            manual_attachments_this_turn: side.manual_attachments_this_turn,
+            retreats_this_turn: side.retreats_this_turn,
+        }
+    }
+
+    // Every card on this side that the viewer knows about.
+    pub fn cards(&self) -> Vec<&Card> {
+        let in_play = self.active.iter().chain(self.bench.iter())
+            .flat_map(|in_play| in_play.stack.iter().chain(in_play.attached.iter()));
+
+        self.hand.iter().flatten()
+            .chain(self.discard.iter())
+            .chain(self.lost_zone.iter())
+            .chain(self.prizes.iter().filter_map(|prize| prize.card.as_ref()))
+            .chain(in_play.flatten())
+            .chain(self.stadium.iter())
+            .chain(self.supporter.iter())
+            .chain(self.working_area.iter().flatten())
+            .collect()
+    }
+
+    fn determinize(&self, fill: &mut dyn FnMut(Player, &Option<Card>) -> Card) -> PlayerSide {
+        let owner = self.owner;
+        let mut face = |card: &Option<Card>| match card {
+            Some(card) => FaceCard::Up(card.clone()),
+            None => FaceCard::Down(fill(owner, &None)),
+        };
+        let prizes = self.prizes.iter().map(|prize| PrizeCard { id: prize.id, card: face(&prize.card) }).collect();
+        let mut in_play = |in_play: &InPlayView| InPlayCard {
+            id: in_play.id,
+            owner: in_play.owner,
+            stack: in_play.stack.iter().map(&mut face).collect(),
+            attached: in_play.attached.iter().map(|card| AttachedCard { card: face(card), attached_turn: 0 }).collect(),
+            damage_counters: in_play.damage_counters,
+            rotational_status: in_play.rotational_status.clone(),
+            poisoned: in_play.poisoned.clone(),
+            burned: in_play.burned,
+            put_in_play_turn: in_play.put_in_play_turn,
+        };
+        let active = self.active.iter().map(&mut in_play).collect();
+        let bench = self.bench.iter().map(&mut in_play).collect();
+        let deck = match self.deck {
+            0 => Deck::default(),
+            n => Deck::new(&(0..n).map(|_| fill(owner, &None)).collect::<Vec<_>>()).shuffle(),
+        };
+
+        PlayerSide {
+            owner,
+            deck,
+            hand: self.hand.iter().map(|card| fill(owner, card)).collect(),
+            discard: self.discard.clone(),
+            lost_zone: self.lost_zone.clone(),
+            prizes,
+            gx_available: self.gx_available,
+            vstar_available: self.vstar_available,
+            active,
+            bench,
+            stadium: self.stadium.clone(),
+            supporter: self.supporter.clone(),
+            working_area: self.working_area.iter().map(|card| fill(owner, card)).collect(),
+            manual_attachments_this_turn: self.manual_attachments_this_turn,
+            retreats_this_turn: self.retreats_this_turn,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrizeView {
+    pub id: usize,
+    pub card: Option<Card>,
+}
+
+impl PrizeView {
+    // Nobody gets to look at a face-down prize, not even its owner.
+    pub fn new(prize: &PrizeCard) -> Self {
+        Self {
+            id: prize.id,
+            card: seen(&prize.card, false),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InPlayView {
+    pub id: InPlayID,
+    pub owner: Player,
+    pub stack: Vec<Option<Card>>,
+    pub attached: Vec<Option<Card>>,
+    pub damage_counters: usize,
+    pub rotational_status: RotationalStatus,
+    pub poisoned: Option<Poison>,
+    pub burned: bool,
+    pub put_in_play_turn: usize,
+}
+
+impl InPlayView {
+    pub fn new(in_play: &InPlayCard, viewer: Player) -> Self {
+        let own = in_play.owner == viewer;
+
+        Self {
+            id: in_play.id,
+            owner: in_play.owner,
+            stack: in_play.stack.iter().map(|card| seen(card, own)).collect(),
+            attached: in_play.attached.iter().map(|attached| seen(&attached.card, own)).collect(),
+            damage_counters: in_play.damage_counters,
+            rotational_status: in_play.rotational_status.clone(),
+            poisoned: in_play.poisoned.clone(),
+            burned: in_play.burned,
+            put_in_play_turn: in_play.put_in_play_turn,
+        }
+    }
+}
+
+// An Action as the player it's offered to sees it, with the Pokémon it
+// involves as InPlayViews.
+#[derive(Clone)]
+pub enum ActionView {
+    Pass,
+    TrainerFromHand(Player, Card),
+    AttachFromHand(Player, Card),
+    BenchFromHand(Player, Card),
+    EvolveFromHand(Player, Card),
+    Attack(Player, InPlayView, Attack),
+    PokePower(Player, InPlayView, Attack),
+    Retreat(Player, InPlayView),
+}
+
+impl ActionView {
+    pub fn new(action: &Action, viewer: Player) -> Self {
+        match action {
+            Action::Pass => ActionView::Pass,
+            Action::TrainerFromHand(player, card) => ActionView::TrainerFromHand(*player, card.clone()),
+            Action::AttachFromHand(player, card) => ActionView::AttachFromHand(*player, card.clone()),
+            Action::BenchFromHand(player, card) => ActionView::BenchFromHand(*player, card.clone()),
+            Action::EvolveFromHand(player, card) => ActionView::EvolveFromHand(*player, card.clone()),
+            Action::Attack(player, in_play, attack) => ActionView::Attack(*player, InPlayView::new(in_play, viewer), attack.clone()),
+            Action::PokePower(player, in_play, attack) => ActionView::PokePower(*player, InPlayView::new(in_play, viewer), attack.clone()),
+            Action::Retreat(player, in_play) => ActionView::Retreat(*player, InPlayView::new(in_play, viewer)),
+        }
+    }
+}
+
+// Same as Action's, so that labels don't change with what's being looked at.
+impl std::fmt::Debug for ActionView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        let name = |in_play: &InPlayView| in_play.stack[0].as_ref().map(|card| card.archetype.clone()).unwrap_or_else(|| "Face down Pokémon".into());
+
+        match self {
+            ActionView::TrainerFromHand(_player, card) => { write!(f, "Play {}", card.archetype) },
+            ActionView::AttachFromHand(_player, card) => { write!(f, "Attach {}", card.archetype) },
+            ActionView::BenchFromHand(_player, card) => { write!(f, "Bench {}", card.archetype) },
+            ActionView::EvolveFromHand(_player, card) => { write!(f, "Evolve into {}", card.archetype) },
+            ActionView::Attack(_player, in_play, attack) => { write!(f, "Attack with {}: {}", name(in_play), attack.name()) },
+            ActionView::PokePower(_player, in_play, attack) => { write!(f, "Use {}'s PokéPower: {}", name(in_play), attack.name()) },
+            ActionView::Retreat(_player, in_play) => { write!(f, "Retreat {}", name(in_play)) },
+            ActionView::Pass => { write!(f, "Pass") },
+        }
+    }
+}
+
+fn seen(card: &FaceCard, known: bool) -> Option<Card> {
+    match card {
+        FaceCard::Up(card) => Some(card.clone()),
+        FaceCard::Down(card) => known.then(|| card.clone()),
+    }
+}
@@ -0,0 +1,191 @@
+use ptcg_core::*;
+use std::time::{Duration, Instant};
+use ptcg_core::ai::{GreedyDM, MctsDM, RandomDM};
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::simulation::*;
+use ptcg_core::testing::GameStateBuilder;
+
+fn deck() -> Vec<String> {
+    let mut deck = vec![];
+    for (archetype, count) in [
+        ("Hitmonchan (BS 7)", 4), ("Machop (BS 52)", 4), ("Machoke (BS 34)", 3), ("Charmander (BS 46)", 4), ("Charmeleon (BS 24)", 3),
+        ("Potion (BS 93)", 2), ("Switch (BS 94)", 2), ("Bill (BS 90)", 4), ("Gust of Wind (BS 92)", 2),
+        ("Fighting Energy (BS 97)", 20), ("Fire Energy (BS 98)", 12),
+    ] {
+        deck.extend(vec![archetype.to_string(); count]);
+    }
+    deck
+}
+
+fn random() -> Contender {
+    Box::new(|_, seed| Box::new(RandomDM::new(seed)))
+}
+
+fn greedy() -> Contender {
+    Box::new(|_, seed| Box::new(GreedyDM::new(Box::new(BaseFossil::new()), seed)))
+}
+
+fn engine(state: GameState) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+fn play(dm: &mut dyn DecisionMaker) -> String {
+    let state = GameState::initial(&deck(), &deck(), &BaseFossil::new(), true).unwrap();
+    let engine = engine(state).play(dm, &mut Headless::default());
+
+    serde_json::to_string(&engine.state).unwrap()
+}
+
+#[test]
+fn random_games_are_reproducible_from_the_seed() {
+    assert_eq!(play(&mut RandomDM::new(3)), play(&mut RandomDM::new(3)));
+    assert_ne!(play(&mut RandomDM::new(3)), play(&mut RandomDM::new(4)));
+}
+
+#[test]
+fn random_and_greedy_players_only_make_legal_choices() {
+    let report = Simulation::new(|| Box::new(BaseFossil::new()), (&deck(), random()), (&deck(), greedy()))
+        .games(10)
+        .run()
+        .unwrap();
+
+    assert_eq!(report.count(Ending::Crashed), 0);
+    assert_eq!(report.count(Ending::TurnLimit), 0);
+}
+
+#[test]
+fn greedy_beats_random() {
+    let report = Simulation::new(|| Box::new(BaseFossil::new()), (&deck(), greedy()), (&deck(), random()))
+        .games(10)
+        .run()
+        .unwrap();
+
+    assert!(report.win_rate(Deck::A) > 0.8, "{}", report);
+}
+
+#[test]
+fn greedy_knocks_out_the_defending_pokemon_when_it_can() {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)", "Fighting Energy (BS 97)", "Fire Energy (BS 98)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .bench(Player::Two, "Magikarp (BS 35)")
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .build();
+
+    let engine = engine(state).step(&mut GreedyDM::new(Box::new(BaseFossil::new()), 0));
+
+    assert!(engine.events().iter().any(|event| matches!(event, GameEvent::AttackDeclared { attack, .. } if attack == "Special Punch")));
+    assert!(engine.events().iter().any(|event| matches!(event, GameEvent::KnockedOut { player: Player::Two, .. })));
+}
+
+#[test]
+fn greedy_attaches_energy_towards_unpaid_attacks() {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .bench(Player::One, "Charmander (BS 46)").attach(&["Fire Energy (BS 98)", "Fire Energy (BS 98)"])
+        .hand(Player::One, &["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .build();
+
+    let engine = engine(state).step(&mut GreedyDM::new(Box::new(BaseFossil::new()), 0));
+
+    assert_eq!(engine.state.p1.active[0].attached.len(), 2);
+    assert_eq!(engine.state.p1.bench[0].attached.len(), 2);
+}
+
+#[test]
+fn ai_players_bench_no_more_than_they_are_allowed_during_setup() {
+    let state = GameStateBuilder::new()
+        .hand(Player::One, &["Machop (BS 52)"; 4])
+        .build();
+    let cards = state.p1.hand.clone();
+
+    assert_eq!(GreedyDM::new(Box::new(BaseFossil::new()), 0).confirm_setup_bench_selection(Player::One, 2, &cards).len(), 2);
+    for seed in 0..20 {
+        assert!(RandomDM::new(seed).confirm_setup_bench_selection(Player::One, 2, &cards).len() <= 2);
+    }
+}
+
+#[test]
+fn greedy_evolves_before_attacking() {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Charmander (BS 46)").attach(&["Fire Energy (BS 98)"])
+        .hand(Player::One, &["Charmeleon (BS 24)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .build();
+
+    let engine = engine(state).step(&mut GreedyDM::new(Box::new(BaseFossil::new()), 0));
+
+    assert_eq!(engine.state.p1.active[0].stack[0].card().archetype, "Charmeleon (BS 24)");
+}
+
+#[test]
+fn greedy_retreats_a_pokemon_about_to_be_knocked_out() {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Magikarp (BS 35)").attach(&["Fire Energy (BS 98)"]).damage_counters(1)
+        .bench(Player::One, "Hitmonchan (BS 7)")
+        .active(Player::Two, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .build();
+
+    let engine = engine(state).step(&mut GreedyDM::new(Box::new(BaseFossil::new()), 0));
+
+    assert_eq!(engine.state.p1.active[0].stack[0].card().archetype, "Hitmonchan (BS 7)");
+}
+
+#[test]
+fn mcts_finds_the_knockout() {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)", "Fighting Energy (BS 97)", "Fire Energy (BS 98)"])
+        .hand(Player::One, &["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .bench(Player::Two, "Magikarp (BS 35)")
+        .deck(Player::One, &["Fighting Energy (BS 97)"; 10])
+        .deck(Player::Two, &["Water Energy (BS 102)"; 10])
+        .prizes(Player::One, &["Potion (BS 93)"])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .build();
+
+    let mut dm = MctsDM::new(Box::new(BaseFossil::new()), 0).iterations(60);
+    let engine = engine(state).step(&mut dm);
+    let engine = engine.step(&mut dm);
+
+    assert!(engine.is_finished() || engine.events().iter().any(|event| matches!(event, GameEvent::KnockedOut { player: Player::Two, .. })));
+}
+
+#[test]
+fn mcts_stops_searching_when_out_of_time() {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .hand(Player::One, &["Fighting Energy (BS 97)", "Potion (BS 93)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .deck(Player::One, &["Fighting Energy (BS 97)"; 20])
+        .deck(Player::Two, &["Fighting Energy (BS 97)"; 20])
+        .prizes(Player::One, &["Potion (BS 93)"; 6])
+        .prizes(Player::Two, &["Potion (BS 93)"; 6])
+        .build();
+
+    let mut dm = MctsDM::new(Box::new(BaseFossil::new()), 0).iterations(usize::MAX).time_budget(Duration::from_millis(100));
+    let start = Instant::now();
+    engine(state).step(&mut dm);
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn mcts_plays_whole_games_from_its_deck_list() {
+    let report = Simulation::new(
+        || Box::new(BaseFossil::new()),
+        (&deck(), Box::new(|player, seed| Box::new(MctsDM::new(Box::new(BaseFossil::new()), seed).deck(player, &deck()).deck(player.opponent(), &deck()).iterations(4).rollout_turns(2)))),
+        (&deck(), random()),
+    ).games(1).run().unwrap();
+
+    assert_eq!(report.count(Ending::Crashed), 0);
+    assert_eq!(report.count(Ending::TurnLimit), 0);
+}
@@ -0,0 +1,127 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn builder() -> GameStateBuilder {
+    GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+}
+
+fn engine(state: GameState) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+fn attack(engine: &GameEngine, player: Player, name: &str) -> Action {
+    let in_play = engine.state.side(player).active[0].clone();
+    let attack = engine.attacks(&in_play).into_iter().find(|attack| attack.name() == name).unwrap();
+
+    Action::Attack(player, in_play, attack)
+}
+
+#[test]
+fn applying_an_available_action_is_like_picking_it() {
+    let engine = engine(builder()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build());
+
+    let mut dm = ScriptedDM::new(&[]);
+    let applied = engine.apply_action(&attack(&engine, Player::One, "Jab"), &mut dm).unwrap();
+    let stepped = engine.step(&mut ScriptedDM::new(&["pick_action: Attack with Hitmonchan: Jab"]));
+
+    dm.assert_done();
+    assert_eq!(applied.state.p2.active[0].damage_counters, 2);
+    assert_eq!(applied.events(), stepped.events());
+    assert_eq!(dm.views().len(), 2);
+}
+
+#[test]
+fn actions_only_happen_on_their_players_turn() {
+    let engine = engine(builder()
+        .active(Player::One, "Hitmonchan (BS 7)")
+        .active(Player::Two, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .build());
+
+    let result = engine.apply_action(&attack(&engine, Player::Two, "Jab"), &mut ScriptedDM::new(&[]));
+    assert_eq!(result.err(), Some(IllegalAction::NotYourTurn));
+}
+
+#[test]
+fn energy_can_only_be_attached_once_a_turn() {
+    let engine = engine(builder()
+        .active(Player::One, "Hitmonchan (BS 7)")
+        .hand(Player::One, &["Fighting Energy (BS 97)", "Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build());
+
+    let first = Action::AttachFromHand(Player::One, engine.state.p1.hand[0].clone());
+    let second = Action::AttachFromHand(Player::One, engine.state.p1.hand[1].clone());
+    let engine = engine.apply_action(&first, &mut ScriptedDM::new(&["pick_in_play: Hitmonchan"])).unwrap();
+
+    assert_eq!(engine.apply_action(&second, &mut ScriptedDM::new(&[])).err(), Some(IllegalAction::AlreadyAttachedEnergy));
+    assert_eq!(engine.apply_action(&first, &mut ScriptedDM::new(&[])).err(), Some(IllegalAction::NotInHand));
+}
+
+#[test]
+fn attacks_need_their_energy_and_a_pokemon_that_can_move() {
+    let engine = engine(builder()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build());
+
+    let result = engine.apply_action(&attack(&engine, Player::One, "Special Punch"), &mut ScriptedDM::new(&[]));
+    assert_eq!(result.err(), Some(IllegalAction::CostNotMet));
+
+    for (status, reason) in [(RotationalStatus::Paralyzed, IllegalAction::Paralyzed), (RotationalStatus::Asleep, IllegalAction::Asleep)] {
+        let engine = self::engine(builder()
+            .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"]).status(status)
+            .active(Player::Two, "Magikarp (BS 35)")
+            .build());
+
+        let result = engine.apply_action(&attack(&engine, Player::One, "Jab"), &mut ScriptedDM::new(&[]));
+        assert_eq!(result.err(), Some(reason));
+    }
+}
+
+#[test]
+fn benched_pokemon_dont_attack() {
+    let engine = engine(builder()
+        .active(Player::One, "Magikarp (BS 35)")
+        .bench(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build());
+
+    let benched = engine.state.p1.bench[0].clone();
+    let jab = engine.attacks(&benched).remove(0);
+    let result = engine.apply_action(&Action::Attack(Player::One, benched, jab), &mut ScriptedDM::new(&[]));
+
+    assert_eq!(result.err(), Some(IllegalAction::NotActive));
+}
+
+#[test]
+fn the_bench_only_holds_five() {
+    let mut builder = builder().active(Player::One, "Magikarp (BS 35)");
+    for _ in 0..5 {
+        builder = builder.bench(Player::One, "Magikarp (BS 35)");
+    }
+    let engine = engine(builder
+        .hand(Player::One, &["Hitmonchan (BS 7)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build());
+
+    let result = engine.apply_action(&Action::BenchFromHand(Player::One, engine.state.p1.hand[0].clone()), &mut ScriptedDM::new(&[]));
+    assert_eq!(result.err(), Some(IllegalAction::BenchFull));
+}
+
+#[test]
+fn nothing_happens_once_the_game_is_over() {
+    let engine = engine(builder()
+        .active(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .stage(GameStage::Winner(Player::One))
+        .build());
+
+    assert_eq!(engine.apply_action(&Action::Pass, &mut ScriptedDM::new(&[])).err(), Some(IllegalAction::GameOver));
+}
@@ -0,0 +1,472 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{self, until_turn, active, bench, names, GameStateBuilder, ScriptedDM};
+
+#[test]
+fn mewtwo_psychic_adds_10_per_defending_energy() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)").attach(&["Psychic Energy (BS 101)", "Psychic Energy (BS 101)"])
+        .active(Player::Two, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)", "Fighting Energy (BS 97)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Mewtwo: Psychic"]);
+
+    // (10 + 2 * 10) x2 weakness
+    assert_eq!(active(&engine, Player::Two).damage_counters, 6);
+}
+
+#[test]
+fn mewtwo_barrier_prevents_damage_next_turn() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)").attach(&["Psychic Energy (BS 101)", "Psychic Energy (BS 101)"])
+        .active(Player::Two, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .deck(Player::Two, &["Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Mewtwo: Barrier"]);
+    assert!(names(&engine.state.side(Player::One).discard).contains(&"Psychic Energy (BS 101)"));
+
+    let engine = until_turn(engine, Player::Two);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Attack with Hitmonchan: Jab"]));
+
+    assert_eq!(active(&engine, Player::One).damage_counters, 0);
+}
+
+#[test]
+fn pikachu_thunder_jolt_damages_itself_on_tails() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Pikachu (BS 58)").attach(&["Lightning Energy (BS 100)", "Lightning Energy (BS 100)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Pikachu: Thunder Jolt",
+        "flip: tails",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 3);
+    assert_eq!(active(&engine, Player::One).damage_counters, 1);
+}
+
+#[test]
+fn pikachu_thunder_jolt_on_heads() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Pikachu (BS 58)").attach(&["Lightning Energy (BS 100)", "Lightning Energy (BS 100)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Pikachu: Thunder Jolt",
+        "flip: heads",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 3);
+    assert_eq!(active(&engine, Player::One).damage_counters, 0);
+}
+
+#[test]
+fn electabuzz_thundershock_paralyzes_on_heads() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Electabuzz (BS 20)").attach(&["Lightning Energy (BS 100)"])
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Electabuzz: Thundershock",
+        "flip: heads",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 1);
+    assert_eq!(active(&engine, Player::Two).rotational_status, RotationalStatus::Paralyzed);
+}
+
+#[test]
+fn charizard_fire_spin_knocks_out_zapdos() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Charmander (BS 46)").evolve_into("Charmeleon (BS 24)").evolve_into("Charizard (BS 4)")
+            .attach(&["Fire Energy (BS 98)", "Fire Energy (BS 98)", "Fire Energy (BS 98)", "Fire Energy (BS 98)", "Fire Energy (BS 98)"])
+        .active(Player::Two, "Zapdos (BS 16)")
+        .bench(Player::Two, "Mewtwo (BS 10)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Charizard: Fire Spin",
+        "pick_from_prizes: Prize #1",
+        "pick_in_play: Mewtwo",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::Two).discard), vec!["Zapdos (BS 16)"]);
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Water Energy (BS 102)"]);
+    assert_eq!(active(&engine, Player::Two).stack[0].card().archetype, "Mewtwo (BS 10)");
+}
+
+#[test]
+fn zapdos_resists_hitmonchan() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Zapdos (BS 16)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Hitmonchan: Jab"]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 0);
+}
+
+#[test]
+fn nidoran_horn_hazard_does_nothing_on_tails() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Nidoran ♂ (BS 55)").attach(&["Grass Energy (BS 99)"])
+        .active(Player::Two, "Electabuzz (BS 20)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Nidoran ♂: Horn Hazard",
+        "flip: tails",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 0);
+}
+
+#[test]
+fn koffing_foul_gas_poisons_on_heads() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Koffing (BS 51)").attach(&["Grass Energy (BS 99)", "Grass Energy (BS 99)"])
+        .active(Player::Two, "Electabuzz (BS 20)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Koffing: Foul Gas",
+        "flip: heads",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 1);
+    assert!(active(&engine, Player::Two).poisoned.is_some());
+}
+
+#[test]
+fn koffing_foul_gas_confuses_on_tails() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Koffing (BS 51)").attach(&["Grass Energy (BS 99)", "Grass Energy (BS 99)"])
+        .active(Player::Two, "Electabuzz (BS 20)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Koffing: Foul Gas",
+        "flip: tails",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).rotational_status, RotationalStatus::Confused);
+    assert!(active(&engine, Player::Two).poisoned.is_none());
+}
+
+#[test]
+fn magikarp_flail_counts_own_damage() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Magikarp (BS 35)").attach(&["Water Energy (BS 102)"]).damage_counters(2)
+        .active(Player::Two, "Electabuzz (BS 20)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Magikarp: Flail"]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 2);
+}
+
+#[test]
+fn knocking_out_the_last_pokemon_wins() {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)").damage_counters(1)
+        .prizes(Player::One, &["Potion (BS 93)", "Switch (BS 94)"])
+        .prizes(Player::Two, &["Water Energy (BS 102)"; 6])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Hitmonchan: Jab",
+        "pick_from_prizes: Prize #2",
+    ]);
+
+    assert_eq!(engine.state.stage, GameStage::Winner(Player::One));
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Switch (BS 94)"]);
+}
+
+#[test]
+fn attaching_energy_from_hand() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .bench(Player::One, "Abra (BS 43)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Psychic Energy (BS 101)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attach Psychic Energy",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(bench(&engine, Player::One)[0].attached.len(), 1);
+    assert!(engine.state.side(Player::One).hand.is_empty());
+    assert_eq!(engine.state.side(Player::One).manual_attachments_this_turn, 1);
+}
+
+#[test]
+fn evolving_keeps_damage_and_energy() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Abra (BS 43)").attach(&["Psychic Energy (BS 101)"]).damage_counters(2)
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Kadabra (BS 32)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Evolve into Kadabra",
+        "pick_in_play: Abra",
+    ]);
+
+    let kadabra = active(&engine, Player::One);
+    assert_eq!(kadabra.stack.len(), 2);
+    assert_eq!(kadabra.stack[0].card().archetype, "Kadabra (BS 32)");
+    assert_eq!(kadabra.damage_counters, 2);
+    assert_eq!(kadabra.attached.len(), 1);
+}
+
+#[test]
+fn potion_removes_two_damage_counters() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)").damage_counters(3)
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Potion (BS 93)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Potion",
+        "pick_in_play: Mewtwo",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).damage_counters, 1);
+    assert_eq!(names(&engine.state.side(Player::One).discard), vec!["Potion (BS 93)"]);
+}
+
+#[test]
+fn super_potion_discards_energy_to_heal_40() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)").attach(&["Psychic Energy (BS 101)"]).damage_counters(5)
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Super Potion (BS 90)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Super Potion",
+        "pick_in_play: Mewtwo",
+        "pick_attached: Psychic Energy",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).damage_counters, 1);
+    assert!(active(&engine, Player::One).attached.is_empty());
+}
+
+#[test]
+fn bill_draws_two_cards() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Bill (BS 90)"])
+        .deck(Player::One, &["Abra (BS 43)", "Potion (BS 93)", "Switch (BS 94)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Play Bill"]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Abra (BS 43)", "Potion (BS 93)"]);
+    assert_eq!(engine.state.side(Player::One).deck.len(), 1);
+}
+
+#[test]
+fn professor_oak_discards_hand_and_draws_seven() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Professor Oak (BS 88)", "Potion (BS 93)", "Abra (BS 43)"])
+        .deck(Player::One, &["Psychic Energy (BS 101)"; 10])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Play Professor Oak"]);
+
+    assert_eq!(engine.state.side(Player::One).hand.len(), 7);
+    assert_eq!(engine.state.side(Player::One).deck.len(), 3);
+    assert_eq!(engine.state.side(Player::One).discard.len(), 3);
+}
+
+#[test]
+fn energy_removal_discards_an_opponents_energy() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)").attach(&["Water Energy (BS 102)", "Water Energy (BS 102)"])
+        .hand(Player::One, &["Energy Removal (BS 91)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Energy Removal",
+        "pick_in_play: Magikarp",
+        "pick_attached: Water Energy",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).attached.len(), 1);
+    assert_eq!(names(&engine.state.side(Player::Two).discard), vec!["Water Energy (BS 102)"]);
+}
+
+#[test]
+fn gust_of_wind_brings_up_a_benched_pokemon() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .bench(Player::Two, "Abra (BS 43)")
+        .hand(Player::One, &["Gust of Wind (BS 92)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Gust of Wind",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).stack[0].card().archetype, "Abra (BS 43)");
+    assert_eq!(bench(&engine, Player::Two)[0].stack[0].card().archetype, "Magikarp (BS 35)");
+}
+
+#[test]
+fn switch_swaps_the_active_pokemon() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .bench(Player::One, "Abra (BS 43)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Switch (BS 94)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Switch",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).stack[0].card().archetype, "Abra (BS 43)");
+    assert_eq!(bench(&engine, Player::One)[0].stack[0].card().archetype, "Mewtwo (BS 10)");
+}
+
+#[test]
+fn plus_power_adds_10_damage() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .hand(Player::One, &["Plus Power (BS 84)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Plus Power",
+        "pick_in_play: Hitmonchan",
+    ]);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Attack with Hitmonchan: Jab"]));
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 3);
+}
+
+#[test]
+fn pokemon_center_heals_everything_and_discards_energy() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)").attach(&["Psychic Energy (BS 101)"]).damage_counters(3)
+        .bench(Player::One, "Abra (BS 43)").damage_counters(1)
+        .bench(Player::One, "Machop (BS 52)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Pokémon Center (BS 85)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Play Pokémon Center"]);
+
+    assert_eq!(active(&engine, Player::One).damage_counters, 0);
+    assert!(active(&engine, Player::One).attached.is_empty());
+    assert_eq!(bench(&engine, Player::One)[0].damage_counters, 0);
+    assert_eq!(bench(&engine, Player::One)[1].attached.len(), 1);
+}
+
+
+#[test]
+fn defender_reduces_damage_by_20() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Electabuzz (BS 20)").attach(&["Lightning Energy (BS 100)", "Lightning Energy (BS 100)", "Lightning Energy (BS 100)"])
+        .hand(Player::One, &["Defender (BS 80)"])
+        .deck(Player::Two, &["Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Defender",
+        "pick_in_play: Mewtwo",
+    ]);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Pass"]));
+    let engine = until_turn(engine, Player::Two);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Attack with Electabuzz: Thunderpunch", "flip: heads"]));
+
+    // 30 + 10 from heads, minus 20
+    assert_eq!(active(&engine, Player::One).damage_counters, 2);
+}
+
+#[test]
+fn full_heal_removes_special_conditions() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)").status(RotationalStatus::Confused).poisoned()
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Full Heal (BS 82)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Full Heal",
+        "pick_in_play: Mewtwo",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).rotational_status, RotationalStatus::None);
+    assert!(active(&engine, Player::One).poisoned.is_none());
+}
+
+#[test]
+fn revive_benches_a_basic_with_half_its_hp_as_damage() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Revive (BS 89)"])
+        .discard(Player::One, &["Electabuzz (BS 20)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Revive",
+        "pick_from_discard: Electabuzz",
+    ]);
+
+    assert_eq!(bench(&engine, Player::One)[0].stack[0].card().archetype, "Electabuzz (BS 20)");
+    assert_eq!(bench(&engine, Player::One)[0].damage_counters, 4);
+}
+
+#[test]
+fn computer_search_discards_two_to_search_any_card() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Computer Search (BS 71)", "Potion (BS 93)", "Abra (BS 43)"])
+        .deck(Player::One, &["Psychic Energy (BS 101)", "Kadabra (BS 32)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Computer Search",
+        "pick_from_hand: Potion, Abra",
+        "search_deck: Kadabra",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Kadabra (BS 32)"]);
+    assert_eq!(engine.state.side(Player::One).discard.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "no option matches")]
+fn scripted_dm_rejects_unavailable_answers() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build();
+
+    testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Mewtwo: Psychic"]);
+}
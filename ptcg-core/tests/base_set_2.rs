@@ -0,0 +1,113 @@
+use ptcg_core::*;
+use ptcg_core::formats::{self, BaseFossil};
+use ptcg_core::state::*;
+use ptcg_core::testing::{self, active};
+
+fn engine(state: GameState) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::base_set_2()))
+}
+
+#[test]
+fn reprints_behave_like_the_original() {
+    let state = testing::game(&BaseFossil::base_set_2())
+        .active(Player::One, "Electabuzz (B2 24)").attach(&["Lightning Energy (B2 128)"])
+        .active(Player::Two, "Mewtwo (B2 10)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::base_set_2(), state, &[
+        "pick_action: Attack with Electabuzz: Thundershock",
+        "flip: heads",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 1);
+    assert_eq!(active(&engine, Player::Two).rotational_status, RotationalStatus::Paralyzed);
+    assert_eq!(engine.full_hp(active(&engine, Player::One)), 70);
+}
+
+#[test]
+fn reprints_keep_their_own_identifier() {
+    let state = testing::game(&BaseFossil::base_set_2())
+        .active(Player::One, "Abra (B2 65)")
+        .active(Player::Two, "Magikarp (B2 50)")
+        .hand(Player::One, &["Kadabra (B2 46)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::base_set_2(), state, &[
+        "pick_action: Evolve into Kadabra",
+        "pick_in_play: Abra",
+    ]);
+
+    let kadabra = active(&engine, Player::One);
+    assert_eq!(kadabra.stack[0].card().archetype, "Kadabra (B2 46)");
+    assert_eq!(kadabra.stack[1].card().archetype, "Abra (B2 65)");
+}
+
+#[test]
+fn dark_pokemon_evolve_from_reprints() {
+    let state = testing::game(&BaseFossil::base_set_2())
+        .active(Player::One, "Abra (B2 65)")
+        .active(Player::Two, "Magikarp (B2 50)")
+        .hand(Player::One, &["Dark Kadabra (TR 39)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::base_set_2(), state, &[
+        "pick_action: Evolve into Dark Kadabra",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).stack[0].card().archetype, "Dark Kadabra (TR 39)");
+}
+
+#[test]
+fn base_set_2_format_is_available_by_name() {
+    let format = formats::by_name("base-set-2").unwrap();
+
+    assert_eq!(format.name(), "base-set-2");
+    assert_eq!(format.behavior_from_id("Pikachu (B2 87)").unwrap().name(), "Pikachu");
+    assert_eq!(format.behavior_from_id("Bill (B2 118)").unwrap().name(), "Bill");
+}
+
+#[test]
+fn base_set_2_format_only_has_base_set_2_trainers_and_energy() {
+    let format = BaseFossil::base_set_2();
+
+    for card in ["Bill (BS 90)", "Poké Ball (JU 64)", "Fire Energy (BS 98)", "Double Colorless Energy (BS 96)", "Clefairy Doll (BS 70)"] {
+        assert!(format.behavior_from_id(card).is_err(), "{} shouldn't be in Base Set 2", card);
+    }
+
+    let mut deck = vec!["Abra (B2 65)".to_string(); 4];
+    deck.extend(vec!["Bill (B2 118)".to_string(); 4]);
+    deck.extend(vec!["Poké Ball (B2 121)".to_string(); 4]);
+    deck.extend(vec!["Psychic Energy (B2 129)".to_string(); 48]);
+    assert!(format.validate_deck(&deck).is_legal(), "{}", format.validate_deck(&deck));
+
+    deck[4] = "Devolution Spray (BS 72)".into();
+    assert_eq!(format.validate_deck(&deck).not_in_format, vec!["Devolution Spray (BS 72)".to_string()]);
+}
+
+#[test]
+fn reprinted_trainers_and_energy_work_like_the_originals() {
+    let state = testing::game(&BaseFossil::base_set_2())
+        .active(Player::One, "Charmander (B2 69)").attach(&["Fire Energy (B2 126)"]).damage_counters(2)
+        .hand(Player::One, &["Super Potion (B2 117)"])
+        .discard(Player::One, &["Water Energy (B2 130)"])
+        .active(Player::Two, "Magikarp (B2 50)")
+        .build();
+    let engine = engine(state);
+
+    let water = engine.state.p1.discard[0].clone();
+    assert!(engine.is_basic_energy(&water));
+
+    let engine = testing::run(&BaseFossil::base_set_2(), engine.state, &[
+        "pick_action: Play Super Potion",
+        "pick_in_play: Charmander",
+        "pick_attached: Fire Energy",
+    ]);
+    assert_eq!(active(&engine, Player::One).damage_counters, 0);
+    assert!(active(&engine, Player::One).attached.is_empty());
+}
+
+#[test]
+fn base_set_2_format_does_not_include_fossil() {
+    assert!(BaseFossil::base_set_2().behavior_from_id("Zapdos (FO 15)").is_err());
+}
@@ -0,0 +1,83 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn builder() -> GameStateBuilder {
+    GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .active(Player::Two, "Magikarp (BS 35)")
+}
+
+fn checkup(format: BaseFossil, state: GameState, script: &[&str]) -> GameEngine {
+    let mut dm = ScriptedDM::new(script);
+    let engine = GameEngine::from_state(state, Box::new(format)).pokemon_checkup(Player::Two, &mut dm);
+    dm.assert_done();
+
+    engine
+}
+
+fn chansey(engine: &GameEngine) -> &InPlayCard {
+    &engine.state.p1.active[0]
+}
+
+#[test]
+fn burned_pokemon_take_damage_on_tails() {
+    let state = builder().active(Player::One, "Chansey (BS 3)").burned().build();
+
+    let engine = checkup(BaseFossil::new(), state.clone(), &["flip: tails"]);
+    assert_eq!(chansey(&engine).damage_counters, 2);
+    assert!(chansey(&engine).burned);
+
+    let engine = checkup(BaseFossil::new(), state, &["flip: heads"]);
+    assert_eq!(chansey(&engine).damage_counters, 0);
+    assert!(chansey(&engine).burned);
+}
+
+#[test]
+fn later_burns_always_hurt_but_heads_cures_them() {
+    let format = || BaseFossil::new().with_burn(BurnRules::FlipToRecover);
+    let state = builder().active(Player::One, "Chansey (BS 3)").burned().build();
+
+    let engine = checkup(format(), state.clone(), &["flip: heads"]);
+    assert_eq!(chansey(&engine).damage_counters, 2);
+    assert!(!chansey(&engine).burned);
+
+    let engine = checkup(format(), state, &["flip: tails"]);
+    assert_eq!(chansey(&engine).damage_counters, 2);
+    assert!(chansey(&engine).burned);
+}
+
+#[test]
+fn poison_and_burn_both_apply() {
+    let state = builder().active(Player::One, "Chansey (BS 3)").poisoned().burned().build();
+
+    let engine = checkup(BaseFossil::new(), state, &["flip: tails"]);
+    assert_eq!(chansey(&engine).damage_counters, 3);
+}
+
+#[test]
+fn the_format_decides_the_order_of_the_checkup() {
+    let state = builder().active(Player::One, "Chansey (BS 3)").status(RotationalStatus::Asleep).burned().build();
+
+    // burn first: tails hurts, then heads wakes it up.
+    let engine = checkup(BaseFossil::new(), state.clone(), &["flip: tails", "flip: heads"]);
+    assert_eq!(chansey(&engine).damage_counters, 2);
+    assert_eq!(chansey(&engine).rotational_status, RotationalStatus::None);
+
+    // sleep first: tails keeps it asleep, then heads spares it the burn.
+    let format = BaseFossil::new().with_pokemon_checkup(vec![CheckupStep::Sleep, CheckupStep::Burn]);
+    let engine = checkup(format, state, &["flip: tails", "flip: heads"]);
+    assert_eq!(chansey(&engine).damage_counters, 0);
+    assert_eq!(chansey(&engine).rotational_status, RotationalStatus::Asleep);
+}
+
+#[test]
+fn steps_left_out_of_the_checkup_dont_happen() {
+    let state = builder().active(Player::One, "Chansey (BS 3)").poisoned().build();
+
+    let format = BaseFossil::new().with_pokemon_checkup(vec![CheckupStep::Burn, CheckupStep::Sleep]);
+    let engine = checkup(format, state, &[]);
+    assert_eq!(chansey(&engine).damage_counters, 0);
+}
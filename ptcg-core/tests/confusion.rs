@@ -0,0 +1,114 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn state() -> GameState {
+    GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .active(Player::One, "Hitmonchan (BS 7)").status(RotationalStatus::Confused)
+        .attach(&["Fighting Energy (BS 97)", "Fighting Energy (BS 97)"])
+        .bench(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Chansey (BS 3)")
+        .build()
+}
+
+fn run(format: BaseFossil, action: fn(&GameEngine) -> Action, script: &[&str]) -> GameEngine {
+    let engine = GameEngine::from_state(state(), Box::new(format));
+    let mut dm = ScriptedDM::new(script);
+    let engine = engine.apply_action(&action(&engine), &mut dm).unwrap();
+    dm.assert_done();
+
+    engine
+}
+
+fn jab(engine: &GameEngine) -> Action {
+    let hitmonchan = engine.state.p1.active[0].clone();
+    let attack = engine.attacks(&hitmonchan).into_iter().find(|attack| attack.name() == "Jab").unwrap();
+
+    Action::Attack(Player::One, hitmonchan, attack)
+}
+
+fn retreat(engine: &GameEngine) -> Action {
+    Action::Retreat(Player::One, engine.state.p1.active[0].clone())
+}
+
+fn hitmonchan(engine: &GameEngine) -> &InPlayCard {
+    engine.state.p1.all_in_play().into_iter().find(|in_play| in_play.stack[0].card().archetype == "Hitmonchan (BS 7)").unwrap()
+}
+
+#[test]
+fn confused_pokemon_attack_on_heads() {
+    let engine = run(BaseFossil::new(), jab, &["flip: heads"]);
+
+    // Chansey is weak to Fighting.
+    assert_eq!(engine.state.p2.active[0].damage_counters, 4);
+    assert_eq!(hitmonchan(&engine).damage_counters, 0);
+}
+
+#[test]
+fn confused_pokemon_damage_themselves_on_tails() {
+    let engine = run(BaseFossil::new(), jab, &["flip: tails"]);
+
+    assert_eq!(engine.state.p2.active[0].damage_counters, 0);
+    assert_eq!(hitmonchan(&engine).damage_counters, 2);
+    assert!(engine.events().contains(&GameEvent::DamageDealt {
+        player: Player::One,
+        pokemon: hitmonchan(&engine).id,
+        base: 20,
+        after_weakness: 20,
+        after_resistance: 20,
+        dealt: 20,
+    }));
+}
+
+#[test]
+fn later_rules_put_three_counters_on_tails() {
+    let engine = run(BaseFossil::new().with_confusion(ConfusionRules::Modern), jab, &["flip: tails"]);
+
+    assert_eq!(hitmonchan(&engine).damage_counters, 3);
+}
+
+#[test]
+fn confused_pokemon_pay_the_retreat_cost_even_when_they_stay() {
+    let engine = run(BaseFossil::new(), retreat, &["pick_in_play: Magikarp", "flip: tails"]);
+
+    assert_eq!(engine.state.p1.active[0].stack[0].card().archetype, "Hitmonchan (BS 7)");
+    assert!(engine.state.p1.active[0].attached.is_empty());
+    assert_eq!(engine.state.p1.discard.len(), 2);
+}
+
+#[test]
+fn confused_pokemon_retreat_on_heads() {
+    let engine = run(BaseFossil::new(), retreat, &["pick_in_play: Magikarp", "flip: heads"]);
+
+    assert_eq!(engine.state.p1.active[0].stack[0].card().archetype, "Magikarp (BS 35)");
+}
+
+#[test]
+fn later_rules_dont_flip_to_retreat() {
+    let engine = run(BaseFossil::new().with_confusion(ConfusionRules::Modern), retreat, &["pick_in_play: Magikarp"]);
+
+    assert_eq!(engine.state.p1.active[0].stack[0].card().archetype, "Magikarp (BS 35)");
+}
+
+#[test]
+fn confused_pokemon_that_fail_to_retreat_cant_try_again_that_turn() {
+    let state = GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .active(Player::One, "Hitmonchan (BS 7)").status(RotationalStatus::Confused)
+        .attach(&["Fighting Energy (BS 97)"; 4])
+        .bench(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Chansey (BS 3)")
+        .build();
+    let engine = GameEngine::from_state(state, Box::new(BaseFossil::new()));
+    let mut dm = ScriptedDM::new(&["pick_in_play: Magikarp", "flip: tails"]);
+
+    let engine = engine.apply_action(&retreat(&engine), &mut dm).unwrap();
+
+    assert_eq!(engine.state.p1.active[0].attached.len(), 2);
+    assert!(!engine.available_actions(Player::One).iter().any(|action| matches!(action, Action::Retreat(..))));
+    assert_eq!(engine.apply_action(&retreat(&engine), &mut ScriptedDM::new(&[])).err(), Some(IllegalAction::AlreadyRetreated));
+}
@@ -0,0 +1,156 @@
+use ptcg_core::*;
+use ptcg_core::deck::{DeckError, DeckList, Section};
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::registry::UnknownCard;
+
+fn parse(text: &str) -> Result<DeckList, DeckError> {
+    DeckList::parse(text, BaseFossil::with_team_rocket().registry())
+}
+
+#[test]
+fn parses_a_ptcgl_export() {
+    let deck = parse("\
+Pokémon: 6
+4 Abra BS 43
+2 Dark Kadabra TR 39
+
+Trainer: 2
+2 Switch BS 94
+
+Energy: 4
+4 Psychic Energy BS 101
+
+Total Cards: 12
+").unwrap();
+
+    assert_eq!(deck.len(), 12);
+    assert_eq!(deck.entries[1].archetype, "Dark Kadabra (TR 39)");
+    assert_eq!(deck.entries[1].section, Some(Section::Pokemon));
+    assert_eq!(deck.entries[2].section, Some(Section::Trainer));
+    assert_eq!(deck.entries[3].section, Some(Section::Energy));
+}
+
+#[test]
+fn parses_a_ptcgo_export() {
+    let deck = parse("\
+****** Pokémon Trading Card Game Deck List ******
+
+##Pokémon - 3
+
+* 3 Kangaskhan JU 5
+
+##Energy - 2
+
+* 2 Water Energy BS 102
+
+Total Cards - 5
+").unwrap();
+
+    assert_eq!(deck.cards(), vec![
+        "Kangaskhan (JU 5)", "Kangaskhan (JU 5)", "Kangaskhan (JU 5)",
+        "Water Energy (BS 102)", "Water Energy (BS 102)",
+    ]);
+}
+
+#[test]
+fn parses_identifiers_and_comments() {
+    let deck = parse("\
+# rain dance
+4 Squirtle (BS 63)
+// the engine
+10 Water Energy
+").unwrap();
+
+    assert_eq!(deck.entries[0].archetype, "Squirtle (BS 63)");
+    assert_eq!(deck.entries[1].archetype, "Water Energy (BS 102)");
+    assert_eq!(deck.entries[1].section, None);
+}
+
+#[test]
+fn reports_unknown_cards_with_their_line() {
+    let err = parse("4 Abra BS 43\n\n2 Abar BS 200\n").unwrap_err();
+
+    assert!(matches!(err, DeckError::UnknownCard(3, UnknownCard::SetNumber(_, 200))));
+    assert_eq!(err.to_string(), "line 3: no card number 200 in set BS");
+}
+
+#[test]
+fn reports_a_name_that_does_not_match_the_set_number() {
+    let err = parse("4 Abra BS 32\n").unwrap_err();
+
+    assert_eq!(err.to_string(), "line 1: BS 32 is not a Abra");
+}
+
+#[test]
+fn reports_a_missing_count() {
+    let err = parse("Pokémon: 4\nAbra BS 43\n").unwrap_err();
+
+    assert!(matches!(err, DeckError::Syntax(2, _)));
+}
+
+fn deck(cards: &[(usize, &str)]) -> Vec<String> {
+    cards.iter().flat_map(|(count, card)| vec![card.to_string(); *count]).collect()
+}
+
+fn legal_deck() -> Vec<String> {
+    deck(&[
+        (4, "Abra (BS 43)"),
+        (3, "Kadabra (BS 32)"),
+        (2, "Alakazam (BS 1)"),
+        (4, "Professor Oak (BS 88)"),
+        (4, "Computer Search (BS 71)"),
+        (43, "Psychic Energy (BS 101)"),
+    ])
+}
+
+#[test]
+fn a_60_card_deck_with_a_basic_is_legal() {
+    let report = BaseFossil::new().validate_deck(&legal_deck());
+
+    assert!(report.is_legal(), "{}", report);
+    assert_eq!(report.to_string(), "legal");
+}
+
+#[test]
+fn copies_are_counted_by_name_across_printings() {
+    let mut cards = legal_deck();
+    cards.pop();
+    cards.push("Abra (TR 49)".into());
+
+    let report = BaseFossil::with_team_rocket().validate_deck(&cards);
+
+    assert_eq!(report.too_many_copies, vec![("Abra".to_string(), 5)]);
+}
+
+#[test]
+fn reports_every_construction_problem() {
+    let cards = deck(&[
+        (5, "Professor Oak (BS 88)"),
+        (1, "Dark Kadabra (TR 39)"),
+        (10, "Psychic Energy (BS 101)"),
+    ]);
+
+    let report = BaseFossil::new().validate_deck(&cards);
+
+    assert!(!report.is_legal());
+    assert_eq!(report.to_string(), "16 cards instead of 60, 5 copies of Professor Oak (at most 4), Dark Kadabra (TR 39) is not legal in this format, no Basic Pokémon");
+}
+
+#[test]
+fn initial_refuses_illegal_decks_unless_casual() {
+    let format = BaseFossil::new();
+    let small = deck(&[(5, "Abra (BS 43)"), (5, "Psychic Energy (BS 101)")]);
+
+    let err = GameState::initial(&legal_deck(), &small, &format, false).err().unwrap();
+    assert_eq!(err.player, Player::Two);
+    assert!(GameState::initial(&legal_deck(), &small, &format, true).is_ok());
+    assert!(GameState::initial(&legal_deck(), &legal_deck(), &format, false).is_ok());
+}
+
+#[test]
+fn casual_games_still_need_a_basic_pokemon() {
+    let format = BaseFossil::new();
+    let no_basics = deck(&[(60, "Psychic Energy (BS 101)")]);
+
+    assert!(GameState::initial(&no_basics, &legal_deck(), &format, true).is_err());
+}
@@ -0,0 +1,69 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::testing::GameStateBuilder;
+
+fn engine() -> GameEngine {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Charmander (BS 46)")
+        .attach(&["Double Colorless Energy (BS 96)", "Rainbow Energy (TR 80)", "Fire Energy (BS 98)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build();
+
+    GameEngine::from_state(state, Box::new(BaseFossil::with_team_rocket()))
+}
+
+fn attached(engine: &GameEngine) -> Vec<Card> {
+    engine.state.p1.active[0].attached.iter().map(|attached| attached.card().clone()).collect()
+}
+
+#[test]
+fn colorless_can_come_before_typed_requirements() {
+    let engine = engine();
+
+    assert!(engine.is_energy_cost_met(&[Type::Colorless, Type::Fire], vec![Type::Water, Type::Fire]));
+    assert!(engine.is_energy_cost_met(&[Type::Colorless, Type::Colorless, Type::Water], vec![Type::Water, Type::Colorless, Type::Colorless]));
+    assert!(!engine.is_energy_cost_met(&[Type::Colorless, Type::Fire], vec![Type::Colorless, Type::Colorless]));
+}
+
+#[test]
+fn wildcards_are_kept_for_the_symbols_that_need_them() {
+    let engine = engine();
+
+    assert!(engine.is_energy_cost_met(&[Type::Colorless, Type::Fire, Type::Water], vec![Type::Any, Type::Fire, Type::Psychic]));
+    assert!(engine.is_energy_cost_met(&[Type::Fire, Type::Water], vec![Type::Fire, Type::Any]));
+    assert!(!engine.is_energy_cost_met(&[Type::Fire, Type::Water, Type::Colorless], vec![Type::Fire, Type::Any]));
+}
+
+#[test]
+fn missing_energy_counts_the_unpaid_symbols() {
+    let engine = engine();
+
+    assert_eq!(engine.missing_energy(&[Type::Fire, Type::Fire, Type::Colorless], vec![Type::Water]), 2);
+    assert_eq!(engine.missing_energy(&[Type::Colorless, Type::Water], vec![Type::Water, Type::Any]), 0);
+    assert_eq!(engine.missing_energy(&[Type::Water, Type::Water], vec![]), 2);
+}
+
+#[test]
+fn payment_tells_which_card_pays_each_symbol() {
+    let engine = engine();
+    let cards = attached(&engine);
+    let (dce, rainbow, fire) = (&cards[0], &cards[1], &cards[2]);
+
+    let payment = engine.energy_payment(&[Type::Colorless, Type::Fire, Type::Colorless], &cards).unwrap();
+    assert_eq!(payment, vec![dce.clone(), fire.clone(), dce.clone()]);
+
+    let payment = engine.energy_payment(&[Type::Fire, Type::Water], &cards).unwrap();
+    assert_eq!(payment, vec![fire.clone(), rainbow.clone()]);
+
+    assert_eq!(engine.energy_payment(&[Type::Fire, Type::Fire, Type::Fire], &cards), None);
+}
+
+#[test]
+fn attack_payment_only_uses_attached_energy() {
+    let engine = engine();
+    let charmander = engine.state.p1.active[0].clone();
+
+    let payment = engine.attack_energy_payment(&charmander, &[Type::Water, Type::Colorless, Type::Colorless, Type::Fire]).unwrap();
+    assert_eq!(payment.len(), 4);
+    assert!(engine.attack_energy_payment(&charmander, &vec![Type::Colorless; 5]).is_none());
+}
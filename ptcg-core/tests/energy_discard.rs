@@ -0,0 +1,152 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn builder() -> GameStateBuilder {
+    GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+}
+
+fn engine(state: GameState) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+fn attached(in_play: &InPlayCard) -> Vec<String> {
+    let mut archetypes = in_play.attached.iter().map(|attached| attached.card().archetype.clone()).collect::<Vec<_>>();
+    archetypes.sort();
+    archetypes
+}
+
+fn attached_labels(cards: &[Card]) -> String {
+    let mut archetypes = cards.iter().map(|card| card.archetype.as_str()).collect::<Vec<_>>();
+    archetypes.sort();
+    archetypes.join(" + ")
+}
+
+fn attack(engine: &GameEngine, name: &str) -> Action {
+    let in_play = engine.state.p1.active[0].clone();
+    let attack = engine.attacks(&in_play).into_iter().find(|attack| attack.name() == name).unwrap();
+
+    Action::Attack(Player::One, in_play, attack)
+}
+
+#[test]
+fn retreating_discards_the_energy_the_player_picks() {
+    let engine = engine(builder()
+        .active(Player::One, "Hitmonchan (BS 7)")
+        .attach(&["Double Colorless Energy (BS 96)", "Fighting Energy (BS 97)", "Fighting Energy (BS 97)"])
+        .bench(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build());
+    let hitmonchan = engine.state.p1.active[0].clone();
+
+    let options = engine.energy_discard_options(&hitmonchan, &engine.retreat_cost(&hitmonchan));
+    assert_eq!(options.len(), 2);
+
+    let mut dm = ScriptedDM::new(&[
+        "pick_in_play: Magikarp",
+        "pick_energy_discard: Fighting Energy + Fighting Energy",
+    ]);
+    let engine = engine.apply_action(&Action::Retreat(Player::One, hitmonchan.clone()), &mut dm).unwrap();
+
+    dm.assert_done();
+    let hitmonchan = engine.state.p1.in_play(&hitmonchan.id).unwrap();
+    assert_eq!(attached(hitmonchan), vec!["Double Colorless Energy (BS 96)"]);
+    assert_eq!(engine.state.p1.discard.len(), 2);
+}
+
+#[test]
+fn there_is_nothing_to_pick_when_only_one_discard_pays_the_cost() {
+    let engine = engine(builder()
+        .active(Player::One, "Hitmonchan (BS 7)")
+        .attach(&["Fighting Energy (BS 97)", "Fighting Energy (BS 97)", "Fighting Energy (BS 97)"])
+        .bench(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build());
+    let hitmonchan = engine.state.p1.active[0].clone();
+
+    let mut dm = ScriptedDM::new(&["pick_in_play: Magikarp"]);
+    let engine = engine.apply_action(&Action::Retreat(Player::One, hitmonchan.clone()), &mut dm).unwrap();
+
+    dm.assert_done();
+    assert_eq!(engine.state.p1.in_play(&hitmonchan.id).unwrap().attached.len(), 1);
+}
+
+#[test]
+fn discard_costs_count_energy_cards() {
+    let engine = engine(builder()
+        .active(Player::One, "Charizard (BS 4)")
+        .attach(&["Double Colorless Energy (BS 96)", "Fire Energy (BS 98)", "Fire Energy (BS 98)", "Fire Energy (BS 98)", "Fire Energy (BS 98)"])
+        .active(Player::Two, "Chansey (BS 3)")
+        .build());
+    let charizard = engine.state.p1.active[0].clone();
+
+    let options = engine.energy_card_discard_options(&charizard, &[Type::Any, Type::Any]);
+    assert!(options.iter().all(|option| option.len() == 2));
+    assert_eq!(options.len(), 2);
+
+    let mut dm = ScriptedDM::new(&["pick_energy_discard: Double Colorless Energy + Fire Energy"]);
+    let engine = engine.apply_action(&attack(&engine, "Fire Spin"), &mut dm).unwrap();
+
+    dm.assert_done();
+    assert_eq!(attached(engine.state.p1.in_play(&charizard.id).unwrap()), vec!["Fire Energy (BS 98)"; 3]);
+    assert_eq!(engine.state.p2.active[0].damage_counters, 10);
+}
+
+#[test]
+fn typed_discard_costs_leave_other_energy_attached() {
+    let engine = engine(builder()
+        .active(Player::One, "Charmander (BS 46)")
+        .attach(&["Double Colorless Energy (BS 96)", "Fire Energy (BS 98)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .build());
+    let charmander = engine.state.p1.active[0].clone();
+
+    let mut dm = ScriptedDM::new(&[]);
+    let engine = engine.apply_action(&attack(&engine, "Ember"), &mut dm).unwrap();
+
+    dm.assert_done();
+    assert_eq!(attached(engine.state.p1.in_play(&charmander.id).unwrap()), vec!["Double Colorless Energy (BS 96)"]);
+    assert_eq!(engine.state.p2.active[0].damage_counters, 3);
+}
+
+#[test]
+fn no_energy_means_nothing_to_discard() {
+    let engine = engine(builder()
+        .active(Player::One, "Charmander (BS 46)")
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .build());
+    let charmander = engine.state.p1.active[0].clone();
+
+    assert!(engine.energy_card_discard_options(&charmander, &[Type::Fire]).is_empty());
+    assert!(engine.energy_discard_options(&charmander, &[Type::Colorless]).is_empty());
+}
+
+#[test]
+fn lots_of_attached_energy_still_has_a_few_options() {
+    let mut energy = vec!["Fire Energy (BS 98)"; 40];
+    energy.extend(["Fighting Energy (BS 97)"; 20]);
+    energy.extend(["Double Colorless Energy (BS 96)"; 10]);
+    let engine = engine(builder()
+        .active(Player::One, "Charizard (BS 4)").attach(&energy)
+        .active(Player::Two, "Chansey (BS 3)")
+        .build());
+    let charizard = engine.state.p1.active[0].clone();
+
+    let options = engine.energy_discard_options(&charizard, &[Type::Colorless, Type::Colorless, Type::Colorless]);
+    let mut labels = options.iter().map(|option| attached_labels(option)).collect::<Vec<_>>();
+    labels.sort();
+    assert_eq!(labels, vec![
+        "Double Colorless Energy (BS 96) + Double Colorless Energy (BS 96)",
+        "Double Colorless Energy (BS 96) + Fighting Energy (BS 97)",
+        "Double Colorless Energy (BS 96) + Fire Energy (BS 98)",
+        "Fighting Energy (BS 97) + Fighting Energy (BS 97) + Fighting Energy (BS 97)",
+        "Fighting Energy (BS 97) + Fighting Energy (BS 97) + Fire Energy (BS 98)",
+        "Fighting Energy (BS 97) + Fire Energy (BS 98) + Fire Energy (BS 98)",
+        "Fire Energy (BS 98) + Fire Energy (BS 98) + Fire Energy (BS 98)",
+    ]);
+
+    let options = engine.energy_card_discard_options(&charizard, &[Type::Any, Type::Any]);
+    assert_eq!(options.len(), 6);
+}
@@ -0,0 +1,105 @@
+use ptcg_core::*;
+use ptcg_core::events::SpecialCondition;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn engine(state: GameState) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+fn active(engine: &GameEngine, player: Player) -> InPlayCard {
+    engine.state.side(player).active[0].clone()
+}
+
+#[test]
+fn attacks_report_the_damage_before_and_after_weakness() {
+    let engine = engine(GameStateBuilder::new()
+        .active(Player::One, "Electabuzz (BS 20)").attach(&["Lightning Energy (BS 100)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .prizes(Player::One, &["Potion (BS 93)"])
+        .prizes(Player::Two, &["Potion (BS 93)"])
+        .build());
+    let electabuzz = active(&engine, Player::One);
+    let magikarp = active(&engine, Player::Two);
+    let mut dm = ScriptedDM::new(&[
+        "pick_action: Attack with Electabuzz: Thundershock",
+        "flip: heads",
+    ]);
+
+    let engine = engine.step(&mut dm);
+
+    dm.assert_done();
+    assert_eq!(engine.events(), &[
+        GameEvent::AttackDeclared { player: Player::One, pokemon: electabuzz.id, attack: "Thundershock".into() },
+        GameEvent::CoinFlipped { player: Player::One, results: vec![true] },
+        GameEvent::DamageDealt { player: Player::Two, pokemon: magikarp.id, base: 10, after_weakness: 20, after_resistance: 20, dealt: 20 },
+        GameEvent::EffectAdded { name: "Damaged by attack".into(), target: EffectTarget::InPlayPokemon(Player::Two, magikarp.id) },
+        GameEvent::SpecialConditionApplied { player: Player::Two, pokemon: magikarp.id, condition: SpecialCondition::Paralyzed },
+        GameEvent::TurnEnded { player: Player::One, turn: 3 },
+    ]);
+}
+
+#[test]
+fn resistance_shows_up_in_the_damage_numbers() {
+    let engine = engine(GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Gyarados (BS 6)")
+        .build());
+    let gyarados = active(&engine, Player::Two);
+    let mut dm = ScriptedDM::new(&["pick_action: Attack with Hitmonchan: Jab"]);
+
+    let engine = engine.step(&mut dm);
+
+    let damage = engine.events().iter().find(|e| matches!(e, GameEvent::DamageDealt { .. }));
+    assert_eq!(damage, Some(&GameEvent::DamageDealt { player: Player::Two, pokemon: gyarados.id, base: 20, after_weakness: 20, after_resistance: 0, dealt: 0 }));
+}
+
+#[test]
+fn knock_outs_and_prizes_are_reported() {
+    let engine = engine(GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)").damage_counters(1)
+        .bench(Player::Two, "Magikarp (BS 35)")
+        .prizes(Player::One, &["Potion (BS 93)", "Switch (BS 94)"])
+        .build());
+    let magikarp = active(&engine, Player::Two);
+    let switch = engine.state.side(Player::One).prizes[1].card.card().clone();
+    let mut dm = ScriptedDM::new(&[
+        "pick_action: Attack with Hitmonchan: Jab",
+        "pick_from_prizes: Prize #2",
+        "pick_in_play: Magikarp",
+    ]);
+
+    let engine = engine.step(&mut dm);
+
+    dm.assert_done();
+    let knocked_out = engine.events().iter().position(|e| e == &GameEvent::KnockedOut { player: Player::Two, pokemon: magikarp.id });
+    let prize = engine.events().iter().position(|e| e == &GameEvent::PrizeTaken { card: switch.clone() });
+    assert!(knocked_out.is_some());
+    assert!(prize > knocked_out);
+}
+
+#[test]
+fn events_only_cover_the_last_step() {
+    let engine = engine(GameStateBuilder::new()
+        .stage(GameStage::StartOfTurn(Player::One))
+        .active(Player::One, "Electabuzz (BS 20)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .deck(Player::One, &["Lightning Energy (BS 100)"])
+        .build());
+    let energy = engine.state.side(Player::One).deck.cards()[0].clone();
+    let mut dm = ScriptedDM::new(&[
+        "pick_action: Attach Lightning Energy (BS 100)",
+        "pick_in_play: Electabuzz",
+    ]);
+
+    let engine = engine.step(&mut dm);
+    assert_eq!(engine.events(), &[GameEvent::CardDrawn { card: energy.clone() }]);
+
+    let engine = engine.step(&mut dm);
+    dm.assert_done();
+    assert_eq!(engine.events(), &[
+        GameEvent::EnergyAttached { card: energy, player: Player::One, pokemon: active(&engine, Player::One).id },
+    ]);
+}
@@ -0,0 +1,125 @@
+use ptcg_core::*;
+use ptcg_core::ai::RandomDM;
+use ptcg_core::events::GameEvent;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn builder() -> GameStateBuilder {
+    GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+}
+
+// Only the turns in `turns` have been played, the last one is going on.
+fn after_turns(mut state: GameState, turns: &[Player]) -> GameState {
+    let player = *turns.last().unwrap();
+    state.turns = turns.to_vec();
+    state.turn = turns.len();
+    state.stage = GameStage::Turn(player);
+    state
+}
+
+fn engine(state: GameState, restrictions: &[FirstTurnRestriction]) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::new().with_first_turn_restrictions(restrictions.to_vec())))
+}
+
+fn attack(engine: &GameEngine, player: Player, name: &str) -> Action {
+    let in_play = engine.state.side(player).active[0].clone();
+    let attack = engine.attacks(&in_play).into_iter().find(|attack| attack.name() == name).unwrap();
+
+    Action::Attack(player, in_play, attack)
+}
+
+fn available(engine: &GameEngine, player: Player, action: &Action) -> bool {
+    engine.available_actions(player).iter().any(|available| available.same_as(action))
+}
+
+#[test]
+fn the_winner_of_the_coin_flip_decides_who_goes_first() {
+    let engine = GameEngine::from_state(builder().build(), Box::new(BaseFossil::new()));
+
+    let mut dm = ScriptedDM::new(&["flip: heads", "confirm_going_first: second"]);
+    let (flipped, first) = engine.setup_first_player(&mut dm);
+    dm.assert_done();
+    assert_eq!(Player::Two, first);
+    assert!(flipped.events().contains(&GameEvent::GoingFirst { player: Player::Two }));
+
+    let mut dm = ScriptedDM::new(&["flip: tails", "confirm_going_first: first"]);
+    let (_, first) = engine.setup_first_player(&mut dm);
+    dm.assert_done();
+    assert_eq!(Player::Two, first);
+}
+
+#[test]
+fn setup_starts_the_turn_of_whoever_goes_first() {
+    let deck = vec!["Machop (BS 52)".to_string(); 20];
+    let state = GameState::initial(&deck, &deck, &BaseFossil::new(), true).unwrap();
+
+    for seed in 0..8 {
+        let engine = GameEngine::from_state(state.clone(), Box::new(BaseFossil::new())).step(&mut RandomDM::new(seed));
+        let first = engine.events().iter().find_map(|event| match event {
+            GameEvent::GoingFirst { player } => Some(*player),
+            _ => None,
+        }).unwrap();
+
+        assert_eq!(GameStage::StartOfTurn(first), engine.state.stage);
+        assert_eq!(vec![first], engine.state.turns);
+    }
+}
+
+#[test]
+fn the_player_going_first_cant_attack_when_the_format_says_so() {
+    let state = after_turns(builder()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build(), &[Player::One]);
+
+    let classic = engine(state.clone(), &[]);
+    assert!(available(&classic, Player::One, &attack(&classic, Player::One, "Jab")));
+
+    let modern = engine(state, &[FirstTurnRestriction::NoAttack]);
+    assert!(!modern.available_actions(Player::One).iter().any(|action| matches!(action, Action::Attack(..))));
+    let result = modern.apply_action(&attack(&modern, Player::One, "Jab"), &mut ScriptedDM::new(&[]));
+    assert_eq!(result.err(), Some(IllegalAction::FirstTurn));
+}
+
+#[test]
+fn the_player_going_second_can_attack_on_their_first_turn() {
+    let engine = engine(after_turns(builder()
+        .active(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .build(), &[Player::One, Player::Two]), &[FirstTurnRestriction::NoAttack]);
+
+    assert!(available(&engine, Player::Two, &attack(&engine, Player::Two, "Jab")));
+}
+
+#[test]
+fn nobody_evolves_on_their_first_turn_in_base_fossil() {
+    let state = after_turns(builder()
+        .active(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Machop (BS 52)")
+        .hand(Player::Two, &["Machoke (BS 34)"])
+        .build(), &[Player::One, Player::Two]);
+
+    let engine = GameEngine::from_state(state.clone(), Box::new(BaseFossil::new()));
+    let evolve = Action::EvolveFromHand(Player::Two, engine.state.p2.hand[0].clone());
+    assert!(!available(&engine, Player::Two, &evolve));
+    assert_eq!(engine.apply_action(&evolve, &mut ScriptedDM::new(&[])).err(), Some(IllegalAction::FirstTurn));
+
+    let unrestricted = self::engine(state, &[]);
+    assert!(available(&unrestricted, Player::Two, &evolve));
+}
+
+#[test]
+fn trainers_that_arent_supporters_can_be_played_on_the_first_turn() {
+    let engine = engine(after_turns(builder()
+        .active(Player::One, "Magikarp (BS 35)").damage_counters(1)
+        .hand(Player::One, &["Potion (BS 93)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build(), &[Player::One]), &[FirstTurnRestriction::NoSupporter]);
+
+    let potion = engine.state.p1.hand[0].clone();
+    assert!(!engine.is_supporter(&potion));
+    assert!(available(&engine, Player::One, &Action::TrainerFromHand(Player::One, potion)));
+}
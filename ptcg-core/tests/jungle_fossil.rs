@@ -0,0 +1,471 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{self, until_turn, active, bench, names, ScriptedDM};
+
+#[test]
+fn pidgeot_hurricane_returns_the_defending_pokemon_to_hand() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Pidgey (BS 57)").evolve_into("Pidgeotto (BS 22)").evolve_into("Pidgeot (JU 8)")
+            .attach(&["Water Energy (BS 102)", "Water Energy (BS 102)", "Water Energy (BS 102)"])
+        .active(Player::Two, "Mewtwo (BS 10)").attach(&["Psychic Energy (BS 101)"])
+        .bench(Player::Two, "Magikarp (BS 35)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Pidgeot: Hurricane",
+        "pick_in_play: Magikarp",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::Two).hand), vec!["Mewtwo (BS 10)", "Psychic Energy (BS 101)"]);
+    assert_eq!(active(&engine, Player::Two).stack[0].card().archetype, "Magikarp (BS 35)");
+}
+
+#[test]
+fn victreebel_acid_prevents_retreat_on_heads() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Bellsprout (JU 49)").evolve_into("Weepinbell (JU 48)").evolve_into("Victreebel (JU 14)")
+            .attach(&["Grass Energy (BS 99)", "Grass Energy (BS 99)"])
+        .active(Player::Two, "Mewtwo (BS 10)").attach(&["Psychic Energy (BS 101)", "Psychic Energy (BS 101)"])
+        .bench(Player::Two, "Magikarp (BS 35)")
+        .deck(Player::Two, &["Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Victreebel: Acid",
+        "flip: heads",
+    ]);
+    let engine = until_turn(engine, Player::Two);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 2);
+    assert!(!engine.can_retreat(Player::Two, active(&engine, Player::Two)));
+}
+
+#[test]
+fn scyther_swords_dance_powers_up_slash() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Scyther (JU 10)")
+            .attach(&["Grass Energy (BS 99)", "Grass Energy (BS 99)", "Grass Energy (BS 99)"])
+        .active(Player::Two, "Chansey (BS 3)")
+        .deck(Player::One, &["Water Energy (BS 102)"])
+        .deck(Player::Two, &["Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Scyther: Swords Dance"]);
+    let engine = until_turn(engine, Player::Two);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Pass"]));
+    let engine = until_turn(engine, Player::One);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Attack with Scyther: Slash"]));
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 6);
+}
+
+#[test]
+fn spearow_mirror_move_repeats_the_damage_it_took() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Magikarp (BS 35)").attach(&["Water Energy (BS 102)"])
+        .active(Player::Two, "Spearow (JU 62)")
+            .attach(&["Water Energy (BS 102)", "Water Energy (BS 102)", "Water Energy (BS 102)"])
+        .deck(Player::Two, &["Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Magikarp: Tackle"]);
+    let engine = until_turn(engine, Player::Two);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Attack with Spearow: Mirror Move"]));
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 1);
+    assert_eq!(active(&engine, Player::One).damage_counters, 1);
+}
+
+#[test]
+fn spearow_mirror_move_needs_an_attack_last_turn() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Spearow (JU 62)")
+            .attach(&["Water Energy (BS 102)", "Water Energy (BS 102)", "Water Energy (BS 102)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Spearow: Mirror Move"]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 0);
+}
+
+#[test]
+fn hypno_prophecy_rearranges_the_opponents_deck() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Drowzee (BS 49)").evolve_into("Hypno (FO 8)").attach(&["Psychic Energy (BS 101)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .deck(Player::Two, &["Abra (BS 43)", "Potion (BS 93)", "Switch (BS 94)", "Bill (BS 90)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Hypno: Prophecy",
+        "pick_player: Two",
+        "rearrange: Switch, Abra, Potion",
+    ]);
+
+    let deck = engine.state.side(Player::Two).deck.cards();
+    assert_eq!(names(&deck), vec!["Switch (BS 94)", "Abra (BS 43)", "Potion (BS 93)", "Bill (BS 90)"]);
+}
+
+#[test]
+fn zapdos_thunderstorm_hits_the_bench_on_heads_and_itself_on_tails() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Zapdos (FO 15)")
+            .attach(&["Lightning Energy (BS 100)", "Lightning Energy (BS 100)", "Lightning Energy (BS 100)", "Lightning Energy (BS 100)"])
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .bench(Player::Two, "Abra (BS 43)")
+        .bench(Player::Two, "Magikarp (BS 35)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Attack with Zapdos: Thunderstorm",
+        "flip: heads, tails",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 4);
+    assert_eq!(bench(&engine, Player::Two)[0].damage_counters, 2);
+    assert_eq!(bench(&engine, Player::Two)[1].damage_counters, 0);
+    assert_eq!(active(&engine, Player::One).damage_counters, 1);
+}
+
+#[test]
+fn poke_ball_searches_for_a_pokemon_on_heads() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Poké Ball (JU 64)"])
+        .deck(Player::One, &["Potion (BS 93)", "Abra (BS 43)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Poké Ball",
+        "flip: heads",
+        "search_deck: Abra",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Abra (BS 43)"]);
+    assert_eq!(names(&engine.state.side(Player::One).discard), vec!["Poké Ball (JU 64)"]);
+}
+
+#[test]
+fn mr_fuji_shuffles_a_benched_pokemon_into_the_deck() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .bench(Player::One, "Abra (BS 43)").attach(&["Psychic Energy (BS 101)"]).damage_counters(1)
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Mr. Fuji (FO 58)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Mr. Fuji",
+        "pick_in_play: Abra",
+    ]);
+
+    assert!(bench(&engine, Player::One).is_empty());
+    assert_eq!(engine.state.side(Player::One).deck.len(), 2);
+}
+
+#[test]
+fn energy_search_takes_a_basic_energy() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Energy Search (FO 59)"])
+        .deck(Player::One, &["Abra (BS 43)", "Double Colorless Energy (BS 96)", "Psychic Energy (BS 101)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Energy Search",
+        "search_deck: Psychic Energy",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Psychic Energy (BS 101)"]);
+}
+
+#[test]
+fn mysterious_fossil_is_benched_and_evolves_into_kabuto() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Mysterious Fossil (FO 62)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Play Mysterious Fossil"]);
+
+    let fossil = &bench(&engine, Player::One)[0];
+    assert_eq!(fossil.stack[0].card().archetype, "Mysterious Fossil (FO 62)");
+    assert_eq!(engine.full_hp(fossil), 10);
+}
+
+#[test]
+fn recall_uses_an_attack_from_a_lower_stage() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Abra (BS 43)").evolve_into("Kadabra (BS 32)").attach(&["Psychic Energy (BS 101)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Recall (FO 61)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Play Recall",
+        "pick_in_play: Kadabra",
+        "pick_attack: Psyshock",
+    ]);
+    let engine = engine.step(&mut ScriptedDM::new(&[
+        "pick_action: Attack with Kadabra: Psyshock",
+        "flip: tails",
+    ]));
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 1);
+}
+
+fn engine(state: GameState) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+fn poke_powers(engine: &GameEngine, player: Player) -> Vec<String> {
+    engine.available_actions(player).iter()
+        .filter_map(|action| match action {
+            Action::PokePower(_, _, poke_power) => Some(poke_power.name().clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn aerodactyl_prehistoric_power_stops_evolution_until_it_is_asleep() {
+    let state = |status| testing::game(&BaseFossil::new())
+        .active(Player::One, "Mysterious Fossil (FO 62)").evolve_into("Aerodactyl (FO 1)").status(status)
+        .bench(Player::One, "Charmander (BS 46)")
+        .hand(Player::One, &["Charmeleon (BS 24)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build();
+    let can_evolve = |engine: GameEngine| engine.can_evolve(&engine.state.side(Player::One).hand[0]);
+
+    assert!(!can_evolve(engine(state(RotationalStatus::None))));
+    assert!(can_evolve(engine(state(RotationalStatus::Asleep))));
+}
+
+#[test]
+fn dragonite_step_in_switches_it_in_from_the_bench_once_a_turn() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Magikarp (BS 35)")
+        .bench(Player::One, "Dratini (BS 26)").evolve_into("Dragonair (BS 18)").evolve_into("Dragonite (FO 4)")
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Use Dragonite (FO 4)'s PokéPower: Step In"]);
+
+    assert_eq!(active(&engine, Player::One).stack[0].card().archetype, "Dragonite (FO 4)");
+    assert_eq!(bench(&engine, Player::One)[0].stack[0].card().archetype, "Magikarp (BS 35)");
+    assert!(poke_powers(&engine, Player::One).is_empty());
+}
+
+#[test]
+fn gengar_curse_moves_a_damage_counter_even_if_it_knocks_out() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Gastly (FO 33)").evolve_into("Haunter (BS 29)").evolve_into("Gengar (FO 5)")
+        .active(Player::Two, "Mewtwo (BS 10)").damage_counters(1)
+        .bench(Player::Two, "Magikarp (BS 35)").damage_counters(2)
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Use Gengar (FO 5)'s PokéPower: Curse",
+        "pick_move_damage_counters: Mewtwo -> Magikarp x1",
+        "pick_from_prizes: Prize #1",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 0);
+    assert!(bench(&engine, Player::Two).is_empty());
+    assert!(poke_powers(&engine, Player::One).is_empty());
+}
+
+#[test]
+fn muk_toxic_gas_turns_off_the_other_pokemon_powers() {
+    let builder = || testing::game(&BaseFossil::new())
+        .active(Player::One, "Gastly (FO 33)").evolve_into("Haunter (BS 29)").evolve_into("Gengar (FO 5)")
+        .active(Player::Two, "Mewtwo (BS 10)").damage_counters(1)
+        .bench(Player::Two, "Magikarp (BS 35)");
+    let with_muk = builder().bench(Player::Two, "Grimer (FO 48)").evolve_into("Muk (FO 13)");
+
+    assert_eq!(poke_powers(&engine(builder().build()), Player::One), vec!["Curse"]);
+    assert!(poke_powers(&engine(with_muk.build()), Player::One).is_empty());
+}
+
+#[test]
+fn kabuto_armor_halves_the_damage_it_takes() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Machop (BS 52)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Kabuto (FO 50)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Machop: Low Kick"]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 1);
+}
+
+#[test]
+fn omanyte_clairvoyance_shows_the_opponents_hand() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Magikarp (BS 35)")
+        .bench(Player::One, "Omanyte (FO 52)")
+        .hand(Player::One, &["Water Energy (BS 102)"])
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .hand(Player::Two, &["Psychic Energy (BS 101)"])
+        .build();
+    let engine = engine(state);
+
+    let seen = engine.view(Player::One).side(Player::Two).hand.clone();
+    assert_eq!(seen, vec![Some(engine.state.side(Player::Two).hand[0].clone())]);
+    assert_eq!(engine.view(Player::Two).side(Player::One).hand, vec![None]);
+}
+
+#[test]
+fn tentacool_cowardice_returns_it_to_hand_but_not_the_turn_it_was_benched() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Tentacool (FO 56)").attach(&["Water Energy (BS 102)"])
+        .bench(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Use Tentacool (FO 56)'s PokéPower: Cowardice",
+        "pick_in_play: Magikarp",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Tentacool (FO 56)"]);
+    assert_eq!(names(&engine.state.side(Player::One).discard), vec!["Water Energy (BS 102)"]);
+    assert_eq!(active(&engine, Player::One).stack[0].card().archetype, "Magikarp (BS 35)");
+
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Bench Tentacool (FO 56)"]));
+
+    assert!(poke_powers(&engine, Player::One).is_empty());
+}
+
+#[test]
+fn mr_mime_invisible_wall_prevents_30_damage_or_more() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Scyther (JU 10)")
+            .attach(&["Grass Energy (BS 99)", "Grass Energy (BS 99)", "Grass Energy (BS 99)"])
+        .active(Player::Two, "Mr. Mime (JU 6)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Scyther: Slash"]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 0);
+}
+
+#[test]
+fn snorlax_thick_skinned_keeps_it_awake() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Jigglypuff (JU 54)").attach(&["Water Energy (BS 102)"])
+        .active(Player::Two, "Snorlax (JU 11)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Jigglypuff: Lullaby"]);
+
+    assert_eq!(active(&engine, Player::Two).rotational_status, RotationalStatus::None);
+}
+
+#[test]
+fn venomoth_shift_takes_the_type_of_another_pokemon_for_the_turn() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Venonat (JU 63)").evolve_into("Venomoth (JU 13)")
+        .active(Player::Two, "Charmander (BS 46)")
+        .deck(Player::One, &["Water Energy (BS 102)"])
+        .deck(Player::Two, &["Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Use Venomoth (JU 13)'s PokéPower: Shift",
+        "pick_in_play: Charmander",
+    ]);
+
+    assert_eq!(engine.pokemon_types(active(&engine, Player::One)), vec![Type::Fire]);
+    assert!(poke_powers(&engine, Player::One).is_empty());
+
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Pass"]));
+    let engine = until_turn(engine, Player::Two);
+
+    assert_eq!(engine.pokemon_types(active(&engine, Player::One)), vec![Type::Grass]);
+}
+
+#[test]
+fn vileplume_heal_removes_a_damage_counter_on_heads() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Oddish (JU 58)").evolve_into("Gloom (JU 37)").evolve_into("Vileplume (JU 15)")
+        .bench(Player::One, "Magikarp (BS 35)").damage_counters(2)
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &[
+        "pick_action: Use Vileplume (JU 15)'s PokéPower: Heal",
+        "flip: heads",
+        "pick_in_play: Magikarp",
+    ]);
+
+    assert_eq!(bench(&engine, Player::One)[0].damage_counters, 1);
+    assert!(poke_powers(&engine, Player::One).is_empty());
+}
+
+#[test]
+fn dodrio_retreat_aid_lowers_the_active_pokemons_retreat_cost() {
+    let state = |status| testing::game(&BaseFossil::new())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .bench(Player::One, "Doduo (BS 48)").evolve_into("Dodrio (JU 34)").status(status)
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build();
+    let retreat_cost = |engine: GameEngine| engine.retreat_cost(active(&engine, Player::One)).len();
+
+    assert_eq!(retreat_cost(engine(state(RotationalStatus::None))), 2);
+    assert_eq!(retreat_cost(engine(state(RotationalStatus::Confused))), 3);
+}
+
+#[test]
+fn ditto_transform_copies_the_defending_pokemon() {
+    let state = |status| testing::game(&BaseFossil::new())
+        .active(Player::One, "Ditto (FO 3)").attach(&["Water Energy (BS 102)"]).status(status)
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .build();
+    let attacks = |engine: &GameEngine| engine.attacks(active(engine, Player::One)).iter().map(|a| a.name().clone()).collect::<Vec<_>>();
+
+    let transformed = engine(state(RotationalStatus::None));
+    let ditto = active(&transformed, Player::One);
+    assert_eq!(transformed.full_hp(ditto), 70);
+    assert_eq!(transformed.pokemon_types(ditto), vec![Type::Fighting]);
+    assert_eq!(transformed.retreat_cost(ditto).len(), 2);
+    assert_eq!(attacks(&transformed), vec!["Jab", "Special Punch"]);
+
+    let asleep = engine(state(RotationalStatus::Asleep));
+    assert_eq!(asleep.full_hp(active(&asleep, Player::One)), 50);
+    assert!(attacks(&asleep).is_empty());
+}
+
+#[test]
+fn ditto_transform_pays_for_attacks_with_any_energy() {
+    let state = testing::game(&BaseFossil::new())
+        .active(Player::One, "Ditto (FO 18)").attach(&["Water Energy (BS 102)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::new(), state, &["pick_action: Attack with Ditto: Jab"]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 2);
+}
+
+#[test]
+fn haunter_transparency_prevents_the_attack_on_heads() {
+    let state = |status| testing::game(&BaseFossil::new())
+        .active(Player::One, "Pikachu (BS 58)").attach(&["Lightning Energy (BS 100)"])
+        .active(Player::Two, "Gastly (FO 33)").evolve_into("Haunter (FO 6)").status(status)
+        .build();
+    let gnaw = |status, script: &[&str]| {
+        let mut full_script = vec!["pick_action: Attack with Pikachu: Gnaw"];
+        full_script.extend_from_slice(script);
+        active(&testing::run(&BaseFossil::new(), state(status), &full_script), Player::Two).damage_counters
+    };
+
+    assert_eq!(gnaw(RotationalStatus::None, &["flip: heads"]), 0);
+    assert_eq!(gnaw(RotationalStatus::None, &["flip: tails"]), 1);
+    assert_eq!(gnaw(RotationalStatus::Asleep, &[]), 1);
+}
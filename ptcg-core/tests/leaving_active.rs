@@ -0,0 +1,119 @@
+use ptcg_core::*;
+use ptcg_core::custom_effects::PreventDamageAndEffectsDuringOpponentsTurn;
+use ptcg_core::effect::CustomEffect;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn builder() -> GameStateBuilder {
+    GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .active(Player::Two, "Magikarp (BS 35)")
+}
+
+// Something like Agility, from an attack, and something like a Trainer's
+// effect that doesn't care where the Pokémon is.
+fn with_effects(state: GameState) -> GameEngine {
+    let engine = GameEngine::from_state(state, Box::new(BaseFossil::new()));
+    let active = engine.state.p1.active[0].clone();
+
+    engine
+        .with_effect(Effect {
+            source: EffectSource::Attack(Player::One, active.id),
+            target: EffectTarget::InPlayPokemon(Player::One, active.id),
+            expires: EffectExpiration::EndOfTurn(Player::Two, 0),
+            consequence: PreventDamageAndEffectsDuringOpponentsTurn::identifier(),
+            parameters: vec![],
+            name: "Agility".into(),
+        })
+        .with_effect(Effect {
+            source: EffectSource::Trainer(Player::One, active.stack[0].card().clone()),
+            target: EffectTarget::InPlayPokemon(Player::One, active.id),
+            expires: EffectExpiration::EndOfTurn(Player::Two, 0),
+            consequence: PreventDamageAndEffectsDuringOpponentsTurn::identifier(),
+            parameters: vec![],
+            name: "From a trainer".into(),
+        })
+}
+
+fn play(engine: &GameEngine, script: &[&str]) -> GameEngine {
+    let trainer = engine.state.p1.hand[0].clone();
+    let mut dm = ScriptedDM::new(script);
+    let engine = engine.apply_action(&Action::TrainerFromHand(Player::One, trainer), &mut dm).unwrap();
+    dm.assert_done();
+
+    engine
+}
+
+fn effect_names(engine: &GameEngine) -> Vec<&str> {
+    engine.state.effects.iter().map(|effect| effect.name.as_str()).collect()
+}
+
+#[test]
+fn switching_ends_special_conditions_and_attack_effects() {
+    let engine = with_effects(builder()
+        .active(Player::One, "Hitmonchan (BS 7)").status(RotationalStatus::Asleep).poisoned().burned()
+        .bench(Player::One, "Magikarp (BS 35)")
+        .hand(Player::One, &["Switch (BS 94)"])
+        .build());
+
+    let engine = play(&engine, &["pick_in_play: Magikarp"]);
+
+    let hitmonchan = &engine.state.p1.bench[0];
+    assert_eq!(hitmonchan.stack[0].card().archetype, "Hitmonchan (BS 7)");
+    assert_eq!(hitmonchan.rotational_status, RotationalStatus::None);
+    assert!(hitmonchan.poisoned.is_none());
+    assert!(!hitmonchan.burned);
+    assert_eq!(effect_names(&engine), vec!["From a trainer"]);
+    assert!(engine.events().iter().any(|event| matches!(event, GameEvent::EffectExpired { name, .. } if name == "Agility")));
+}
+
+#[test]
+fn scooping_up_ends_attack_effects() {
+    let engine = with_effects(builder()
+        .active(Player::One, "Hitmonchan (BS 7)").status(RotationalStatus::Confused)
+        .bench(Player::One, "Magikarp (BS 35)")
+        .hand(Player::One, &["Scoop Up (BS 78)"])
+        .build());
+
+    let engine = play(&engine, &["pick_in_play: Hitmonchan", "pick_in_play: Magikarp"]);
+
+    assert!(engine.state.p1.hand.iter().any(|card| card.archetype == "Hitmonchan (BS 7)"));
+    assert_eq!(effect_names(&engine), vec!["From a trainer"]);
+}
+
+#[test]
+fn devolving_ends_special_conditions_and_attack_effects() {
+    let engine = with_effects(builder()
+        .active(Player::One, "Charmander (BS 46)").evolve_into("Charmeleon (BS 24)").status(RotationalStatus::Confused).poisoned()
+        .hand(Player::One, &["Devolution Spray (BS 72)"])
+        .build());
+
+    let engine = play(&engine, &["pick_in_play: Charmeleon", "pick_stage: Basic"]);
+
+    let charmander = &engine.state.p1.active[0];
+    assert_eq!(charmander.stack[0].card().archetype, "Charmander (BS 46)");
+    assert_eq!(charmander.rotational_status, RotationalStatus::None);
+    assert!(charmander.poisoned.is_none());
+    assert_eq!(effect_names(&engine), vec!["From a trainer"]);
+}
+
+#[test]
+fn evolving_ends_special_conditions_and_attack_effects() {
+    let engine = with_effects(builder()
+        .active(Player::One, "Charmander (BS 46)").status(RotationalStatus::Confused).burned()
+        .hand(Player::One, &["Charmeleon (BS 24)"])
+        .build());
+
+    let charmeleon = engine.state.p1.hand[0].clone();
+    let mut dm = ScriptedDM::new(&["pick_in_play: Charmander"]);
+    let engine = engine.apply_action(&Action::EvolveFromHand(Player::One, charmeleon), &mut dm).unwrap();
+    dm.assert_done();
+
+    let charmeleon = &engine.state.p1.active[0];
+    assert_eq!(charmeleon.stack[0].card().archetype, "Charmeleon (BS 24)");
+    assert_eq!(charmeleon.rotational_status, RotationalStatus::None);
+    assert!(!charmeleon.burned);
+    assert_eq!(effect_names(&engine), vec!["From a trainer"]);
+}
@@ -0,0 +1,64 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+#[derive(Default)]
+struct Counter {
+    started: usize,
+    steps: Vec<(GameStage, GameStage)>,
+    finished: Option<GameStage>,
+}
+
+impl Observer for Counter {
+    fn on_start(&mut self, _engine: &GameEngine) {
+        self.started += 1;
+    }
+
+    fn on_step(&mut self, before: &GameEngine, after: &GameEngine) {
+        self.steps.push((before.state.stage.clone(), after.state.stage.clone()));
+    }
+
+    fn on_finish(&mut self, engine: &GameEngine) {
+        self.finished = Some(engine.state.stage.clone());
+    }
+}
+
+fn last_attack() -> GameEngine {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)").damage_counters(1)
+        .prizes(Player::One, &["Potion (BS 93)", "Switch (BS 94)"])
+        .prizes(Player::Two, &["Water Energy (BS 102)"; 6])
+        .build();
+
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+#[test]
+fn play_reports_every_step_to_the_observer() {
+    let mut dm = ScriptedDM::new(&[
+        "pick_action: Attack with Hitmonchan: Jab",
+        "pick_from_prizes: Prize #2",
+    ]);
+    let mut counter = Counter::default();
+
+    let engine = last_attack().play(&mut dm, &mut counter);
+
+    assert_eq!(counter.started, 1);
+    assert_eq!(counter.steps, vec![(GameStage::Turn(Player::One), GameStage::Winner(Player::One))]);
+    assert_eq!(counter.finished, Some(engine.state.stage));
+}
+
+#[test]
+fn headless_play_runs_to_the_end() {
+    let mut dm = ScriptedDM::new(&[
+        "pick_action: Attack with Hitmonchan: Jab",
+        "pick_from_prizes: Prize #1",
+    ]);
+
+    let engine = last_attack().play(&mut dm, &mut Headless::default());
+
+    dm.assert_done();
+    assert!(engine.is_finished());
+}
@@ -0,0 +1,66 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::registry::{self, UnknownCard};
+
+#[test]
+fn looks_up_cards_by_archetype() {
+    let format = BaseFossil::new();
+
+    assert_eq!(format.registry().by_archetype("Abra (BS 43)").unwrap().name(), "Abra");
+    assert_eq!(format.registry().by_archetype("Abar (BS 43)").err(), Some(UnknownCard::Identifier("Abar (BS 43)".into())));
+}
+
+#[test]
+fn looks_up_every_printing_by_name() {
+    let format = BaseFossil::new();
+    let printings = format.registry().by_name("Kangaskhan").unwrap();
+
+    let identifiers = printings.iter().map(|a| a.identifier()).collect::<Vec<_>>();
+    assert_eq!(identifiers, vec!["Kangaskhan (JU 5)", "Kangaskhan (JU 21)"]);
+    assert_eq!(format.registry().by_name("Agumon").err(), Some(UnknownCard::Name("Agumon".into())));
+}
+
+#[test]
+fn looks_up_cards_by_set_code_and_number() {
+    let format = BaseFossil::with_team_rocket();
+
+    assert_eq!(format.registry().by_set_number("TR", 39).unwrap().identifier(), "Dark Kadabra (TR 39)");
+    assert_eq!(format.registry().by_set_number("FO", 99).err(), Some(UnknownCard::SetNumber("FO".into(), 99)));
+    assert_eq!(registry::set_number("Poké Ball (JU 64)"), Some(("JU".into(), 64)));
+}
+
+#[test]
+fn reports_the_first_unknown_card_in_a_deck() {
+    let format = BaseFossil::new();
+    let deck = vec!["Abra (BS 43)".to_string(), "Dark Kadabra (TR 39)".to_string()];
+
+    let err = format.registry().check(&deck).unwrap_err();
+    assert_eq!(err.to_string(), "unknown card Dark Kadabra (TR 39)");
+}
+
+#[test]
+fn finds_the_basic_for_a_stage_2() {
+    let format = BaseFossil::new();
+    let alakazam = Card { owner: Player::One, in_game_id: 0, archetype: "Alakazam (BS 1)".into() };
+
+    assert_eq!(format.basic_for_stage2(&alakazam).unwrap(), "Abra");
+}
+
+#[test]
+fn stage_2_pokemon_know_their_basic_and_others_are_an_error() {
+    let format = BaseFossil::new();
+    let state = ptcg_core::testing::GameStateBuilder::new()
+        .hand(Player::One, &["Charizard (BS 4)", "Abra (BS 43)"])
+        .build();
+
+    assert_eq!(format.basic_for_stage2(&state.p1.hand[0]), Ok("Charmander".into()));
+    assert_eq!(format.basic_for_stage2(&state.p1.hand[1]), Err(UnknownCard::PreEvolution("Abra (BS 43)".into())));
+}
+
+#[test]
+fn custom_effects_the_format_doesnt_register_are_an_error() {
+    let format = BaseFossil::new();
+
+    assert!(format.effect("DAMAGED_BY_ATTACK").is_ok());
+    assert_eq!(format.effect("NO_SUCH_EFFECT").err().map(|err| err.0), Some("NO_SUCH_EFFECT".to_string()));
+}
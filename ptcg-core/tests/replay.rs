@@ -0,0 +1,121 @@
+use ptcg_core::*;
+use ptcg_core::ai::RandomDM;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::replay::*;
+use ptcg_core::snapshot::{SnapshotError, SNAPSHOT_VERSION};
+
+fn deck() -> Vec<String> {
+    let mut deck = vec![];
+    for (archetype, count) in [
+        ("Hitmonchan (BS 7)", 4), ("Machop (BS 52)", 4), ("Machoke (BS 34)", 3), ("Charmander (BS 46)", 4),
+        ("Potion (BS 93)", 2), ("Switch (BS 94)", 2), ("Bill (BS 90)", 4), ("Gust of Wind (BS 92)", 2),
+        ("Fighting Energy (BS 97)", 23), ("Fire Energy (BS 98)", 12),
+    ] {
+        deck.extend(vec![archetype.to_string(); count]);
+    }
+    deck
+}
+
+fn engine() -> GameEngine {
+    let state = GameState::initial(&deck(), &deck(), &BaseFossil::new(), true).unwrap();
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+fn json(engine: &GameEngine) -> String {
+    serde_json::to_string(&engine.state).unwrap()
+}
+
+fn recorded(seed: u64) -> (GameEngine, Replay) {
+    let (result, replay) = Replay::record(&engine(), &mut RandomDM::new(seed), &mut Headless::default());
+    (result.unwrap(), replay)
+}
+
+fn panic_message(result: std::thread::Result<Result<GameEngine, SnapshotError>>) -> String {
+    let payload = result.err().expect("the replay should have panicked");
+    payload.downcast_ref::<String>().cloned().unwrap_or_default()
+}
+
+#[test]
+fn recording_doesnt_change_the_answers() {
+    let played = engine().play(&mut RandomDM::new(3), &mut Headless::default());
+    let (recorded, replay) = recorded(3);
+
+    assert!(recorded.is_finished());
+    assert_eq!(json(&played), json(&recorded));
+    assert!(replay.decisions.iter().any(|decision| decision.call == "pick_action"));
+    assert!(replay.decisions.iter().any(|decision| decision.call == "random_card"));
+}
+
+#[test]
+fn replaying_a_recorded_game_ends_the_same_way() {
+    for seed in 0..4 {
+        let (finished, replay) = recorded(seed);
+
+        assert_eq!(json(&finished), json(&replay.run().unwrap()));
+    }
+}
+
+#[test]
+fn replays_survive_a_trip_through_a_file() {
+    let (finished, replay) = recorded(5);
+    let path = std::env::temp_dir().join(format!("ptcg-replay-test-{}.json", std::process::id()));
+
+    replay.save(&path).unwrap();
+    let loaded = Replay::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let loaded = loaded.unwrap();
+    assert_eq!(replay.decisions, loaded.decisions);
+    assert_eq!(json(&finished), json(&loaded.run().unwrap()));
+}
+
+#[test]
+fn replays_from_other_snapshot_versions_are_rejected() {
+    let (_, mut replay) = recorded(5);
+    replay.start.version = SNAPSHOT_VERSION + 1;
+
+    assert!(matches!(Replay::from_json(&replay.to_json().unwrap()), Err(SnapshotError::UnsupportedVersion(_))));
+}
+
+#[test]
+fn a_replay_that_diverges_says_where() {
+    let (_, mut replay) = recorded(6);
+    let at = replay.decisions.iter().position(|decision| decision.call == "pick_action").unwrap();
+    replay.decisions[at].summary = "Pass".into();
+
+    let message = panic_message(std::panic::catch_unwind(|| replay.run()));
+    assert!(message.contains(&format!("replay diverged at decision {}", at)), "{}", message);
+    assert!(message.contains("recorded: pick_action(") && message.contains("asked: pick_action("), "{}", message);
+}
+
+#[test]
+fn a_replay_that_runs_out_of_options_says_so() {
+    let (_, mut replay) = recorded(6);
+    let at = replay.decisions.iter().position(|decision| decision.call == "random_card").unwrap();
+    replay.decisions[at].choice = vec![1000];
+
+    let message = panic_message(std::panic::catch_unwind(|| replay.run()));
+    assert!(message.contains(&format!("replay diverged at decision {}: recorded choice 1000", at)), "{}", message);
+}
+
+#[test]
+fn a_replay_with_decisions_left_over_is_rejected() {
+    let (_, mut replay) = recorded(7);
+    let extra = replay.decisions.last().unwrap().clone();
+    replay.decisions.push(extra);
+
+    assert!(matches!(replay.run(), Err(SnapshotError::UnusedDecisions(1))));
+}
+
+#[test]
+fn replays_of_games_with_other_rules_end_the_same_way() {
+    let format = BaseFossil::new()
+        .with_confusion(ConfusionRules::Modern)
+        .with_simultaneous_win(SimultaneousWin::Tie);
+    let state = GameState::initial(&deck(), &deck(), &format, true).unwrap();
+    let engine = GameEngine::from_state(state, Box::new(format));
+
+    let (result, replay) = Replay::record(&engine, &mut RandomDM::new(8), &mut Headless::default());
+
+    assert_eq!(json(&result.unwrap()), json(&replay.run().unwrap()));
+}
@@ -0,0 +1,196 @@
+use std::time::Duration;
+use ptcg_core::*;
+use ptcg_core::state::GameStage;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::server::{Client, RemoteDM, ServerMessage};
+use ptcg_core::testing::{GameStateBuilder, ScriptedClient};
+
+fn last_attack() -> GameEngine {
+    let state = GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .hand(Player::One, &["Potion (BS 93)"])
+        .active(Player::Two, "Magikarp (BS 35)").damage_counters(1)
+        .prizes(Player::One, &["Potion (BS 93)", "Switch (BS 94)"])
+        .prizes(Player::Two, &["Water Energy (BS 102)"; 6])
+        .build();
+
+    GameEngine::from_state(state, Box::new(BaseFossil::new()))
+}
+
+fn server() -> RemoteDM {
+    RemoteDM::bind("127.0.0.1:0").unwrap().seed(1).timeouts(Duration::from_secs(5), Duration::from_secs(5))
+}
+
+fn welcome(client: &mut Client) -> String {
+    match client.receive().unwrap() {
+        ServerMessage::Welcome { secret, .. } => secret,
+        message => panic!("expected a welcome, got {:?}", message),
+    }
+}
+
+fn next_prompt(client: &mut Client) -> (u64, String) {
+    loop {
+        if let ServerMessage::Prompt { id, call, .. } = client.receive().unwrap() {
+            return (id, call);
+        }
+    }
+}
+
+#[test]
+fn two_scripted_clients_play_a_game_over_localhost() {
+    let mut dm = server();
+    let address = dm.local_addr().unwrap();
+
+    let one = std::thread::spawn(move || {
+        let client = Client::connect(address, Player::One).unwrap();
+        ScriptedClient::new(client, &["pick_action: Attack with Hitmonchan: Jab", "pick_from_prizes: Prize #2"]).run().unwrap()
+    });
+    let two = std::thread::spawn(move || {
+        let client = Client::connect(address, Player::Two).unwrap();
+        ScriptedClient::new(client, &[]).run().unwrap()
+    });
+
+    dm.wait_for_players().unwrap();
+    let engine = last_attack().play(&mut dm, &mut Headless::default());
+    dm.finish(&engine);
+
+    let one = one.join().unwrap();
+    let two = two.join().unwrap();
+    assert!(matches!(one.last(), Some(ServerMessage::Finished { winner: Some(Player::One) })));
+    assert!(matches!(two.last(), Some(ServerMessage::Finished { winner: Some(Player::One) })));
+
+    // player two only ever saw the back of player one's hand
+    for message in two.iter() {
+        if let ServerMessage::View { view } = message {
+            assert_eq!(view.player, Player::Two);
+            assert!(view.p1.hand.iter().all(Option::is_none));
+        }
+    }
+}
+
+#[test]
+fn invalid_answers_are_asked_again() {
+    let mut dm = server();
+    let address = dm.local_addr().unwrap();
+
+    let one = std::thread::spawn(move || {
+        let mut client = Client::connect(address, Player::One).unwrap();
+        let (id, _) = next_prompt(&mut client);
+        client.answer(id, vec![99]).unwrap();
+        assert!(matches!(client.receive().unwrap(), ServerMessage::Error { .. }));
+
+        let (again, call) = next_prompt(&mut client);
+        assert_eq!((again, call.as_str()), (id, "pick_player"));
+        client.answer(id, vec![1]).unwrap();
+    });
+
+    let players = vec![Player::One, Player::Two];
+    assert_eq!(dm.pick_player(Player::One, &players), &Player::Two);
+    one.join().unwrap();
+}
+
+#[test]
+fn players_who_time_out_can_reconnect() {
+    let mut dm = RemoteDM::bind("127.0.0.1:0").unwrap().timeouts(Duration::from_millis(200), Duration::from_secs(5));
+    let address = dm.local_addr().unwrap();
+
+    let one = std::thread::spawn(move || {
+        let mut client = Client::connect(address, Player::One).unwrap();
+        let secret = welcome(&mut client);
+        let (id, _) = next_prompt(&mut client);
+
+        // too slow: the server hangs up
+        std::thread::sleep(Duration::from_millis(400));
+        assert!(client.receive().is_err());
+
+        let mut client = Client::reconnect(address, Player::One, &secret).unwrap();
+        assert!(matches!(client.receive().unwrap(), ServerMessage::Welcome { player: Player::One, secret: again } if again == secret));
+        let (again, _) = next_prompt(&mut client);
+        assert_eq!(again, id);
+        client.answer(id, vec![0]).unwrap();
+    });
+
+    let players = vec![Player::One, Player::Two];
+    assert_eq!(dm.pick_player(Player::One, &players), &Player::One);
+    one.join().unwrap();
+}
+
+#[test]
+fn only_the_player_who_had_the_seat_can_take_it_back() {
+    let mut dm = RemoteDM::bind("127.0.0.1:0").unwrap().timeouts(Duration::from_millis(200), Duration::from_secs(5));
+    let address = dm.local_addr().unwrap();
+
+    let one = std::thread::spawn(move || {
+        let mut client = Client::connect(address, Player::One).unwrap();
+        let secret = welcome(&mut client);
+        next_prompt(&mut client);
+        std::thread::sleep(Duration::from_millis(400));
+
+        for mut stranger in [Client::connect(address, Player::One).unwrap(), Client::reconnect(address, Player::One, "guess").unwrap()] {
+            assert!(matches!(stranger.receive().unwrap(), ServerMessage::Error { .. }));
+        }
+
+        let mut client = Client::reconnect(address, Player::One, &secret).unwrap();
+        welcome(&mut client);
+        let (id, _) = next_prompt(&mut client);
+        client.answer(id, vec![1]).unwrap();
+    });
+
+    let players = vec![Player::One, Player::Two];
+    assert_eq!(dm.pick_player(Player::One, &players), &Player::Two);
+    one.join().unwrap();
+}
+
+#[test]
+fn connections_that_never_say_hello_dont_hold_up_the_game() {
+    let mut dm = server().handshake_timeout(Duration::from_millis(100));
+    let address = dm.local_addr().unwrap();
+    let silent = std::net::TcpStream::connect(address).unwrap();
+
+    let one = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(50));
+        let mut client = Client::connect(address, Player::One).unwrap();
+        welcome(&mut client);
+        let (id, _) = next_prompt(&mut client);
+        client.answer(id, vec![0]).unwrap();
+    });
+
+    let started = std::time::Instant::now();
+    let players = vec![Player::One, Player::Two];
+    assert_eq!(dm.pick_player(Player::One, &players), &Player::One);
+    assert!(started.elapsed() < Duration::from_secs(2));
+    one.join().unwrap();
+    drop(silent);
+}
+
+#[test]
+fn waiting_for_a_player_who_never_shows_up_times_out() {
+    let mut dm = RemoteDM::bind("127.0.0.1:0").unwrap().timeouts(Duration::from_millis(100), Duration::from_millis(100));
+
+    let err = dm.wait_for_players().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+}
+
+#[test]
+fn players_who_dont_come_back_forfeit() {
+    let mut dm = server().timeouts(Duration::from_millis(100), Duration::from_millis(200));
+    let address = dm.local_addr().unwrap();
+
+    let one = std::thread::spawn(move || {
+        let mut client = Client::connect(address, Player::One).unwrap();
+        welcome(&mut client);
+        next_prompt(&mut client);
+    });
+    let two = std::thread::spawn(move || {
+        let client = Client::connect(address, Player::Two).unwrap();
+        ScriptedClient::new(client, &[]).run().unwrap()
+    });
+
+    dm.wait_for_players().unwrap();
+    let engine = dm.play(&last_attack(), &mut Headless::default());
+    one.join().unwrap();
+
+    assert_eq!(dm.forfeited(), Some(Player::One));
+    assert!(matches!(engine.state.stage, GameStage::Winner(Player::Two)));
+    assert!(matches!(two.join().unwrap().last(), Some(ServerMessage::Finished { winner: Some(Player::Two) })));
+}
@@ -0,0 +1,110 @@
+use ptcg_core::*;
+use ptcg_core::engine::FakeDM;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::simulation::*;
+
+fn deck(pokemon: &str, energy: &str) -> Vec<String> {
+    let mut deck = vec![pokemon.to_string(); 12];
+    deck.extend(vec![energy.to_string(); 48]);
+    deck
+}
+
+fn first_choice() -> Contender {
+    Box::new(|_, _| Box::new(FakeDM::default()))
+}
+
+fn matchup() -> Simulation {
+    Simulation::new(
+        || Box::new(BaseFossil::new()),
+        (&deck("Hitmonchan (BS 7)", "Fighting Energy (BS 97)"), first_choice()),
+        (&deck("Magikarp (BS 35)", "Water Energy (BS 102)"), first_choice()),
+    ).games(20).seed(7)
+}
+
+fn record(first: Deck, ending: Ending, turns: usize, prize_differential: isize) -> GameRecord {
+    GameRecord { seed: 0, first, ending, turns, prize_differential }
+}
+
+#[test]
+fn every_game_is_accounted_for() {
+    let report = matchup().threads(4).run().unwrap();
+
+    assert_eq!(report.games.len(), 20);
+    assert_eq!(report.count(Ending::Crashed), 0);
+    assert_eq!(report.wins(Deck::A) + report.wins(Deck::B) + report.count(Ending::Tie) + report.count(Ending::TurnLimit), 20);
+}
+
+// Deck A sits as Player One in even games, but the coin decides who goes
+// first: sometimes it's Player Two.
+#[test]
+fn the_coin_flip_decides_who_goes_first() {
+    let report = matchup().run().unwrap();
+    let seat_one = |game: usize| if game.is_multiple_of(2) { Deck::A } else { Deck::B };
+
+    assert!(report.games.iter().enumerate().any(|(n, game)| game.first == seat_one(n)));
+    assert!(report.games.iter().enumerate().any(|(n, game)| game.first != seat_one(n)));
+}
+
+#[test]
+fn results_dont_depend_on_the_number_of_threads() {
+    assert_eq!(matchup().threads(1).run().unwrap(), matchup().threads(3).run().unwrap());
+    assert_ne!(matchup().run().unwrap(), matchup().seed(8).run().unwrap());
+}
+
+#[test]
+fn hitmonchan_punches_above_magikarp() {
+    let report = matchup().run().unwrap();
+    let (low, high) = report.confidence_interval(Deck::A);
+
+    assert!(report.win_rate(Deck::A) > 0.5);
+    assert!(low < report.win_rate(Deck::A) && report.win_rate(Deck::A) <= high);
+    assert!(report.average_prize_differential() > 0.0);
+    assert!(report.average_turns() > 2.0);
+}
+
+#[test]
+fn statistics_over_finished_games() {
+    let report = SimulationReport {
+        games: vec![
+            record(Deck::A, Ending::Won(Deck::A), 10, 6),
+            record(Deck::B, Ending::Won(Deck::A), 20, 3),
+            record(Deck::A, Ending::Won(Deck::B), 30, -2),
+            record(Deck::B, Ending::Tie, 40, 0),
+            record(Deck::A, Ending::TurnLimit, 500, 1),
+        ],
+    };
+
+    assert_eq!(report.decided(), 4);
+    assert_eq!(report.win_rate(Deck::A), 0.625);
+    assert_eq!(report.win_rate(Deck::B), 0.375);
+    assert_eq!(report.average_turns(), 25.0);
+    assert_eq!(report.average_prize_differential(), 1.75);
+
+    let (first, (low, high)) = report.first_turn_win_rate();
+    assert!((first - 1.0 / 3.0).abs() < 1e-9);
+    assert!(low < first && first < high);
+}
+
+#[test]
+fn wilson_intervals_tighten_with_more_games() {
+    let games = |n: usize| SimulationReport {
+        games: (0..n).map(|i| record(Deck::A, Ending::Won(if i % 4 == 0 { Deck::B } else { Deck::A }), 10, 0)).collect(),
+    };
+
+    let (low, high) = games(8).confidence_interval(Deck::A);
+    let (narrow_low, narrow_high) = games(800).confidence_interval(Deck::A);
+    assert!(low < narrow_low && narrow_high < high);
+    assert!(narrow_low < 0.75 && 0.75 < narrow_high);
+    assert!(0.0 <= low && high <= 1.0);
+}
+
+#[test]
+fn illegal_decks_are_refused() {
+    let simulation = Simulation::new(
+        || Box::new(BaseFossil::new()),
+        (&vec!["Fighting Energy (BS 97)".to_string(); 60], first_choice()),
+        (&deck("Magikarp (BS 35)", "Water Energy (BS 102)"), first_choice()),
+    );
+
+    assert_eq!(simulation.run().unwrap_err().player, Player::One);
+}
@@ -0,0 +1,150 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::snapshot::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+// Hitmonchan is in the middle of attacking Chansey with Jab.
+fn mid_attack() -> GameEngine {
+    let state = GameStateBuilder::new()
+        .prizes(Player::One, &["Potion (BS 93)"; 2])
+        .prizes(Player::Two, &["Potion (BS 93)"; 2])
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Chansey (BS 3)").damage_counters(1)
+        .hand(Player::One, &["Bill (BS 90)"])
+        .build();
+    let engine = GameEngine::from_state(state, Box::new(BaseFossil::new()));
+
+    let attacking = engine.state.p1.active[0].clone();
+    let defending = engine.state.p2.active[0].clone();
+    let jab = engine.attacks(&attacking).into_iter().find(|attack| attack.name() == "Jab").unwrap();
+
+    engine
+        .push_action(Action::Attack(Player::One, attacking.clone(), jab))
+        .push_target(&attacking, &defending)
+}
+
+fn json(state: &GameState) -> String {
+    serde_json::to_string(state).unwrap()
+}
+
+// Finishes the attack in flight, with the attack the engine resolved.
+fn finish_attack(engine: &GameEngine) -> GameEngine {
+    let attack = match engine.current_action() {
+        Some(Action::Attack(_, _, attack)) => attack,
+        action => panic!("expected an attack in flight, got {:?}", action),
+    };
+
+    attack.build().apply(engine.clone(), &mut ScriptedDM::new(&[])).engine()
+}
+
+fn assert_same_game(original: &GameEngine, restored: &GameEngine) {
+    assert_eq!(json(&original.state), json(&restored.state));
+    assert_eq!(original.current_attack_name(), restored.current_attack_name());
+    assert_eq!(original.attacking().id, restored.attacking().id);
+    assert_eq!(original.defending().id, restored.defending().id);
+    assert_eq!(json(&finish_attack(original).state), json(&finish_attack(restored).state));
+}
+
+#[test]
+fn json_snapshots_resume_an_attack_in_flight() {
+    let engine = mid_attack();
+    let restored = Snapshot::from_json(&engine.snapshot().to_json().unwrap()).unwrap().restore().unwrap();
+
+    assert_eq!(Some("Jab".to_string()), restored.current_attack_name());
+    assert_same_game(&engine, &restored);
+    // Jab's 20 doubled by Chansey's Fighting weakness, on top of the counter it had
+    assert_eq!(5, finish_attack(&restored).state.p2.active[0].damage_counters);
+}
+
+#[test]
+fn binary_snapshots_resume_an_attack_in_flight() {
+    let engine = mid_attack();
+    let bytes = engine.snapshot().to_binary().unwrap();
+    let restored = Snapshot::from_binary(&bytes).unwrap().restore().unwrap();
+
+    assert!(bytes.starts_with(b"PTCG"));
+    assert_same_game(&engine, &restored);
+}
+
+#[test]
+fn saved_snapshots_are_loaded_in_either_encoding() {
+    let engine = mid_attack();
+
+    for (encoding, name) in [(Encoding::Json, "json"), (Encoding::Binary, "bin")] {
+        let path = std::env::temp_dir().join(format!("ptcg-snapshot-test-{}-{}", std::process::id(), name));
+        engine.snapshot().save(&path, encoding).unwrap();
+        let loaded = Snapshot::load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_same_game(&engine, &loaded.unwrap().restore().unwrap());
+    }
+}
+
+#[test]
+fn anything_without_the_magic_number_is_not_a_binary_snapshot() {
+    let mut bytes = mid_attack().snapshot().to_binary().unwrap();
+    bytes[0..4].copy_from_slice(b"NOPE");
+
+    assert!(matches!(Snapshot::from_binary(&bytes), Err(SnapshotError::NotASnapshot)));
+    assert!(matches!(Snapshot::from_binary(b"PTC"), Err(SnapshotError::NotASnapshot)));
+}
+
+#[test]
+fn snapshots_from_other_versions_are_rejected() {
+    let snapshot = mid_attack().snapshot();
+
+    let mut bytes = snapshot.to_binary().unwrap();
+    bytes[4..8].copy_from_slice(&(SNAPSHOT_VERSION - 1).to_le_bytes());
+    assert!(matches!(Snapshot::from_binary(&bytes), Err(SnapshotError::UnsupportedVersion(v)) if v == SNAPSHOT_VERSION - 1));
+
+    let mut json = serde_json::from_str::<serde_json::Value>(&snapshot.to_json().unwrap()).unwrap();
+    json["version"] = serde_json::json!(SNAPSHOT_VERSION + 1);
+    assert!(matches!(Snapshot::from_json(&json.to_string()), Err(SnapshotError::UnsupportedVersion(v)) if v == SNAPSHOT_VERSION + 1));
+}
+
+#[test]
+fn snapshots_only_restore_with_their_own_format() {
+    let mut snapshot = mid_attack().snapshot();
+
+    let given = BaseFossil::with_team_rocket();
+    assert!(matches!(GameEngine::from_snapshot(&snapshot, Box::new(given)), Err(SnapshotError::FormatMismatch(..))));
+
+    snapshot.format = "no-such-format".into();
+    assert!(matches!(snapshot.restore(), Err(SnapshotError::UnknownFormat(name)) if name == "no-such-format"));
+}
+
+#[test]
+fn attacks_the_card_doesnt_have_are_rejected() {
+    let mut snapshot = mid_attack().snapshot();
+    snapshot.resolving_actions = snapshot.resolving_actions.into_iter().map(|action| match action {
+        SnapshotAction::Attack(player, in_play, _) => SnapshotAction::Attack(player, in_play, "Hyper Beam".into()),
+        action => action,
+    }).collect();
+
+    assert!(matches!(snapshot.restore(), Err(SnapshotError::UnknownAttack(card, attack)) if card == "Hitmonchan (BS 7)" && attack == "Hyper Beam"));
+}
+
+#[test]
+fn effects_the_format_doesnt_have_are_rejected() {
+    let mut snapshot = finish_attack(&mid_attack()).snapshot();
+    assert!(!snapshot.state.effects.is_empty());
+    snapshot.state.effects[0].consequence = "NO_SUCH_EFFECT".into();
+
+    assert!(matches!(snapshot.restore(), Err(SnapshotError::UnknownEffect(effect)) if effect == "NO_SUCH_EFFECT"));
+}
+
+#[test]
+fn snapshots_keep_the_rules_the_game_was_played_by() {
+    let format = BaseFossil::new()
+        .with_confusion(ConfusionRules::Modern)
+        .with_burn(BurnRules::FlipToRecover)
+        .with_pokemon_checkup(vec![CheckupStep::Sleep, CheckupStep::Poison])
+        .with_simultaneous_win(SimultaneousWin::Tie)
+        .with_first_turn_restrictions(vec![FirstTurnRestriction::NoAttack]);
+    let engine = GameEngine::from_state(mid_attack().state, Box::new(format.clone()));
+
+    let restored = Snapshot::from_json(&engine.snapshot().to_json().unwrap()).unwrap().restore().unwrap();
+
+    assert_eq!(restored.format.rules(), format.rules());
+    assert!(matches!(GameEngine::from_snapshot(&engine.snapshot(), Box::new(BaseFossil::new())), Err(SnapshotError::RulesMismatch(..))));
+}
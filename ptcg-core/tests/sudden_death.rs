@@ -0,0 +1,95 @@
+use ptcg_core::*;
+use ptcg_core::ai::RandomDM;
+use ptcg_core::events::GameEvent;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+// Both Active Pokémon get knocked out by Poison during the Pokémon Checkup
+// and neither player has a Benched Pokémon to replace them.
+fn double_knockout(p1_prizes: usize, p2_prizes: usize) -> GameState {
+    GameStateBuilder::new()
+        .stage(GameStage::PokemonCheckup(Player::One))
+        .active(Player::One, "Magikarp (BS 35)").damage_counters(2).poisoned()
+        .active(Player::Two, "Magikarp (BS 35)").damage_counters(2).poisoned()
+        .deck(Player::One, &["Machop (BS 52)"; 10])
+        .deck(Player::Two, &["Hitmonchan (BS 7)"; 10])
+        .prizes(Player::One, &vec!["Potion (BS 93)"; p1_prizes])
+        .prizes(Player::Two, &vec!["Potion (BS 93)"; p2_prizes])
+        .build()
+}
+
+fn checkup(state: GameState, format: BaseFossil) -> GameEngine {
+    // each player takes a prize card for the other's Magikarp
+    let mut dm = ScriptedDM::new(&["pick_from_prizes: Prize #1", "pick_from_prizes: Prize #1"]);
+    let engine = GameEngine::from_state(state, Box::new(format)).step(&mut dm);
+    dm.assert_done();
+
+    engine
+}
+
+fn archetypes(state: &GameState, player: Player) -> Vec<String> {
+    let mut archetypes = state.side(player).all_cards().into_iter().map(|card| card.archetype).collect::<Vec<_>>();
+    archetypes.sort();
+    archetypes
+}
+
+#[test]
+fn both_players_winning_at_once_plays_sudden_death_by_default() {
+    let engine = checkup(double_knockout(2, 2), BaseFossil::new());
+
+    assert_eq!(GameStage::SuddenDeath, engine.state.stage);
+    assert!(engine.is_over());
+    assert!(!engine.is_finished());
+}
+
+#[test]
+fn sudden_death_restarts_with_the_same_decks_and_remembers_the_parent() {
+    let finished = checkup(double_knockout(2, 2), BaseFossil::new());
+
+    let engine = finished.step(&mut ScriptedDM::new(&[]));
+    assert_eq!(GameStage::Uninitialized, engine.state.stage);
+    assert!(engine.state.events.contains(&GameEvent::SuddenDeath));
+    let parent = engine.state.parent.as_deref().unwrap();
+    assert_eq!(GameStage::SuddenDeath, parent.stage);
+    assert_eq!(finished.state.p1.prizes.len(), parent.p1.prizes.len());
+    assert!(parent.events.is_empty());
+
+    for player in [Player::One, Player::Two] {
+        assert_eq!(archetypes(&finished.state, player), archetypes(&engine.state, player));
+        assert_eq!(archetypes(&engine.state, player).len(), engine.state.side(player).deck.len());
+    }
+}
+
+#[test]
+fn sudden_death_is_played_with_a_single_prize_card() {
+    let engine = checkup(double_knockout(2, 2), BaseFossil::new())
+        .step(&mut ScriptedDM::new(&[]))
+        .step(&mut RandomDM::new(7));
+
+    assert!(engine.state.parent.is_some());
+    assert_eq!(1, engine.state.p1.prizes.len());
+    assert_eq!(1, engine.state.p2.prizes.len());
+}
+
+#[test]
+fn formats_can_call_a_simultaneous_win_a_tie() {
+    let engine = checkup(double_knockout(2, 2), BaseFossil::new().with_simultaneous_win(SimultaneousWin::Tie));
+
+    assert_eq!(GameStage::Tie, engine.state.stage);
+    assert!(engine.is_finished());
+}
+
+#[test]
+fn the_player_who_took_more_prizes_wins() {
+    let engine = checkup(double_knockout(3, 2), BaseFossil::new().with_simultaneous_win(SimultaneousWin::MostPrizesTaken));
+
+    assert_eq!(GameStage::Winner(Player::Two), engine.state.stage);
+}
+
+#[test]
+fn taking_as_many_prizes_still_plays_sudden_death() {
+    let engine = checkup(double_knockout(2, 2), BaseFossil::new().with_simultaneous_win(SimultaneousWin::MostPrizesTaken));
+
+    assert_eq!(GameStage::SuddenDeath, engine.state.stage);
+}
@@ -0,0 +1,490 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::state::*;
+use ptcg_core::testing::{self, until_turn, active, bench, names, GameStateBuilder, ScriptedDM};
+
+fn engine(state: GameState) -> GameEngine {
+    GameEngine::from_state(state, Box::new(BaseFossil::with_team_rocket()))
+}
+
+#[test]
+fn dark_kadabra_evolves_from_abra() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Abra (TR 49)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Dark Kadabra (TR 39)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Evolve into Dark Kadabra",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).stack[0].card().archetype, "Dark Kadabra (TR 39)");
+}
+
+#[test]
+fn dark_alakazam_only_evolves_from_dark_kadabra() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Abra (BS 43)").evolve_into("Kadabra (BS 32)")
+        .bench(Player::One, "Abra (TR 49)").evolve_into("Dark Kadabra (TR 39)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Dark Alakazam (TR 1)"])
+        .build();
+
+    let engine = engine(state);
+    let dark_alakazam = &engine.state.side(Player::One).hand[0];
+    let targets = engine.evolution_targets(dark_alakazam);
+
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].stack[0].card().archetype, "Dark Kadabra (TR 39)");
+}
+
+#[test]
+fn dark_kadabra_mind_shock_ignores_weakness() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Abra (TR 49)").evolve_into("Dark Kadabra (TR 39)")
+            .attach(&["Psychic Energy (BS 101)", "Psychic Energy (BS 101)", "Psychic Energy (BS 101)"])
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &["pick_action: Attack with Dark Kadabra: Mind Shock"]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 3);
+}
+
+#[test]
+fn dark_golbat_flitter_can_hit_the_bench() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Zubat (TR 70)").evolve_into("Dark Golbat (TR 7)")
+            .attach(&["Grass Energy (BS 99)", "Grass Energy (BS 99)", "Grass Energy (BS 99)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .bench(Player::Two, "Abra (BS 43)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Attack with Dark Golbat: Flitter",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 0);
+    assert_eq!(bench(&engine, Player::Two)[0].damage_counters, 2);
+}
+
+#[test]
+fn dark_vileplume_petal_whirlwind_confuses_itself_on_two_heads() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Oddish (TR 63)").evolve_into("Dark Gloom (TR 36)").evolve_into("Dark Vileplume (TR 13)")
+            .attach(&["Grass Energy (BS 99)", "Grass Energy (BS 99)", "Grass Energy (BS 99)"])
+        .active(Player::Two, "Hitmonchan (BS 7)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Attack with Dark Vileplume: Petal Whirlwind",
+        "flip: heads, tails, heads",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).damage_counters, 6);
+    assert_eq!(active(&engine, Player::One).rotational_status, RotationalStatus::Confused);
+}
+
+#[test]
+fn magikarp_rapid_evolution_evolves_from_the_deck() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Magikarp (TR 47)").attach(&["Water Energy (BS 102)"])
+        .active(Player::Two, "Mewtwo (BS 10)")
+        .deck(Player::One, &["Dark Gyarados (TR 8)", "Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Attack with Magikarp: Rapid Evolution",
+        "search_deck: Dark Gyarados",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).stack[0].card().archetype, "Dark Gyarados (TR 8)");
+    assert_eq!(engine.state.side(Player::One).deck.len(), 1);
+}
+
+#[test]
+fn dark_wartortle_mirror_shell_reflects_damage() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Squirtle (TR 68)").evolve_into("Dark Wartortle (TR 46)").attach(&["Water Energy (BS 102)"])
+        .active(Player::Two, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .deck(Player::Two, &["Water Energy (BS 102)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &["pick_action: Attack with Dark Wartortle: Mirror Shell"]);
+    let engine = until_turn(engine, Player::Two);
+    let engine = engine.step(&mut ScriptedDM::new(&["pick_action: Attack with Hitmonchan: Jab"]));
+
+    assert_eq!(active(&engine, Player::One).damage_counters, 2);
+    assert_eq!(active(&engine, Player::Two).damage_counters, 2);
+}
+
+#[test]
+fn rainbow_energy_counts_as_any_type_and_puts_a_damage_counter() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Abra (TR 49)").evolve_into("Dark Kadabra (TR 39)")
+            .attach(&["Psychic Energy (BS 101)", "Water Energy (BS 102)"])
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Rainbow Energy (TR 17)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Attach Rainbow Energy",
+        "pick_in_play: Dark Kadabra",
+    ]);
+
+    let dark_kadabra = active(&engine, Player::One);
+    assert_eq!(dark_kadabra.damage_counters, 1);
+    assert!(engine.is_attack_energy_cost_met(dark_kadabra, &[Type::Psychic, Type::Psychic, Type::Colorless]));
+}
+
+#[test]
+fn full_heal_energy_removes_special_conditions() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)").status(RotationalStatus::Confused).poisoned()
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Full Heal Energy (TR 81)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Attach Full Heal Energy",
+        "pick_in_play: Mewtwo",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).rotational_status, RotationalStatus::None);
+    assert!(active(&engine, Player::One).poisoned.is_none());
+}
+
+#[test]
+fn goop_gas_attack_stops_pokemon_powers() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Abra (BS 43)").evolve_into("Kadabra (BS 32)").evolve_into("Alakazam (BS 1)")
+        .hand(Player::One, &["Goop Gas Attack (TR 78)"])
+        .build();
+
+    assert_eq!(engine(state.clone()).poke_powers(&state.side(Player::Two).active[0]).len(), 1);
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &["pick_action: Play Goop Gas Attack"]);
+
+    assert!(engine.poke_powers(active(&engine, Player::Two)).is_empty());
+}
+
+#[test]
+fn the_boss_s_way_searches_for_a_dark_evolution() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["The Boss's Way (TR 73)"])
+        .deck(Player::One, &["Abra (TR 49)", "Kadabra (BS 32)", "Dark Kadabra (TR 39)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Play The Boss's Way",
+        "search_deck: Dark Kadabra",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Dark Kadabra (TR 39)"]);
+}
+
+#[test]
+fn declining_a_challenge_draws_two_cards() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Challenge! (TR 74)"])
+        .deck(Player::One, &["Abra (TR 49)", "Potion (BS 93)", "Switch (BS 94)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Play Challenge!",
+        "accept_challenge: decline",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Abra (TR 49)", "Potion (BS 93)"]);
+    assert!(bench(&engine, Player::One).is_empty());
+}
+
+#[test]
+fn accepting_a_challenge_benches_basics_from_both_decks() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Challenge! (TR 74)"])
+        .deck(Player::One, &["Abra (TR 49)", "Potion (BS 93)"])
+        .deck(Player::Two, &["Diglett (TR 52)", "Dark Dugtrio (TR 6)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Play Challenge!",
+        "accept_challenge: accept",
+        "search_deck: Abra",
+        "search_deck: Diglett",
+    ]);
+
+    assert_eq!(bench(&engine, Player::One)[0].stack[0].card().archetype, "Abra (TR 49)");
+    assert_eq!(bench(&engine, Player::Two)[0].stack[0].card().archetype, "Diglett (TR 52)");
+    assert!(engine.state.side(Player::One).hand.is_empty());
+}
+
+#[test]
+fn sleep_puts_the_defending_pokemon_to_sleep_on_heads() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Sleep! (TR 79)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Play Sleep!",
+        "flip: heads",
+    ]);
+
+    assert_eq!(active(&engine, Player::Two).rotational_status, RotationalStatus::Asleep);
+}
+
+#[test]
+fn dark_charmeleon_only_discards_for_fireball_on_heads() {
+    let state = || testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Dark Charmeleon (TR 32)").attach(&["Fire Energy (BS 98)", "Fire Energy (BS 98)"])
+        .active(Player::Two, "Chansey (BS 3)")
+        .build();
+
+    let tails = testing::run(&BaseFossil::with_team_rocket(), state(), &[
+        "pick_action: Attack with Dark Charmeleon: Fireball",
+        "flip: tails",
+    ]);
+    assert_eq!(active(&tails, Player::Two).damage_counters, 0);
+    assert_eq!(active(&tails, Player::One).attached.len(), 2);
+
+    let heads = testing::run(&BaseFossil::with_team_rocket(), state(), &[
+        "pick_action: Attack with Dark Charmeleon: Fireball",
+        "flip: heads",
+    ]);
+    assert_eq!(active(&heads, Player::Two).damage_counters, 7);
+    assert_eq!(active(&heads, Player::One).attached.len(), 1);
+}
+
+#[test]
+fn challenge_draws_two_cards_without_asking_when_both_benches_are_full() {
+    let full_bench = |builder: GameStateBuilder, player| builder
+        .bench(player, "Abra (TR 49)").bench(player, "Abra (TR 49)").bench(player, "Abra (TR 49)")
+        .bench(player, "Abra (TR 49)").bench(player, "Abra (TR 49)");
+    let state = full_bench(full_bench(testing::game(&BaseFossil::with_team_rocket()), Player::One), Player::Two)
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Challenge! (TR 74)"])
+        .deck(Player::One, &["Diglett (TR 52)", "Potion (BS 93)", "Switch (BS 94)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &["pick_action: Play Challenge!"]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Diglett (TR 52)", "Potion (BS 93)"]);
+}
+
+#[test]
+fn digger_s_second_coin_is_flipped_by_the_opponent() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Digger (TR 75)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Play Digger",
+        "flip: heads",
+        "flip: tails",
+    ]);
+
+    let flippers = engine.events().iter()
+        .filter_map(|e| match e { GameEvent::CoinFlipped { player, .. } => Some(*player), _ => None })
+        .collect::<Vec<_>>();
+    assert_eq!(flippers, vec![Player::One, Player::Two]);
+    assert_eq!(active(&engine, Player::Two).damage_counters, 1);
+}
+
+#[test]
+fn dark_dragonite_summon_minions_benches_basics_when_it_is_played() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Dratini (TR 53)").evolve_into("Dark Dragonair (TR 33)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Dark Dragonite (TR 5)"])
+        .deck(Player::One, &["Abra (TR 49)", "Potion (BS 93)", "Diglett (TR 52)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Evolve into Dark Dragonite",
+        "pick_in_play: Dark Dragonair",
+        "search_deck: Abra, Diglett",
+    ]);
+
+    let benched = bench(&engine, Player::One).iter().map(|p| p.stack[0].card().archetype.clone()).collect::<Vec<_>>();
+    assert_eq!(benched, vec!["Abra (TR 49)", "Diglett (TR 52)"]);
+    assert_eq!(names(&engine.state.side(Player::One).deck.cards()), vec!["Potion (BS 93)"]);
+}
+
+#[test]
+fn dark_golbat_sneak_attack_damages_any_opposing_pokemon_when_it_is_played() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Zubat (TR 70)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .bench(Player::Two, "Abra (TR 49)")
+        .hand(Player::One, &["Dark Golbat (TR 7)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Evolve into Dark Golbat",
+        "pick_in_play: Zubat",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(bench(&engine, Player::Two)[0].damage_counters, 1);
+    assert_eq!(active(&engine, Player::Two).damage_counters, 0);
+}
+
+#[test]
+fn dark_slowbro_reel_in_takes_pokemon_back_from_the_discard_pile() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Slowpoke (TR 67)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Dark Slowbro (TR 12)"])
+        .discard(Player::One, &["Abra (TR 49)", "Potion (BS 93)", "Dark Kadabra (TR 39)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Evolve into Dark Slowbro",
+        "pick_in_play: Slowpoke",
+        "pick_from_discard: Abra, Dark Kadabra",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Abra (TR 49)", "Dark Kadabra (TR 39)"]);
+}
+
+#[test]
+fn dark_vileplume_hay_fever_stops_trainers() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Mewtwo (BS 10)")
+        .active(Player::Two, "Oddish (TR 63)").evolve_into("Dark Gloom (TR 36)").evolve_into("Dark Vileplume (TR 13)")
+        .hand(Player::One, &["Potion (BS 93)"])
+        .build();
+
+    let engine = engine(state);
+
+    assert!(!engine.available_actions(Player::One).iter().any(|action| matches!(action, Action::TrainerFromHand(..))));
+}
+
+#[test]
+fn dark_muk_sticky_goo_adds_two_to_the_opponent_s_retreat_cost() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Abra (BS 43)")
+        .active(Player::Two, "Grimer (TR 57)").evolve_into("Dark Muk (TR 41)")
+        .build();
+
+    let engine = engine(state);
+
+    assert_eq!(engine.retreat_cost(active(&engine, Player::One)).len(), 2);
+}
+
+#[test]
+fn dark_dugtrio_sinkhole_damages_the_pokemon_that_retreats() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Abra (BS 43)")
+        .bench(Player::One, "Magikarp (BS 35)")
+        .active(Player::Two, "Diglett (TR 52)").evolve_into("Dark Dugtrio (TR 6)")
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Retreat Abra (BS 43)",
+        "pick_in_play: Magikarp",
+        "flip: tails, heads",
+    ]);
+
+    assert_eq!(bench(&engine, Player::One)[0].damage_counters, 1);
+}
+
+#[test]
+fn dark_gyarados_final_beam_hits_the_attacker_for_each_water_energy() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Electabuzz (BS 20)").attach(&["Lightning Energy (BS 100)"])
+        .active(Player::Two, "Magikarp (BS 35)").evolve_into("Dark Gyarados (TR 8)")
+            .attach(&["Water Energy (BS 102)", "Water Energy (BS 102)"])
+            .damage_counters(6)
+        .bench(Player::Two, "Abra (TR 49)")
+        .prizes(Player::One, &["Potion (BS 93)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Attack with Electabuzz: Thundershock",
+        "flip: tails",
+        "flip: heads",
+        "pick_from_prizes: Prize #1",
+        "pick_in_play: Abra",
+    ]);
+
+    assert_eq!(active(&engine, Player::One).damage_counters, 4);
+}
+
+#[test]
+fn dark_kadabra_matter_exchange_discards_a_card_to_draw_one() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Abra (TR 49)").evolve_into("Dark Kadabra (TR 39)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Potion (BS 93)"])
+        .deck(Player::One, &["Switch (BS 94)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Use Dark Kadabra (TR 39)'s PokéPower: Matter Exchange",
+        "pick_from_hand: Potion",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Switch (BS 94)"]);
+    assert_eq!(names(&engine.state.side(Player::One).discard), vec!["Potion (BS 93)"]);
+}
+
+#[test]
+fn dark_gloom_pollen_stench_confuses_whoever_loses_the_flip() {
+    let state = || testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Oddish (TR 63)").evolve_into("Dark Gloom (TR 36)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .build();
+
+    let heads = testing::run(&BaseFossil::with_team_rocket(), state(), &["pick_action: Use Dark Gloom (TR 36)'s PokéPower: Pollen Stench", "flip: heads"]);
+    let tails = testing::run(&BaseFossil::with_team_rocket(), state(), &["pick_action: Use Dark Gloom (TR 36)'s PokéPower: Pollen Stench", "flip: tails"]);
+
+    assert_eq!(active(&heads, Player::Two).rotational_status, RotationalStatus::Confused);
+    assert_eq!(active(&tails, Player::One).rotational_status, RotationalStatus::Confused);
+}
+
+#[test]
+fn dark_dragonair_evolutionary_light_searches_for_an_evolution_card() {
+    let state = testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Dratini (TR 53)").evolve_into("Dark Dragonair (TR 33)")
+        .active(Player::Two, "Magikarp (BS 35)")
+        .deck(Player::One, &["Abra (TR 49)", "Dark Kadabra (TR 39)"])
+        .build();
+
+    let engine = testing::run(&BaseFossil::with_team_rocket(), state, &[
+        "pick_action: Use Dark Dragonair (TR 33)'s PokéPower: Evolutionary Light",
+        "search_deck: Dark Kadabra",
+    ]);
+
+    assert_eq!(names(&engine.state.side(Player::One).hand), vec!["Dark Kadabra (TR 39)"]);
+}
+
+#[test]
+fn dark_primeape_frenzy_confuses_the_pokemon_that_damages_it() {
+    let state = |status| testing::game(&BaseFossil::with_team_rocket())
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Mankey (TR 61)").evolve_into("Dark Primeape (TR 43)").status(status)
+        .build();
+    let jab = |status| {
+        let engine = testing::run(&BaseFossil::with_team_rocket(), state(status), &["pick_action: Attack with Hitmonchan: Jab"]);
+        active(&engine, Player::One).rotational_status.clone()
+    };
+
+    assert_eq!(jab(RotationalStatus::None), RotationalStatus::Confused);
+    assert_eq!(jab(RotationalStatus::Asleep), RotationalStatus::None);
+}
@@ -0,0 +1,122 @@
+use ptcg_core::*;
+use ptcg_core::formats::BaseFossil;
+use ptcg_core::testing::{GameStateBuilder, ScriptedDM};
+
+fn state() -> GameState {
+    GameStateBuilder::new()
+        .active(Player::One, "Hitmonchan (BS 7)").attach(&["Fighting Energy (BS 97)"])
+        .active(Player::Two, "Magikarp (BS 35)").damage_counters(1)
+        .bench(Player::Two, "Magikarp (BS 35)")
+        .hand(Player::One, &["Potion (BS 93)", "Switch (BS 94)"])
+        .hand(Player::Two, &["Gust of Wind (BS 92)", "Water Energy (BS 102)", "Water Energy (BS 102)"])
+        .deck(Player::One, &["Fighting Energy (BS 97)"; 5])
+        .deck(Player::Two, &["Water Energy (BS 102)"; 7])
+        .prizes(Player::One, &["Potion (BS 93)", "Switch (BS 94)"])
+        .prizes(Player::Two, &["Water Energy (BS 102)"; 2])
+        .build()
+}
+
+#[test]
+fn the_opponents_hand_and_both_decks_are_only_counted() {
+    let state = state();
+    let view = PlayerView::new(&state, Player::One);
+
+    assert_eq!(view.p1.hand, state.p1.hand.iter().cloned().map(Some).collect::<Vec<_>>());
+    assert_eq!(view.p2.hand, vec![None, None, None]);
+    assert_eq!(view.p1.deck, 5);
+    assert_eq!(view.p2.deck, 7);
+    assert_eq!(view.p2.active[0].stack, vec![Some(state.p2.active[0].stack[0].card().clone())]);
+    assert_eq!(view.p2.active[0].damage_counters, 1);
+}
+
+#[test]
+fn face_down_prizes_are_hidden_from_both_players_until_revealed() {
+    let state = state();
+
+    for player in [Player::One, Player::Two] {
+        let view = PlayerView::new(&state, player);
+        assert!(view.p1.prizes.iter().all(|prize| prize.card.is_none()));
+        assert_eq!(view.p1.prizes.len(), 2);
+    }
+
+    let state = state.reveal_prizes(Player::One);
+    let view = PlayerView::new(&state, Player::Two);
+    assert!(view.p1.prizes.iter().all(|prize| prize.card.is_some()));
+}
+
+#[test]
+fn face_down_pokemon_are_only_seen_by_their_owner() {
+    let mut state = GameState::initial(&["Magikarp (BS 35)".into()], &["Magikarp (BS 35)".into()], &BaseFossil::new(), true).unwrap();
+    state = state.draw_to_hand(Player::One, &mut ScriptedDM::new(&["random_card: 0"]));
+    let magikarp = state.p1.hand[0].clone();
+    state = state.play_from_hand_to_active_face_down(Player::One, &magikarp);
+
+    assert_eq!(PlayerView::new(&state, Player::One).p1.active[0].stack, vec![Some(magikarp)]);
+    assert_eq!(PlayerView::new(&state, Player::Two).p1.active[0].stack, vec![None]);
+}
+
+#[test]
+fn decision_makers_are_shown_views() {
+    let engine = GameEngine::from_state(state(), Box::new(BaseFossil::new()));
+    let mut dm = ScriptedDM::new(&[
+        "pick_action: Attack with Hitmonchan: Jab",
+        "pick_from_prizes: Prize #2",
+        "pick_in_play: Magikarp",
+    ]);
+
+    engine.step(&mut dm);
+
+    dm.assert_done();
+    let players = dm.views().iter().map(|view| view.player).collect::<Vec<_>>();
+    assert_eq!(players, vec![Player::One, Player::Two]);
+    assert_eq!(dm.views()[0].p2.hand, vec![None, None, None]);
+    assert_eq!(dm.views()[1].p1.hand, vec![None, None]);
+}
+
+#[test]
+fn decision_makers_are_only_offered_what_they_can_see() {
+    let mut setup = GameState::initial(&["Magikarp (BS 35)".into()], &["Magikarp (BS 35)".into()], &BaseFossil::new(), true).unwrap();
+    setup = setup.draw_to_hand(Player::One, &mut ScriptedDM::new(&["random_card: 0"]));
+    let magikarp = setup.p1.hand[0].clone();
+    setup = setup.play_from_hand_to_active_face_down(Player::One, &magikarp);
+    let in_play = setup.p1.active.clone();
+
+    let mut dm = ScriptedDM::new(&["pick_in_play: Face down Pokémon", "pick_in_play: Magikarp"]);
+    let chosen = (&mut dm as &mut dyn DecisionMaker).pick_in_play_cards(Player::Two, 1, &in_play);
+    assert_eq!(chosen[0].id, in_play[0].id);
+    (&mut dm as &mut dyn DecisionMaker).pick_in_play_cards(Player::One, 1, &in_play);
+    dm.assert_done();
+
+    let deck = state().p1.deck.cards();
+    let mut dm = ScriptedDM::new(&["search_deck: Face down card", "search_deck: Fighting Energy"]);
+    let chosen = (&mut dm as &mut dyn DecisionMaker).search_deck_cards(Player::Two, Player::One, 1, &deck);
+    assert_eq!(chosen, vec![&deck[0]]);
+    (&mut dm as &mut dyn DecisionMaker).search_deck_cards(Player::One, Player::One, 1, &deck);
+    dm.assert_done();
+}
+
+#[test]
+fn determinizing_keeps_what_the_player_sees_and_makes_up_the_rest() {
+    let state = state();
+    let view = PlayerView::new(&state, Player::One);
+    let mut guessed = vec![];
+    let determinized = view.determinize(&mut |owner| {
+        guessed.push(owner);
+        "Psychic Energy (BS 101)".to_string()
+    });
+
+    assert_eq!(determinized.p1.hand, state.p1.hand);
+    assert_eq!(determinized.p2.hand.len(), 3);
+    assert!(determinized.p2.hand.iter().all(|card| card.archetype == "Psychic Energy (BS 101)" && card.owner == Player::Two));
+    assert_eq!(determinized.p1.deck.len(), 5);
+    assert_eq!(determinized.p2.deck.len(), 7);
+    assert_eq!(determinized.p2.active[0].damage_counters, 1);
+    assert_eq!(guessed.iter().filter(|&&owner| owner == Player::One).count(), 5 + 2);
+    assert_eq!(guessed.iter().filter(|&&owner| owner == Player::Two).count(), 7 + 3 + 2);
+
+    let mut ids = determinized.all_cards().iter().map(|card| card.in_game_id).collect::<Vec<_>>();
+    let count = ids.len();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), count);
+}